@@ -0,0 +1,455 @@
+//! The subset of a JSON ABI the generators need, with the field and type names that
+//! `substreams_ethereum::Abigen` gives the decoded values.
+//!
+//! Params are renamed before either sees them: `_newProxyFactory` becomes `new_proxy_factory`,
+//! and the unnamed params of well-known callbacks get the names their standard gives them.
+
+use anyhow::{anyhow, bail, Context, Result};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use serde_json::Value;
+
+/// A contract listed in `build/main.rs`.
+pub struct Source {
+    /// Prefix of every generated proto message and container field.
+    pub name: &'static str,
+    pub abi: &'static str,
+    /// `None` for contracts decoded by their own modules rather than `map_events` / `map_calls`,
+    /// because they have a different address on each network or mostly unrelated traffic.
+    /// Their mappers take the address to decode as an argument instead.
+    pub address: Option<&'static str>,
+    /// ABI names of the events and functions managing the contract (roles, configuration,
+    /// pausing), built with the `admin` feature. Everything else is built with `trading`.
+    pub admin: &'static [&'static str],
+}
+
+pub struct Contract {
+    pub name: String,
+    /// The ABI with every param renamed to its field name, to generate the bindings from.
+    pub bindings_abi: String,
+    /// Name of the generated bindings module, from the ABI file name.
+    pub module: String,
+    pub address: Option<String>,
+    pub events: Vec<Event>,
+    pub functions: Vec<Function>,
+    pub structs: Vec<Struct>,
+}
+
+pub struct Event {
+    /// Name of the generated bindings struct.
+    pub name: String,
+    pub params: Vec<Param>,
+    pub admin: bool,
+}
+
+pub struct Function {
+    pub name: String,
+    /// Suffix of its field in the `Calls` container.
+    pub plural: String,
+    pub inputs: Vec<Param>,
+    pub admin: bool,
+    pub outputs: Vec<Param>,
+}
+
+/// A named tuple type (`struct Order` in Solidity), shared by every param using it.
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<Param>,
+    /// Functions taking it, used in comments.
+    pub used_by: Vec<String>,
+}
+
+pub struct Param {
+    pub name: String,
+    /// Name in the ABI, empty for unnamed params.
+    pub abi_name: String,
+    pub kind: Kind,
+    /// Solidity type of enum params, which the ABI encodes as `uint8`.
+    pub enum_type: Option<String>,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Kind {
+    Address,
+    Bool,
+    Uint(usize),
+    Int(usize),
+    Bytes,
+    FixedBytes(usize),
+    String,
+    Array(Box<Kind>),
+    FixedArray(Box<Kind>, usize),
+    /// Index into `Contract::structs`.
+    Tuple(usize),
+}
+
+impl Contract {
+    pub fn parse(source: &Source, abi: &str) -> Result<Self> {
+        let module = std::path::Path::new(source.abi)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.strip_suffix(".abi"))
+            .with_context(|| format!("ABI file {} should be named <module>.abi.json", source.abi))?
+            .to_string();
+
+        let mut entries: Vec<Value> = serde_json::from_str(abi).with_context(|| format!("parsing {}", source.abi))?;
+        for entry in &mut entries {
+            rename(entry).with_context(|| format!("{}: {}", source.abi, signature(entry)))?;
+        }
+
+        let mut contract = Contract {
+            name: source.name.to_string(),
+            bindings_abi: String::new(),
+            module,
+            address: source.address.map(|a| a.trim_start_matches("0x").to_lowercase()),
+            events: Vec::new(),
+            functions: Vec::new(),
+            structs: Vec::new(),
+        };
+
+        for entry in &entries {
+            let name = entry["name"].as_str().unwrap_or_default();
+            match entry["type"].as_str() {
+                Some("event") if entry["anonymous"].as_bool() != Some(true) => {
+                    let mut params = Vec::new();
+                    for input in inputs(entry, "inputs") {
+                        let indexed = input["indexed"].as_bool().unwrap_or_default();
+                        let mut param = contract.param(input, None)?;
+                        // Indexed reference types are only available as their hash.
+                        if indexed && !param.kind.is_value_type() {
+                            param.kind = Kind::FixedBytes(32);
+                        }
+                        params.push(param);
+                    }
+                    contract.events.push(Event {
+                        name: name.to_upper_camel_case(),
+                        params,
+                        admin: source.admin.contains(&name),
+                    });
+                }
+                // View functions are only ever reached through eth_call, not transactions.
+                Some("function")
+                    if !matches!(entry["stateMutability"].as_str(), Some("view") | Some("pure"))
+                        && entry["constant"].as_bool() != Some(true) =>
+                {
+                    let mut function = Function {
+                        name: name.to_upper_camel_case(),
+                        plural: plural(&name.to_snake_case()),
+                        inputs: Vec::new(),
+                        outputs: Vec::new(),
+                        admin: source.admin.contains(&name),
+                    };
+                    for input in inputs(entry, "inputs") {
+                        function.inputs.push(contract.param(input, Some(name))?);
+                    }
+                    for output in inputs(entry, "outputs") {
+                        function.outputs.push(contract.param(output, Some(name))?);
+                    }
+                    contract.functions.push(function);
+                }
+                _ => {}
+            }
+        }
+
+        for name in source.admin {
+            let tracked = entries.iter().any(|entry| {
+                entry["name"] == *name
+                    && match entry["type"].as_str() {
+                        Some("event") => contract.events.iter().any(|e| e.name == name.to_upper_camel_case()),
+                        Some("function") => contract.functions.iter().any(|f| f.name == name.to_upper_camel_case()),
+                        _ => false,
+                    }
+            });
+            if !tracked {
+                bail!("{}: admin entry {} is not a tracked event or function", source.abi, name);
+            }
+        }
+
+        // Abigen emits bindings sorted by name; keep the same order everywhere.
+        contract.events.sort_by(|a, b| a.name.cmp(&b.name));
+        contract.functions.sort_by(|a, b| a.name.cmp(&b.name));
+        for pair in contract.events.windows(2) {
+            if pair[0].name == pair[1].name {
+                bail!("{}: overloaded event {} is not supported", source.abi, pair[0].name);
+            }
+        }
+        for pair in contract.functions.windows(2) {
+            if pair[0].name == pair[1].name {
+                bail!("{}: overloaded function {} is not supported", source.abi, pair[0].name);
+            }
+        }
+        // `cancelOrder` and `cancelOrders` both pluralize to `cancel_orders`; the one already
+        // named in the plural becomes `cancel_orders_batch`.
+        let plurals: Vec<String> = contract.functions.iter().map(|f| f.plural.clone()).collect();
+        for function in &mut contract.functions {
+            let collides = plurals.iter().filter(|p| **p == function.plural).count() > 1;
+            if collides && function.plural == function.name.to_snake_case() {
+                function.plural.push_str("_batch");
+            }
+        }
+
+        for entry in &mut entries {
+            strip_abi_names(entry);
+        }
+        contract.bindings_abi = serde_json::to_string_pretty(&entries)?;
+
+        Ok(contract)
+    }
+
+    fn param(&mut self, value: &Value, function: Option<&str>) -> Result<Param> {
+        let name = match value["name"].as_str().unwrap_or_default() {
+            // Abigen escapes it the same way.
+            "self" => "_self".to_string(),
+            name => name.to_string(),
+        };
+        let abi_name = value[ABI_NAME].as_str().unwrap_or_default().to_string();
+        let ty = value["type"].as_str().ok_or_else(|| anyhow!("param {} has no type", name))?;
+        let internal_type = value["internalType"].as_str().unwrap_or_default();
+        let enum_type = internal_type.strip_prefix("enum ").map(|t| t.rsplit('.').next().unwrap().to_string());
+
+        let kind = self.kind(ty, &name, internal_type, value, function)?;
+        Ok(Param {
+            name,
+            abi_name,
+            kind,
+            enum_type,
+        })
+    }
+
+    fn kind(&mut self, ty: &str, name: &str, internal_type: &str, value: &Value, function: Option<&str>) -> Result<Kind> {
+        if let Some(inner) = ty.strip_suffix("[]") {
+            let inner_internal = internal_type.strip_suffix("[]").unwrap_or(internal_type);
+            let kind = self.kind(inner, name, inner_internal, value, function)?;
+            if matches!(kind, Kind::Array(_) | Kind::FixedArray(..)) {
+                bail!("nested array param {} is not supported", name);
+            }
+            return Ok(Kind::Array(Box::new(kind)));
+        }
+        if let Some((inner, len)) = ty.strip_suffix(']').and_then(|t| t.rsplit_once('[')) {
+            let inner_internal = internal_type.rsplit_once('[').map(|(t, _)| t).unwrap_or(internal_type);
+            let kind = self.kind(inner, name, inner_internal, value, function)?;
+            if matches!(kind, Kind::Array(_) | Kind::FixedArray(..)) {
+                bail!("nested array param {} is not supported", name);
+            }
+            return Ok(Kind::FixedArray(Box::new(kind), len.parse()?));
+        }
+
+        Ok(match ty {
+            "address" => Kind::Address,
+            "bool" => Kind::Bool,
+            "bytes" => Kind::Bytes,
+            "string" => Kind::String,
+            "tuple" => Kind::Tuple(self.tuple(name, internal_type, value, function)?),
+            _ if ty.starts_with("uint") => Kind::Uint(ty[4..].parse().unwrap_or(256)),
+            _ if ty.starts_with("int") => Kind::Int(ty[3..].parse().unwrap_or(256)),
+            _ if ty.starts_with("bytes") => Kind::FixedBytes(ty[5..].parse()?),
+            _ => bail!("unsupported ABI type {} for param {}", ty, name),
+        })
+    }
+
+    fn tuple(&mut self, name: &str, internal_type: &str, value: &Value, function: Option<&str>) -> Result<usize> {
+        let struct_name = match internal_type.strip_prefix("struct ") {
+            Some(ty) => ty.rsplit('.').next().unwrap().to_upper_camel_case(),
+            None => format!("{}_{}", function.unwrap_or_default(), name).to_upper_camel_case(),
+        };
+
+        let mut fields = Vec::new();
+        for component in inputs(value, "components") {
+            fields.push(self.param(component, function)?);
+        }
+
+        if let Some(index) = self.structs.iter().position(|s| s.name == struct_name) {
+            let existing = &self.structs[index];
+            let same_layout = existing.fields.len() == fields.len()
+                && existing.fields.iter().zip(&fields).all(|(a, b)| a.name == b.name && a.kind == b.kind);
+            if !same_layout {
+                bail!("struct {} is used with two different layouts", struct_name);
+            }
+            if let Some(function) = function {
+                let used_by = &mut self.structs[index].used_by;
+                if !used_by.iter().any(|f| f == function) {
+                    used_by.push(function.to_string());
+                }
+            }
+            return Ok(index);
+        }
+
+        self.structs.push(Struct {
+            name: struct_name,
+            fields,
+            used_by: function.map(|f| vec![f.to_string()]).unwrap_or_default(),
+        });
+        Ok(self.structs.len() - 1)
+    }
+
+    /// `Polymarketctf`, the prefix of every proto message of the contract.
+    pub fn prefix(&self) -> String {
+        self.name.to_upper_camel_case()
+    }
+}
+
+impl Kind {
+    fn is_value_type(&self) -> bool {
+        !matches!(self, Kind::Bytes | Kind::String | Kind::Array(_) | Kind::FixedArray(..) | Kind::Tuple(_))
+    }
+}
+
+impl Event {
+    /// Suffix of its field in the `Events` container: `order_filleds`, `transfer_batches`.
+    pub fn plural(&self) -> String {
+        let name = self.name.to_snake_case();
+        if sibilant(&name) {
+            format!("{}es", name)
+        } else {
+            format!("{}s", name)
+        }
+    }
+}
+
+/// `add_admins`, `set_proxy_factories`, and names already in the plural left as they are.
+fn plural(name: &str) -> String {
+    if name.ends_with('s') {
+        name.to_string()
+    } else if sibilant(name) {
+        format!("{}es", name)
+    } else if name.ends_with('y') && !name.ends_with("ay") && !name.ends_with("ey") && !name.ends_with("oy") {
+        format!("{}ies", &name[..name.len() - 1])
+    } else {
+        format!("{}s", name)
+    }
+}
+
+fn sibilant(name: &str) -> bool {
+    name.ends_with("ch") || name.ends_with("sh") || name.ends_with('x')
+}
+
+fn inputs<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+/// Key under which `rename` keeps the ABI name of a param, removed again before the ABI is
+/// handed to Abigen, which rejects unknown keys.
+const ABI_NAME: &str = "abiName";
+
+/// Names of the unnamed params of standard callbacks, by signature.
+const STANDARD_PARAMS: &[(&str, &[&str])] = &[
+    (
+        "onERC1155Received(address,address,uint256,uint256,bytes)",
+        &["operator", "from", "id", "value", "data"],
+    ),
+    (
+        "onERC1155BatchReceived(address,address,uint256[],uint256[],bytes)",
+        &["operator", "from", "ids", "values", "data"],
+    ),
+    (
+        "onERC721Received(address,address,uint256,bytes)",
+        &["operator", "from", "token_id", "data"],
+    ),
+];
+
+/// Fields every event message has besides its params.
+const EVENT_FIELDS: &[&str] = &[
+    "evt_tx_hash",
+    "evt_index",
+    "evt_block_time",
+    "evt_block_number",
+    "evt_tx_context",
+    "evt_ordinal",
+    "evt_block_hash",
+];
+
+/// Fields every call message has besides its inputs and `output_*` outputs.
+const CALL_FIELDS: &[&str] = &[
+    "call_tx_hash",
+    "call_block_time",
+    "call_block_number",
+    "call_ordinal",
+    "call_success",
+    "call_tx_context",
+    "call_context",
+    "call_block_hash",
+];
+
+/// Renames the params of an ABI entry to their field names, keeping the ABI name under
+/// `ABI_NAME`, and fails when two of them end up with the same field.
+fn rename(entry: &mut Value) -> Result<()> {
+    let standard = STANDARD_PARAMS
+        .iter()
+        .find(|(signature, _)| *signature == self::signature(entry))
+        .map(|(_, names)| *names);
+    let is_event = entry["type"] == "event";
+
+    let mut fields = Vec::new();
+    for (index, input) in inputs_mut(entry, "inputs").iter_mut().enumerate() {
+        let fallback = match standard {
+            Some(names) => names[index].to_string(),
+            None if is_event && input["indexed"].as_bool() == Some(true) => format!("topic{}", index),
+            None => format!("param{}", index),
+        };
+        fields.push(rename_param(input, fallback)?);
+    }
+    for (index, output) in inputs_mut(entry, "outputs").iter_mut().enumerate() {
+        fields.push(format!("output_{}", rename_param(output, format!("param{}", index))?));
+    }
+
+    let fixed = if is_event { EVENT_FIELDS } else { CALL_FIELDS };
+    check_unique(fields.iter().map(String::as_str).chain(fixed.iter().copied()))
+}
+
+/// Renames a param and its tuple components, returning its new name.
+fn rename_param(param: &mut Value, fallback: String) -> Result<String> {
+    let abi_name = param["name"].as_str().unwrap_or_default().to_string();
+    // `to_snake_case` also drops leading, trailing and repeated underscores.
+    let name = match abi_name.to_snake_case() {
+        name if name.is_empty() => fallback,
+        name => name,
+    };
+
+    let mut components = Vec::new();
+    for (index, component) in inputs_mut(param, "components").iter_mut().enumerate() {
+        components.push(rename_param(component, format!("param{}", index))?);
+    }
+    check_unique(components.iter().map(String::as_str)).with_context(|| format!("in tuple {}", name))?;
+
+    param["name"] = Value::from(if abi_name == "self" { abi_name.as_str() } else { name.as_str() });
+    param[ABI_NAME] = Value::from(abi_name);
+    Ok(name)
+}
+
+fn check_unique<'a>(names: impl Iterator<Item = &'a str>) -> Result<()> {
+    let mut seen = Vec::new();
+    for name in names {
+        if seen.contains(&name) {
+            bail!("two fields named {}, rename one of the params in the ABI", name);
+        }
+        seen.push(name);
+    }
+    Ok(())
+}
+
+fn strip_abi_names(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove(ABI_NAME);
+            map.values_mut().for_each(strip_abi_names);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_abi_names),
+        _ => {}
+    }
+}
+
+/// `name(type,...)` of an ABI entry, with tuples left as `tuple`.
+fn signature(entry: &Value) -> String {
+    let types: Vec<&str> = inputs(entry, "inputs")
+        .iter()
+        .map(|input| input["type"].as_str().unwrap_or_default())
+        .collect();
+    format!("{}({})", entry["name"].as_str().unwrap_or_default(), types.join(","))
+}
+
+fn inputs_mut<'a>(value: &'a mut Value, key: &str) -> &'a mut [Value] {
+    value
+        .get_mut(key)
+        .and_then(Value::as_array_mut)
+        .map(Vec::as_mut_slice)
+        .unwrap_or_default()
+}
@@ -0,0 +1,138 @@
+mod abi;
+mod mappers;
+mod proto;
+
+use abi::{Contract, Source};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use substreams_ethereum::Abigen;
+
+/// Every tracked contract. Adding one only takes its ABI file and an entry here: the
+/// bindings, proto messages, mappers and protobuf code are all generated from it.
+const CONTRACTS: &[Source] = &[
+    Source {
+        name: "polymarketctf",
+        abi: "abi/polymarketctf_contract.abi.json",
+        address: Some("4bfb41d5b3570defd03c39a9a4d8de6bd8b8982e"),
+        admin: &[
+            "NewAdmin",
+            "NewOperator",
+            "ProxyFactoryUpdated",
+            "RemovedAdmin",
+            "RemovedOperator",
+            "SafeFactoryUpdated",
+            "TradingPaused",
+            "TradingUnpaused",
+            "addAdmin",
+            "addOperator",
+            "pauseTrading",
+            "removeAdmin",
+            "removeOperator",
+            "renounceAdminRole",
+            "renounceOperatorRole",
+            "setProxyFactory",
+            "setSafeFactory",
+            "unpauseTrading",
+        ],
+    },
+    Source {
+        name: "ctf",
+        abi: "abi/ctf_contract.abi.json",
+        address: Some("4d97dcd97ec945f40cf65f87097ace5ea0476045"),
+        admin: &[],
+    },
+    // Our own vault and the stETH token it deploys, at a different address on every network.
+    Source {
+        name: "vault",
+        abi: "abi/vault_contract.abi.json",
+        address: None,
+        admin: &[],
+    },
+    Source {
+        name: "steth",
+        abi: "abi/steth_contract.abi.json",
+        address: None,
+        admin: &[],
+    },
+    // The exchange collateral (USDC.e), only decoded around the exchange and conditional tokens.
+    Source {
+        name: "usdc",
+        abi: "abi/usdc_contract.abi.json",
+        address: None,
+        admin: &[],
+    },
+];
+
+/// Proto files compiled into `src/pb`, the generated ones and those they import included.
+const PROTO_FILES: &[&str] = &["contract.proto", "contract_v2.proto", "exchange.proto"];
+
+fn main() -> Result<()> {
+    println!("cargo:rerun-if-changed=build");
+    println!("cargo:rerun-if-changed=abi");
+    println!("cargo:rerun-if-changed=proto");
+
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+    let mut contracts = Vec::new();
+    for source in CONTRACTS {
+        let contents = fs::read_to_string(source.abi).with_context(|| format!("reading {}", source.abi))?;
+        let contract = Contract::parse(source, &contents)?;
+
+        let bindings = out_dir.join(format!("{}.rs", contract.module));
+        Abigen::from_bytes("Contract", contract.bindings_abi.as_bytes())?
+            .generate()?
+            .write_to_file(&bindings)?;
+        write_if_changed(format!("src/abi/{}.rs", contract.module), fs::read_to_string(&bindings)?)?;
+
+        contracts.push(contract);
+    }
+
+    let mut abi_mod = String::new();
+    for contract in &contracts {
+        abi_mod.push_str(&format!("#[allow(clippy::all)]\npub mod {};\n", contract.module));
+    }
+    write_if_changed("src/abi/mod.rs", abi_mod)?;
+
+    write_if_changed("proto/contract.proto", proto::generate(&contracts, proto::Version::V1))?;
+    write_if_changed("proto/contract_v2.proto", proto::generate(&contracts, proto::Version::V2))?;
+
+    write_if_changed("src/mappers/mod.rs", mappers::generate_mod(&contracts))?;
+    for contract in &contracts {
+        write_if_changed(format!("src/mappers/{}.rs", contract.name), mappers::generate_contract(contract))?;
+    }
+
+    compile_protos(&out_dir.join("pb"))
+}
+
+/// Regenerates `src/pb/contract.v1.rs` and `src/pb/contract.v2.rs` from the proto files.
+fn compile_protos(out_dir: &Path) -> Result<()> {
+    fs::create_dir_all(out_dir)?;
+    let files = protox::compile(PROTO_FILES, ["proto"])?;
+    prost_build::Config::new()
+        .out_dir(out_dir)
+        .format(false)
+        .compile_fds(files)?;
+
+    for package in ["contract.v1", "contract.v2"] {
+        let code = fs::read_to_string(out_dir.join(format!("{}.rs", package)))?;
+        write_if_changed(
+            format!("src/pb/{}.rs", package),
+            format!("// @generated\n{}// @@protoc_insertion_point(module)\n", code),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Leaves unchanged files untouched so that their modification time does not trigger
+/// another run of this script.
+fn write_if_changed(path: impl AsRef<Path>, contents: String) -> Result<()> {
+    let path = path.as_ref();
+    if fs::read_to_string(path).is_ok_and(|current| current == contents) {
+        return Ok(());
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+}
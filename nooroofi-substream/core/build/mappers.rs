@@ -0,0 +1,422 @@
+//! Generates `src/mappers/`: one module per contract decoding its events and calls into the
+//! `contract.v1` messages and converting those to `contract.v2`, plus a `mod.rs` filling the
+//! `Events` / `Calls` containers across contracts.
+
+use crate::abi::{Contract, Kind, Param};
+use crate::proto::output_field;
+use heck::ToSnakeCase;
+use std::fmt::Write;
+
+const HEADER: &str = "// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.\n";
+
+pub fn generate_mod(contracts: &[Contract]) -> String {
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push('\n');
+    for contract in contracts {
+        writeln!(out, "pub mod {};", contract.name).unwrap();
+    }
+    out.push_str("\nuse crate::pb::contract::{v1, v2};\nuse substreams_ethereum::pb::eth::v2 as eth;\n");
+
+    // Contracts without a fixed address are decoded by their own mappers, given the address.
+    let fixed: Vec<&Contract> = contracts.iter().filter(|c| c.address.is_some()).collect();
+    out.push_str("\n/// Decodes the events of every contract tracked at a fixed address.\n");
+    out.push_str("pub fn map_events(blk: &eth::Block, events: &mut v1::Events) {\n");
+    for contract in &fixed {
+        writeln!(out, "    {}::map_events(blk, events);", contract.name).unwrap();
+    }
+    out.push_str("}\n\n/// Decodes the calls to every contract tracked at a fixed address, reverted ones included.\n");
+    out.push_str("pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls) {\n");
+    for contract in &fixed {
+        writeln!(out, "    {}::map_calls(blk, calls);", contract.name).unwrap();
+    }
+
+    out.push_str("}\n\npub fn events_v2(v1: v1::Events) -> v2::Events {\n    v2::Events {\n");
+    for contract in contracts {
+        for event in &contract.events {
+            let field = format!("{}_{}", contract.name, event.plural());
+            writeln!(
+                out,
+                "        {field}: v1.{field}.into_iter().map({}::{}_v2).collect(),",
+                contract.name,
+                event.name.to_snake_case()
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("    }\n}\n\npub fn calls_v2(v1: v1::Calls) -> v2::Calls {\n    v2::Calls {\n");
+    for contract in contracts {
+        for function in &contract.functions {
+            let field = format!("{}_call_{}", contract.name, function.plural);
+            writeln!(
+                out,
+                "        {field}: v1.{field}.into_iter().map({}::{}_call_v2).collect(),",
+                contract.name,
+                function.name.to_snake_case()
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("    }\n}\n");
+
+    out
+}
+
+pub fn generate_contract(contract: &Contract) -> String {
+    let mut body = String::new();
+    let prefix = contract.prefix();
+    let bindings = format!("abi::{}", contract.module);
+
+    // `(param, argument, compared address)` of the decoding functions.
+    let (param, arg, tracked) = match &contract.address {
+        Some(address) => {
+            writeln!(body, "\npub const TRACKED_CONTRACT: [u8; 20] = hex!(\"{}\");", address).unwrap();
+            ("", "", "TRACKED_CONTRACT")
+        }
+        None => (", contract_address: &[u8]", ", contract_address", "contract_address"),
+    };
+    let no_fixed_address = if contract.address.is_some() {
+        ""
+    } else {
+        "///\n/// The contract has no fixed address, the one to decode is given as `contract_address`.\n"
+    };
+
+    body.push_str("\n/// An event of the contract, decoded into its `contract.v1` message.\n");
+    body.push_str("#[allow(clippy::large_enum_variant)]\npub enum Event {\n");
+    for event in &contract.events {
+        writeln!(body, "    {}", cfg("events", event.admin)).unwrap();
+        writeln!(body, "    {}(v1::{}{}),", event.name, prefix, event.name).unwrap();
+    }
+    body.push_str("}\n\n/// A call to the contract, decoded into its `contract.v1` message.\n");
+    body.push_str("#[allow(clippy::large_enum_variant)]\npub enum Call {\n");
+    for function in &contract.functions {
+        writeln!(body, "    {}", cfg("calls", function.admin)).unwrap();
+        writeln!(body, "    {}(v1::{}{}Call),", function.name, prefix, function.name).unwrap();
+    }
+    body.push_str("}\n");
+
+    body.push_str(concat!(
+        "\n/// Decodes a log emitted by the contract. Only the params, `evt_index` and `evt_ordinal`\n",
+        "/// are set, the transaction and block fields are left to the caller.\n",
+    ));
+    body.push_str(no_fixed_address);
+    writeln!(body, "pub fn decode_log(log: &eth::Log{param}) -> Option<Event> {{").unwrap();
+    writeln!(body, "    if log.address != {tracked} {{\n        return None;\n    }}").unwrap();
+    for event in &contract.events {
+        let binding = if event.params.is_empty() { "_event" } else { "event" };
+        writeln!(body, "\n    {}", cfg("events", event.admin)).unwrap();
+        writeln!(
+            body,
+            "    if let Some({}) = {}::events::{}::match_and_decode(log) {{",
+            binding, bindings, event.name
+        )
+        .unwrap();
+        writeln!(body, "        return Some(Event::{}(v1::{}{} {{", event.name, prefix, event.name).unwrap();
+        body.push_str("            evt_index: log.block_index,\n            evt_ordinal: log.ordinal,\n");
+        for param in &event.params {
+            let value = field_v1(contract, param, &format!("event.{}", param.name));
+            writeln!(body, "            {}: {},", param.name, value).unwrap();
+        }
+        body.push_str("            ..Default::default()\n        }));\n    }\n");
+    }
+    body.push_str("\n    None\n}\n");
+
+    body.push_str(concat!(
+        "\n/// Decodes a call to the contract, reverted ones included. Only the inputs, outputs,\n",
+        "/// `call_ordinal`, `call_success` and `call_context` are set, the transaction and block\n",
+        "/// fields are left to the caller.\n",
+    ));
+    body.push_str(no_fixed_address);
+    writeln!(body, "pub fn decode_call(call: &eth::Call{param}) -> Option<Call> {{").unwrap();
+    writeln!(body, "    if call.address != {tracked} {{\n        return None;\n    }}").unwrap();
+    for function in &contract.functions {
+        let path = format!("{}::functions::{}", bindings, function.name);
+        let binding = if function.inputs.is_empty() { "_decoded_call" } else { "decoded_call" };
+        writeln!(body, "\n    {}", cfg("calls", function.admin)).unwrap();
+        writeln!(body, "    if {}::match_call(call) {{", path).unwrap();
+        writeln!(body, "        let {} = {}::decode(call).ok()?;", binding, path).unwrap();
+        if !function.outputs.is_empty() {
+            writeln!(body, "        let output = {}::output(&call.return_data).unwrap_or_default();", path).unwrap();
+        }
+        writeln!(body, "        return Some(Call::{}(v1::{}{}Call {{", function.name, prefix, function.name).unwrap();
+        body.push_str(concat!(
+            "            call_ordinal: call.begin_ordinal,\n",
+            "            call_success: !call.state_reverted,\n",
+            "            call_context: Some(call_context(call)),\n",
+        ));
+        for param in &function.inputs {
+            let value = field_v1(contract, param, &format!("decoded_call.{}", param.name));
+            writeln!(body, "            {}: {},", param.name, value).unwrap();
+        }
+        for (index, output) in function.outputs.iter().enumerate() {
+            let expr = if function.outputs.len() == 1 { "output".to_string() } else { format!("output.{}", index) };
+            let value = field_v1(contract, output, &expr);
+            writeln!(body, "            {}: {},", output_field(output), value).unwrap();
+        }
+        body.push_str("            ..Default::default()\n        }));\n    }\n");
+    }
+    body.push_str("\n    None\n}\n");
+
+    writeln!(body, "\npub fn map_events(blk: &eth::Block, events: &mut v1::Events{param}) {{").unwrap();
+    body.push_str("    for view in blk.receipts() {\n        for log in view.receipt.logs.iter() {\n");
+    writeln!(body, "            let Some(event) = decode_log(log{arg}) else {{\n                continue;\n            }};").unwrap();
+    body.push_str("            match event {\n");
+    for event in &contract.events {
+        writeln!(body, "                {}", cfg("events", event.admin)).unwrap();
+        writeln!(
+            body,
+            "                Event::{}(event) => events.{}_{}.push(v1::{}{} {{",
+            event.name,
+            contract.name,
+            event.plural(),
+            prefix,
+            event.name
+        )
+        .unwrap();
+        body.push_str(concat!(
+            "                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),\n",
+            "                    evt_block_time: Some(blk.timestamp().to_owned()),\n",
+            "                    evt_block_number: blk.number,\n",
+            "                    evt_tx_context: Some(tx_context(view.transaction)),\n",
+            "                    evt_block_hash: Hex(&blk.hash).to_string(),\n",
+            "                    ..event\n",
+            "                }),\n",
+        ));
+    }
+    body.push_str("            }\n        }\n    }\n}\n");
+
+    writeln!(body, "\npub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls{param}) {{").unwrap();
+    body.push_str("    for tx in blk.transactions() {\n        for call in tx.calls.iter() {\n");
+    writeln!(body, "            let Some(decoded_call) = decode_call(call{arg}) else {{\n                continue;\n            }};").unwrap();
+    body.push_str("            match decoded_call {\n");
+    for function in &contract.functions {
+        writeln!(body, "                {}", cfg("calls", function.admin)).unwrap();
+        writeln!(
+            body,
+            "                Call::{}(decoded_call) => calls.{}_call_{}.push(v1::{}{}Call {{",
+            function.name, contract.name, function.plural, prefix, function.name
+        )
+        .unwrap();
+        body.push_str(concat!(
+            "                    call_tx_hash: Hex(&tx.hash).to_string(),\n",
+            "                    call_block_time: Some(blk.timestamp().to_owned()),\n",
+            "                    call_block_number: blk.number,\n",
+            "                    call_tx_context: Some(tx_context(tx)),\n",
+            "                    call_block_hash: Hex(&blk.hash).to_string(),\n",
+            "                    ..decoded_call\n",
+            "                }),\n",
+        ));
+    }
+    body.push_str("            }\n        }\n    }\n}\n");
+
+    for strukt in &contract.structs {
+        let types: Vec<String> = strukt.fields.iter().map(|f| rust_type(contract, &f.kind)).collect();
+        writeln!(body, "\n/// {} tuple as decoded by the bindings.", strukt.name).unwrap();
+        writeln!(body, "type Abi{} = ({},);", strukt.name, types.join(", ")).unwrap();
+        writeln!(
+            body,
+            "\nfn {}(value: Abi{}) -> v1::{}{} {{",
+            strukt.name.to_snake_case(),
+            strukt.name,
+            prefix,
+            strukt.name
+        )
+        .unwrap();
+        writeln!(body, "    v1::{}{} {{", prefix, strukt.name).unwrap();
+        for (index, field) in strukt.fields.iter().enumerate() {
+            let value = field_v1(contract, field, &format!("value.{}", index));
+            writeln!(body, "        {}: {},", field.name, value).unwrap();
+        }
+        body.push_str("    }\n}\n");
+    }
+
+    for event in &contract.events {
+        let name = format!("{}{}", prefix, event.name);
+        writeln!(
+            body,
+            "\npub(super) fn {}_v2(v1: v1::{name}) -> v2::{name} {{\n    v2::{name} {{",
+            event.name.to_snake_case()
+        )
+        .unwrap();
+        body.push_str(concat!(
+            "        evt_tx_hash: v1.evt_tx_hash,\n",
+            "        evt_index: v1.evt_index,\n",
+            "        evt_block_time: v1.evt_block_time,\n",
+            "        evt_block_number: v1.evt_block_number,\n",
+        ));
+        for param in &event.params {
+            writeln!(body, "        {}: {},", param.name, field_v2(contract, &param.kind, &param.name)).unwrap();
+        }
+        body.push_str(concat!(
+            "        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),\n",
+            "        evt_ordinal: v1.evt_ordinal,\n",
+            "        evt_block_hash: v1.evt_block_hash,\n",
+            "    }\n}\n",
+        ));
+    }
+
+    for function in &contract.functions {
+        let name = format!("{}{}Call", prefix, function.name);
+        writeln!(
+            body,
+            "\npub(super) fn {}_call_v2(v1: v1::{name}) -> v2::{name} {{\n    v2::{name} {{",
+            function.name.to_snake_case()
+        )
+        .unwrap();
+        body.push_str(concat!(
+            "        call_tx_hash: v1.call_tx_hash,\n",
+            "        call_block_time: v1.call_block_time,\n",
+            "        call_block_number: v1.call_block_number,\n",
+            "        call_ordinal: v1.call_ordinal,\n",
+            "        call_success: v1.call_success,\n",
+        ));
+        for param in &function.inputs {
+            writeln!(body, "        {}: {},", param.name, field_v2(contract, &param.kind, &param.name)).unwrap();
+        }
+        for output in &function.outputs {
+            let field = output_field(output);
+            writeln!(body, "        {}: {},", field, field_v2(contract, &output.kind, &field)).unwrap();
+        }
+        body.push_str(concat!(
+            "        call_tx_context: v1.call_tx_context.map(tx_context_v2),\n",
+            "        call_context: v1.call_context.map(call_context_v2),\n",
+            "        call_block_hash: v1.call_block_hash,\n",
+            "    }\n}\n",
+        ));
+    }
+
+    for strukt in &contract.structs {
+        let name = format!("{}{}", prefix, strukt.name);
+        writeln!(
+            body,
+            "\nfn {}_v2(v1: v1::{name}) -> v2::{name} {{\n    v2::{name} {{",
+            strukt.name.to_snake_case()
+        )
+        .unwrap();
+        for field in &strukt.fields {
+            writeln!(body, "        {}: {},", field.name, field_v2(contract, &field.kind, &field.name)).unwrap();
+        }
+        body.push_str("    }\n}\n");
+    }
+
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push_str(concat!(
+        "\n// Builds without every feature leave the decoding of the disabled entries unused.\n",
+        "#![cfg_attr(\n",
+        "    not(all(feature = \"events\", feature = \"calls\", feature = \"admin\", feature = \"trading\")),\n",
+        "    allow(dead_code, unused_imports, unused_variables)\n",
+        ")]\n\n",
+    ));
+    out.push_str("use crate::abi;\n");
+    out.push_str("use crate::context::{call_context, call_context_v2, tx_context, tx_context_v2};\n");
+    out.push_str("use crate::pb::contract::{v1, v2};\n");
+    let helpers: Vec<&str> = ["address", "uint256"]
+        .into_iter()
+        .filter(|helper| body.contains(&format!("{}(", helper)))
+        .collect();
+    if !helpers.is_empty() {
+        writeln!(out, "use crate::v2::{{{}}};", helpers.join(", ")).unwrap();
+    }
+    if contract.address.is_some() {
+        out.push_str("use hex_literal::hex;\n");
+    }
+    if body.contains("BigInt") {
+        out.push_str("use substreams::scalar::BigInt;\n");
+    }
+    out.push_str("use substreams::Hex;\nuse substreams_ethereum::pb::eth::v2 as eth;\n");
+    if !contract.events.is_empty() {
+        out.push_str("use substreams_ethereum::Event as _;\n");
+    }
+    out.push_str(&body);
+
+    out
+}
+
+/// `#[cfg]` of an event (`kind` = `events`) or call (`calls`) of the admin or trading side.
+fn cfg(kind: &str, admin: bool) -> String {
+    let side = if admin { "admin" } else { "trading" };
+    format!("#[cfg(all(feature = \"{}\", feature = \"{}\"))]", kind, side)
+}
+
+/// Converts a decoded param to its `contract.v1` field, wrapping message fields in `Some`.
+fn field_v1(contract: &Contract, param: &Param, expr: &str) -> String {
+    let value = value_v1(contract, &param.kind, expr);
+    match param.kind {
+        Kind::Tuple(_) => format!("Some({})", value),
+        _ => value,
+    }
+}
+
+fn value_v1(contract: &Contract, kind: &Kind, expr: &str) -> String {
+    match kind {
+        Kind::Address | Kind::Bytes | Kind::Bool | Kind::String => expr.to_string(),
+        Kind::FixedBytes(_) => format!("Vec::from({})", expr),
+        Kind::Uint(bits) if *bits <= 32 => format!("{}.to_u64() as u32", expr),
+        Kind::Uint(bits) if *bits <= 64 => format!("{}.to_u64()", expr),
+        Kind::Int(bits) if *bits <= 32 => format!("{}.to_i32()", expr),
+        Kind::Uint(_) | Kind::Int(_) => format!("{}.to_string()", expr),
+        Kind::Tuple(index) => format!("{}({})", contract.structs[*index].name.to_snake_case(), expr),
+        Kind::Array(inner) | Kind::FixedArray(inner, _) => {
+            let item = value_v1(contract, inner, "x");
+            match (kind, mapper(&item)) {
+                (Kind::Array(_), None) => expr.to_string(),
+                (_, None) => format!("{}.to_vec()", expr),
+                (_, Some(mapper)) => format!("{}.into_iter().map({}).collect::<Vec<_>>()", expr, mapper),
+            }
+        }
+    }
+}
+
+/// Converts a `contract.v1` field named `field` to its `contract.v2` value.
+fn field_v2(contract: &Contract, kind: &Kind, field: &str) -> String {
+    let expr = format!("v1.{}", field);
+    match kind {
+        Kind::Address => format!("address(&{})", expr),
+        Kind::Uint(bits) if *bits > 64 => format!("uint256(&{}).unwrap_or_default()", expr),
+        Kind::Tuple(index) => format!("{}.map({}_v2)", expr, contract.structs[*index].name.to_snake_case()),
+        Kind::Array(inner) | Kind::FixedArray(inner, _) => match &**inner {
+            Kind::Address => format!("{}.iter().map(|v| address(v)).collect()", expr),
+            Kind::Uint(bits) if *bits > 64 => format!("{}.iter().map(|v| uint256(v).unwrap_or_default()).collect()", expr),
+            Kind::Tuple(index) => {
+                format!("{}.into_iter().map({}_v2).collect()", expr, contract.structs[*index].name.to_snake_case())
+            }
+            _ => expr,
+        },
+        _ => expr,
+    }
+}
+
+/// The argument of `.map()` applying the conversion `item` of `x`, or `None` for the identity.
+fn mapper(item: &str) -> Option<String> {
+    if item == "x" {
+        return None;
+    }
+    match item.strip_suffix("(x)") {
+        Some(function) if function.chars().all(|c| c.is_alphanumeric() || c == '_' || c == ':') => {
+            Some(function.to_string())
+        }
+        _ => Some(format!("|x| {}", item)),
+    }
+}
+
+/// The type `Abigen` decodes a param into.
+fn rust_type(contract: &Contract, kind: &Kind) -> String {
+    match kind {
+        Kind::Address | Kind::Bytes => "Vec<u8>".to_string(),
+        Kind::FixedBytes(size) => format!("[u8; {}]", size),
+        Kind::Uint(_) | Kind::Int(_) => "BigInt".to_string(),
+        Kind::Bool => "bool".to_string(),
+        Kind::String => "String".to_string(),
+        Kind::Array(inner) => format!("Vec<{}>", rust_type(contract, inner)),
+        Kind::FixedArray(inner, size) => format!("[{}; {}]", rust_type(contract, inner), size),
+        Kind::Tuple(index) => {
+            let types: Vec<String> = contract.structs[*index]
+                .fields
+                .iter()
+                .map(|f| rust_type(contract, &f.kind))
+                .collect();
+            format!("({},)", types.join(", "))
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Generates `proto/contract.proto` (`contract.v1`) and `proto/contract_v2.proto`
+//! (`contract.v2`) from the parsed ABIs.
+//!
+//! Event messages hold the event location, the params, then the transaction context,
+//! ordinal and block hash. Call messages hold the call location, the scalar inputs, the
+//! tuple inputs, the outputs, then the transaction context, call frame and block hash.
+
+use crate::abi::{Contract, Kind, Param};
+use heck::ToSnakeCase;
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Version {
+    V1,
+    V2,
+}
+
+const HEADER: &str = "// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.\n";
+
+pub fn generate(contracts: &[Contract], version: Version) -> String {
+    let mut out = String::new();
+    out.push_str(HEADER);
+    out.push_str("syntax = \"proto3\";\n\n");
+    out.push_str("import \"google/protobuf/timestamp.proto\";\n");
+    match version {
+        Version::V1 => out.push_str("import \"context.proto\";\n\npackage contract.v1;\n\n"),
+        Version::V2 => out.push_str(concat!(
+            "import \"context_v2.proto\";\n\npackage contract.v2;\n\n",
+            "// Same messages and field numbers as contract.v1, with uint256 values encoded as 32-byte\n",
+            "// big-endian `bytes` (so they sort numerically, empty if the v1 value isn't a uint256)\n",
+            "// and addresses as EIP-55 checksummed `0x` strings.\n\n",
+        )),
+    }
+
+    out.push_str("// Fields of the containers are only filled when the module is built with the features in\n");
+    out.push_str("// their comment, and stay empty otherwise. They are declared in every build, so that\n");
+    out.push_str("// modules built with different features share one schema.\n");
+    out.push_str("message Events {\n");
+    let mut tag = 0;
+    for contract in contracts {
+        for event in &contract.events {
+            tag += 1;
+            writeln!(
+                out,
+                "    repeated {}_{} {}_{} = {}; // {}",
+                contract.prefix(),
+                event.name,
+                contract.name,
+                event.plural(),
+                tag,
+                features("events", event.admin)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("}\n\nmessage Calls {\n");
+    let mut tag = 0;
+    for contract in contracts {
+        for function in &contract.functions {
+            tag += 1;
+            writeln!(
+                out,
+                "    repeated {}_{}Call {}_call_{} = {}; // {}",
+                contract.prefix(),
+                function.name,
+                contract.name,
+                function.plural,
+                tag,
+                features("calls", function.admin)
+            )
+            .unwrap();
+        }
+    }
+    out.push_str("}\n\nmessage EventsCalls {\n    Events events = 1;\n    Calls calls = 2;\n}\n");
+
+    for contract in contracts {
+        writeln!(out, "\n// {} EVENT MESSAGES", contract.name.to_uppercase()).unwrap();
+        for (i, event) in contract.events.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            writeln!(out, "message {}_{} {{", contract.prefix(), event.name).unwrap();
+            let mut fields = Fields::new(contract, version);
+            fields.push("string", "evt_tx_hash", None);
+            fields.push("uint32", "evt_index", None);
+            fields.push("google.protobuf.Timestamp", "evt_block_time", None);
+            fields.push("uint64", "evt_block_number", None);
+            for param in &event.params {
+                fields.param(param);
+            }
+            fields.push("TxContext", "evt_tx_context", None);
+            fields.push("uint64", "evt_ordinal", None);
+            fields.push("string", "evt_block_hash", None);
+            out.push_str(&fields.out);
+            out.push_str("}\n");
+        }
+
+        writeln!(out, "\n// {} CALL MESSAGES", contract.name.to_uppercase()).unwrap();
+        for strukt in &contract.structs {
+            writeln!(out, "// {} tuple taken by {}", strukt.name, strukt.used_by.join(", ")).unwrap();
+            writeln!(out, "message {}_{} {{", contract.prefix(), strukt.name).unwrap();
+            let mut fields = Fields::new(contract, version);
+            for field in &strukt.fields {
+                fields.param(field);
+            }
+            out.push_str(&fields.out);
+            out.push_str("}\n\n");
+        }
+        for (i, function) in contract.functions.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            writeln!(out, "message {}_{}Call {{", contract.prefix(), function.name).unwrap();
+            let mut fields = Fields::new(contract, version);
+            fields.push("string", "call_tx_hash", None);
+            fields.push("google.protobuf.Timestamp", "call_block_time", None);
+            fields.push("uint64", "call_block_number", None);
+            fields.push("uint64", "call_ordinal", None);
+            fields.push("bool", "call_success", None);
+            for param in function.inputs.iter().filter(|p| !p.kind.has_tuple()) {
+                fields.param(param);
+            }
+            for param in function.inputs.iter().filter(|p| p.kind.has_tuple()) {
+                fields.param(param);
+            }
+            for output in &function.outputs {
+                fields.output(output);
+            }
+            fields.push("TxContext", "call_tx_context", None);
+            fields.push("CallContext", "call_context", None);
+            fields.push("string", "call_block_hash", None);
+            out.push_str(&fields.out);
+            out.push_str("}\n");
+        }
+    }
+
+    out
+}
+
+fn features(kind: &str, admin: bool) -> String {
+    format!("`{}` + `{}`", kind, if admin { "admin" } else { "trading" })
+}
+
+struct Fields<'a> {
+    contract: &'a Contract,
+    version: Version,
+    tag: u32,
+    out: String,
+}
+
+impl<'a> Fields<'a> {
+    fn new(contract: &'a Contract, version: Version) -> Self {
+        Fields { contract, version, tag: 0, out: String::new() }
+    }
+
+    fn push(&mut self, ty: &str, name: &str, comment: Option<String>) {
+        self.tag += 1;
+        write!(self.out, "    {} {} = {};", ty, name, self.tag).unwrap();
+        if let Some(comment) = comment {
+            write!(self.out, " // {}", comment).unwrap();
+        }
+        self.out.push('\n');
+    }
+
+    fn param(&mut self, param: &Param) {
+        let ty = proto_type(self.contract, &param.kind, self.version);
+        self.push(&ty, &param.name, comment(param));
+    }
+
+    fn output(&mut self, output: &Param) {
+        let ty = proto_type(self.contract, &output.kind, self.version);
+        self.push(&ty, &output_field(output), comment(output));
+    }
+}
+
+/// Enum type of the param and, when its field name isn't just the snake_case form of its
+/// ABI name, that ABI name.
+fn comment(param: &Param) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(enum_type) = &param.enum_type {
+        parts.push(format!("enum {}", enum_type));
+    }
+    if param.abi_name.is_empty() {
+        parts.push("unnamed in the ABI".to_string());
+    } else if param.abi_name.contains('_') && param.abi_name != param.name {
+        parts.push(format!("`{}` in the ABI", param.abi_name));
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Proto field of a function output: `output_<name>`, or `output_param<i>` when unnamed.
+pub fn output_field(output: &Param) -> String {
+    format!("output_{}", output.name.to_snake_case())
+}
+
+fn proto_type(contract: &Contract, kind: &Kind, version: Version) -> String {
+    match kind {
+        Kind::Array(inner) | Kind::FixedArray(inner, _) => {
+            format!("repeated {}", proto_type(contract, inner, version))
+        }
+        Kind::Address if version == Version::V2 => "string".to_string(),
+        Kind::Address | Kind::Bytes | Kind::FixedBytes(_) => "bytes".to_string(),
+        Kind::Bool => "bool".to_string(),
+        Kind::String => "string".to_string(),
+        Kind::Uint(bits) if *bits <= 32 => "uint32".to_string(),
+        Kind::Uint(bits) if *bits <= 64 => "uint64".to_string(),
+        Kind::Uint(_) if version == Version::V2 => "bytes".to_string(),
+        Kind::Int(bits) if *bits <= 32 => "int32".to_string(),
+        Kind::Uint(_) | Kind::Int(_) => "string".to_string(),
+        Kind::Tuple(index) => format!("{}_{}", contract.prefix(), contract.structs[*index].name),
+    }
+}
+
+impl Kind {
+    pub fn has_tuple(&self) -> bool {
+        match self {
+            Kind::Tuple(_) => true,
+            Kind::Array(inner) | Kind::FixedArray(inner, _) => inner.has_tuple(),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#[allow(clippy::all)]
+pub mod polymarketctf_contract;
+#[allow(clippy::all)]
+pub mod ctf_contract;
+#[allow(clippy::all)]
+pub mod vault_contract;
+#[allow(clippy::all)]
+pub mod steth_contract;
+#[allow(clippy::all)]
+pub mod usdc_contract;
@@ -2157,7 +2157,7 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct GetPolyProxyWalletAddress {
-        pub u_addr: Vec<u8>,
+        pub addr: Vec<u8>,
     }
     impl GetPolyProxyWalletAddress {
         const METHOD_ID: [u8; 4] = [237u8, 239u8, 125u8, 142u8];
@@ -2175,7 +2175,7 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                u_addr: values
+                addr: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
@@ -2186,7 +2186,7 @@ pub mod functions {
         }
         pub fn encode(&self) -> Vec<u8> {
             let data = ethabi::encode(
-                &[ethabi::Token::Address(ethabi::Address::from_slice(&self.u_addr))],
+                &[ethabi::Token::Address(ethabi::Address::from_slice(&self.addr))],
             );
             let mut encoded = Vec::with_capacity(4 + data.len());
             encoded.extend(Self::METHOD_ID);
@@ -2343,7 +2343,7 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct GetSafeAddress {
-        pub u_addr: Vec<u8>,
+        pub addr: Vec<u8>,
     }
     impl GetSafeAddress {
         const METHOD_ID: [u8; 4] = [162u8, 135u8, 189u8, 241u8];
@@ -2361,7 +2361,7 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                u_addr: values
+                addr: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
@@ -2372,7 +2372,7 @@ pub mod functions {
         }
         pub fn encode(&self) -> Vec<u8> {
             let data = ethabi::encode(
-                &[ethabi::Token::Address(ethabi::Address::from_slice(&self.u_addr))],
+                &[ethabi::Token::Address(ethabi::Address::from_slice(&self.addr))],
             );
             let mut encoded = Vec::with_capacity(4 + data.len());
             encoded.extend(Self::METHOD_ID);
@@ -3942,11 +3942,11 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct OnErc1155BatchReceived {
-        pub param0: Vec<u8>,
-        pub param1: Vec<u8>,
-        pub param2: Vec<substreams::scalar::BigInt>,
-        pub param3: Vec<substreams::scalar::BigInt>,
-        pub param4: Vec<u8>,
+        pub operator: Vec<u8>,
+        pub from: Vec<u8>,
+        pub ids: Vec<substreams::scalar::BigInt>,
+        pub values: Vec<substreams::scalar::BigInt>,
+        pub data: Vec<u8>,
     }
     impl OnErc1155BatchReceived {
         const METHOD_ID: [u8; 4] = [188u8, 25u8, 124u8, 129u8];
@@ -3974,21 +3974,21 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                param0: values
+                operator: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
                     .expect(INTERNAL_ERR)
                     .as_bytes()
                     .to_vec(),
-                param1: values
+                from: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
                     .expect(INTERNAL_ERR)
                     .as_bytes()
                     .to_vec(),
-                param2: values
+                ids: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_array()
@@ -4003,7 +4003,7 @@ pub mod functions {
                         substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
                     })
                     .collect(),
-                param3: values
+                values: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_array()
@@ -4018,21 +4018,17 @@ pub mod functions {
                         substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
                     })
                     .collect(),
-                param4: values
-                    .pop()
-                    .expect(INTERNAL_ERR)
-                    .into_bytes()
-                    .expect(INTERNAL_ERR),
+                data: values.pop().expect(INTERNAL_ERR).into_bytes().expect(INTERNAL_ERR),
             })
         }
         pub fn encode(&self) -> Vec<u8> {
             let data = ethabi::encode(
                 &[
-                    ethabi::Token::Address(ethabi::Address::from_slice(&self.param0)),
-                    ethabi::Token::Address(ethabi::Address::from_slice(&self.param1)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.operator)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.from)),
                     {
                         let v = self
-                            .param2
+                            .ids
                             .iter()
                             .map(|inner| ethabi::Token::Uint(
                                 ethabi::Uint::from_big_endian(
@@ -4051,7 +4047,7 @@ pub mod functions {
                     },
                     {
                         let v = self
-                            .param3
+                            .values
                             .iter()
                             .map(|inner| ethabi::Token::Uint(
                                 ethabi::Uint::from_big_endian(
@@ -4068,7 +4064,7 @@ pub mod functions {
                             .collect();
                         ethabi::Token::Array(v)
                     },
-                    ethabi::Token::Bytes(self.param4.clone()),
+                    ethabi::Token::Bytes(self.data.clone()),
                 ],
             );
             let mut encoded = Vec::with_capacity(4 + data.len());
@@ -4149,11 +4145,11 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct OnErc1155Received {
-        pub param0: Vec<u8>,
-        pub param1: Vec<u8>,
-        pub param2: substreams::scalar::BigInt,
-        pub param3: substreams::scalar::BigInt,
-        pub param4: Vec<u8>,
+        pub operator: Vec<u8>,
+        pub from: Vec<u8>,
+        pub id: substreams::scalar::BigInt,
+        pub value: substreams::scalar::BigInt,
+        pub data: Vec<u8>,
     }
     impl OnErc1155Received {
         const METHOD_ID: [u8; 4] = [242u8, 58u8, 110u8, 97u8];
@@ -4177,21 +4173,21 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                param0: values
+                operator: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
                     .expect(INTERNAL_ERR)
                     .as_bytes()
                     .to_vec(),
-                param1: values
+                from: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
                     .expect(INTERNAL_ERR)
                     .as_bytes()
                     .to_vec(),
-                param2: {
+                id: {
                     let mut v = [0 as u8; 32];
                     values
                         .pop()
@@ -4201,7 +4197,7 @@ pub mod functions {
                         .to_big_endian(v.as_mut_slice());
                     substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
                 },
-                param3: {
+                value: {
                     let mut v = [0 as u8; 32];
                     values
                         .pop()
@@ -4211,21 +4207,17 @@ pub mod functions {
                         .to_big_endian(v.as_mut_slice());
                     substreams::scalar::BigInt::from_unsigned_bytes_be(&v)
                 },
-                param4: values
-                    .pop()
-                    .expect(INTERNAL_ERR)
-                    .into_bytes()
-                    .expect(INTERNAL_ERR),
+                data: values.pop().expect(INTERNAL_ERR).into_bytes().expect(INTERNAL_ERR),
             })
         }
         pub fn encode(&self) -> Vec<u8> {
             let data = ethabi::encode(
                 &[
-                    ethabi::Token::Address(ethabi::Address::from_slice(&self.param0)),
-                    ethabi::Token::Address(ethabi::Address::from_slice(&self.param1)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.operator)),
+                    ethabi::Token::Address(ethabi::Address::from_slice(&self.from)),
                     ethabi::Token::Uint(
                         ethabi::Uint::from_big_endian(
-                            match self.param2.clone().to_bytes_be() {
+                            match self.id.clone().to_bytes_be() {
                                 (num_bigint::Sign::Plus, bytes) => bytes,
                                 (num_bigint::Sign::NoSign, bytes) => bytes,
                                 (num_bigint::Sign::Minus, _) => {
@@ -4237,7 +4229,7 @@ pub mod functions {
                     ),
                     ethabi::Token::Uint(
                         ethabi::Uint::from_big_endian(
-                            match self.param3.clone().to_bytes_be() {
+                            match self.value.clone().to_bytes_be() {
                                 (num_bigint::Sign::Plus, bytes) => bytes,
                                 (num_bigint::Sign::NoSign, bytes) => bytes,
                                 (num_bigint::Sign::Minus, _) => {
@@ -4247,7 +4239,7 @@ pub mod functions {
                                 .as_slice(),
                         ),
                     ),
-                    ethabi::Token::Bytes(self.param4.clone()),
+                    ethabi::Token::Bytes(self.data.clone()),
                 ],
             );
             let mut encoded = Vec::with_capacity(4 + data.len());
@@ -5379,7 +5371,7 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct SetProxyFactory {
-        pub u_new_proxy_factory: Vec<u8>,
+        pub new_proxy_factory: Vec<u8>,
     }
     impl SetProxyFactory {
         const METHOD_ID: [u8; 4] = [251u8, 221u8, 215u8, 81u8];
@@ -5397,7 +5389,7 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                u_new_proxy_factory: values
+                new_proxy_factory: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
@@ -5410,7 +5402,7 @@ pub mod functions {
             let data = ethabi::encode(
                 &[
                     ethabi::Token::Address(
-                        ethabi::Address::from_slice(&self.u_new_proxy_factory),
+                        ethabi::Address::from_slice(&self.new_proxy_factory),
                     ),
                 ],
             );
@@ -5442,7 +5434,7 @@ pub mod functions {
     }
     #[derive(Debug, Clone, PartialEq)]
     pub struct SetSafeFactory {
-        pub u_new_safe_factory: Vec<u8>,
+        pub new_safe_factory: Vec<u8>,
     }
     impl SetSafeFactory {
         const METHOD_ID: [u8; 4] = [69u8, 68u8, 240u8, 85u8];
@@ -5460,7 +5452,7 @@ pub mod functions {
                 .map_err(|e| format!("unable to decode call.input: {:?}", e))?;
             values.reverse();
             Ok(Self {
-                u_new_safe_factory: values
+                new_safe_factory: values
                     .pop()
                     .expect(INTERNAL_ERR)
                     .into_address()
@@ -5473,7 +5465,7 @@ pub mod functions {
             let data = ethabi::encode(
                 &[
                     ethabi::Token::Address(
-                        ethabi::Address::from_slice(&self.u_new_safe_factory),
+                        ethabi::Address::from_slice(&self.new_safe_factory),
                     ),
                 ],
             );
@@ -0,0 +1,46 @@
+use crate::pb::contract::{v1, v2};
+use crate::v2::{address, uint256};
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub fn tx_context(tx: &eth::TransactionTrace) -> v1::TxContext {
+    v1::TxContext {
+        index: tx.index,
+        from: tx.from.clone(),
+        to: tx.to.clone(),
+        nonce: tx.nonce,
+        gas_price: tx.gas_price.as_ref().map(|p| BigInt::from_unsigned_bytes_be(&p.bytes).to_string()).unwrap_or_default(),
+        gas_used: tx.gas_used,
+        status: tx.status,
+    }
+}
+
+pub fn call_context(call: &eth::Call) -> v1::CallContext {
+    v1::CallContext {
+        caller: call.caller.clone(),
+        depth: call.depth,
+        call_type: call.call_type,
+        gas_consumed: call.gas_consumed,
+    }
+}
+
+pub fn tx_context_v2(v1: v1::TxContext) -> v2::TxContext {
+    v2::TxContext {
+        index: v1.index,
+        from: address(&v1.from),
+        to: address(&v1.to),
+        nonce: v1.nonce,
+        gas_price: uint256(&v1.gas_price).unwrap_or_default(),
+        gas_used: v1.gas_used,
+        status: v1.status,
+    }
+}
+
+pub fn call_context_v2(v1: v1::CallContext) -> v2::CallContext {
+    v2::CallContext {
+        caller: address(&v1.caller),
+        depth: v1.depth,
+        call_type: v1.call_type,
+        gas_consumed: v1.gas_consumed,
+    }
+}
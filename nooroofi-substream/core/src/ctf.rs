@@ -0,0 +1,53 @@
+//! Position ids of the conditional tokens, as its `getCollectionId` / `getPositionId` compute
+//! them, to tell which outcome of a condition an outcome token of the exchange stands for.
+
+use crate::order::keccak;
+use crate::v2::uint256;
+use hex_literal::hex;
+use num_bigint::BigUint;
+
+/// Collateral of the tracked exchange (USDC.e), backing all of its outcome tokens.
+pub const EXCHANGE_COLLATERAL: [u8; 20] = hex!("2791bca1f2de4661ed88a30c99a7a9449aa84174");
+
+/// Field modulus of alt_bn128, the curve collection ids are points of.
+const P: &[u8] = b"21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// Collection id of the outcomes in `index_set` of a top-level condition (no parent collection).
+pub fn collection_id(condition_id: &[u8], index_set: u64) -> [u8; 32] {
+    let p = BigUint::parse_bytes(P, 10).unwrap();
+    let hash = keccak(&[condition_id, &uint256(&index_set.to_string()).expect("a u64 is a uint256")].concat());
+    let odd = hash[0] & 0x80 != 0;
+
+    // The first x past the hash on the curve y² = x³ + 3.
+    let exponent = (&p + 1u32) / 4u32;
+    let mut x = BigUint::from_bytes_be(&hash);
+    loop {
+        x = (x + 1u32) % &p;
+        let yy = (&x * &x % &p * &x + 3u32) % &p;
+        let y = yy.modpow(&exponent, &p);
+        if &y * &y % &p == yy {
+            break;
+        }
+    }
+
+    // The contract picks the y of the hash's parity and keeps that parity in bit 254.
+    if odd {
+        x.set_bit(254, true);
+    }
+    let bytes = x.to_bytes_be();
+    let mut id = [0u8; 32];
+    id[32 - bytes.len()..].copy_from_slice(&bytes);
+    id
+}
+
+/// Position id, the ERC1155 token id, of a collection backed by `collateral`.
+pub fn position_id(collateral: &[u8], collection_id: &[u8; 32]) -> String {
+    BigUint::from_bytes_be(&keccak(&[collateral, collection_id.as_slice()].concat())).to_string()
+}
+
+/// Index of the outcome `token_id` stands for among the `outcome_slot_count` outcomes of a
+/// top-level condition, or `None` when it isn't one of its single-outcome positions.
+pub fn outcome_index(collateral: &[u8], condition_id: &[u8], outcome_slot_count: usize, token_id: &str) -> Option<usize> {
+    (0..outcome_slot_count.min(64))
+        .find(|&index| position_id(collateral, &collection_id(condition_id, 1 << index)) == token_id)
+}
@@ -0,0 +1,20 @@
+//! Decoding of the nooroo contracts, shared by the substreams modules and the backend
+//! services: the ABI bindings, the `contract.v1` / `contract.v2` messages and the mappers
+//! filling them, plus order hashing, position ids, price normalization and the address of the
+//! vault's token.
+
+pub mod abi;
+mod context;
+pub mod ctf;
+pub mod mappers;
+pub mod order;
+#[allow(clippy::all)]
+pub mod pb;
+pub mod price;
+pub mod v2;
+pub mod vault;
+
+pub use mappers::polymarketctf::{
+    decode_call, decode_log, Call as ExchangeCall, Event as ExchangeEvent, TRACKED_CONTRACT as EXCHANGE,
+};
+pub use mappers::ctf::TRACKED_CONTRACT as CONDITIONAL_TOKENS;
@@ -0,0 +1,787 @@
+// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.
+
+// Builds without every feature leave the decoding of the disabled entries unused.
+#![cfg_attr(
+    not(all(feature = "events", feature = "calls", feature = "admin", feature = "trading")),
+    allow(dead_code, unused_imports, unused_variables)
+)]
+
+use crate::abi;
+use crate::context::{call_context, call_context_v2, tx_context, tx_context_v2};
+use crate::pb::contract::{v1, v2};
+use crate::v2::{address, uint256};
+use hex_literal::hex;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event as _;
+
+pub const TRACKED_CONTRACT: [u8; 20] = hex!("4d97dcd97ec945f40cf65f87097ace5ea0476045");
+
+/// An event of the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Event {
+    #[cfg(all(feature = "events", feature = "trading"))]
+    ApprovalForAll(v1::CtfApprovalForAll),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    ConditionPreparation(v1::CtfConditionPreparation),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    ConditionResolution(v1::CtfConditionResolution),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    PayoutRedemption(v1::CtfPayoutRedemption),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    PositionSplit(v1::CtfPositionSplit),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    PositionsMerge(v1::CtfPositionsMerge),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    TransferBatch(v1::CtfTransferBatch),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    TransferSingle(v1::CtfTransferSingle),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    Uri(v1::CtfUri),
+}
+
+/// A call to the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Call {
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    MergePositions(v1::CtfMergePositionsCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    PrepareCondition(v1::CtfPrepareConditionCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    RedeemPositions(v1::CtfRedeemPositionsCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    ReportPayouts(v1::CtfReportPayoutsCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    SafeBatchTransferFrom(v1::CtfSafeBatchTransferFromCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    SafeTransferFrom(v1::CtfSafeTransferFromCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    SetApprovalForAll(v1::CtfSetApprovalForAllCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    SplitPosition(v1::CtfSplitPositionCall),
+}
+
+/// Decodes a log emitted by the contract. Only the params, `evt_index` and `evt_ordinal`
+/// are set, the transaction and block fields are left to the caller.
+pub fn decode_log(log: &eth::Log) -> Option<Event> {
+    if log.address != TRACKED_CONTRACT {
+        return None;
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::ApprovalForAll::match_and_decode(log) {
+        return Some(Event::ApprovalForAll(v1::CtfApprovalForAll {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            owner: event.owner,
+            operator: event.operator,
+            approved: event.approved,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::ConditionPreparation::match_and_decode(log) {
+        return Some(Event::ConditionPreparation(v1::CtfConditionPreparation {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            condition_id: Vec::from(event.condition_id),
+            oracle: event.oracle,
+            question_id: Vec::from(event.question_id),
+            outcome_slot_count: event.outcome_slot_count.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::ConditionResolution::match_and_decode(log) {
+        return Some(Event::ConditionResolution(v1::CtfConditionResolution {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            condition_id: Vec::from(event.condition_id),
+            oracle: event.oracle,
+            question_id: Vec::from(event.question_id),
+            outcome_slot_count: event.outcome_slot_count.to_string(),
+            payout_numerators: event.payout_numerators.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::PayoutRedemption::match_and_decode(log) {
+        return Some(Event::PayoutRedemption(v1::CtfPayoutRedemption {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            redeemer: event.redeemer,
+            collateral_token: event.collateral_token,
+            parent_collection_id: Vec::from(event.parent_collection_id),
+            condition_id: Vec::from(event.condition_id),
+            index_sets: event.index_sets.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            payout: event.payout.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::PositionSplit::match_and_decode(log) {
+        return Some(Event::PositionSplit(v1::CtfPositionSplit {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            stakeholder: event.stakeholder,
+            collateral_token: event.collateral_token,
+            parent_collection_id: Vec::from(event.parent_collection_id),
+            condition_id: Vec::from(event.condition_id),
+            partition: event.partition.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            amount: event.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::PositionsMerge::match_and_decode(log) {
+        return Some(Event::PositionsMerge(v1::CtfPositionsMerge {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            stakeholder: event.stakeholder,
+            collateral_token: event.collateral_token,
+            parent_collection_id: Vec::from(event.parent_collection_id),
+            condition_id: Vec::from(event.condition_id),
+            partition: event.partition.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            amount: event.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::TransferBatch::match_and_decode(log) {
+        return Some(Event::TransferBatch(v1::CtfTransferBatch {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            operator: event.operator,
+            from: event.from,
+            to: event.to,
+            ids: event.ids.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            values: event.values.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::TransferSingle::match_and_decode(log) {
+        return Some(Event::TransferSingle(v1::CtfTransferSingle {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            operator: event.operator,
+            from: event.from,
+            to: event.to,
+            id: event.id.to_string(),
+            value: event.value.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::ctf_contract::events::Uri::match_and_decode(log) {
+        return Some(Event::Uri(v1::CtfUri {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            value: event.value,
+            id: event.id.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+/// Decodes a call to the contract, reverted ones included. Only the inputs, outputs,
+/// `call_ordinal`, `call_success` and `call_context` are set, the transaction and block
+/// fields are left to the caller.
+pub fn decode_call(call: &eth::Call) -> Option<Call> {
+    if call.address != TRACKED_CONTRACT {
+        return None;
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::MergePositions::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::MergePositions::decode(call).ok()?;
+        return Some(Call::MergePositions(v1::CtfMergePositionsCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            collateral_token: decoded_call.collateral_token,
+            parent_collection_id: Vec::from(decoded_call.parent_collection_id),
+            condition_id: Vec::from(decoded_call.condition_id),
+            partition: decoded_call.partition.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            amount: decoded_call.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::PrepareCondition::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::PrepareCondition::decode(call).ok()?;
+        return Some(Call::PrepareCondition(v1::CtfPrepareConditionCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            oracle: decoded_call.oracle,
+            question_id: Vec::from(decoded_call.question_id),
+            outcome_slot_count: decoded_call.outcome_slot_count.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::RedeemPositions::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::RedeemPositions::decode(call).ok()?;
+        return Some(Call::RedeemPositions(v1::CtfRedeemPositionsCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            collateral_token: decoded_call.collateral_token,
+            parent_collection_id: Vec::from(decoded_call.parent_collection_id),
+            condition_id: Vec::from(decoded_call.condition_id),
+            index_sets: decoded_call.index_sets.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::ReportPayouts::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::ReportPayouts::decode(call).ok()?;
+        return Some(Call::ReportPayouts(v1::CtfReportPayoutsCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            question_id: Vec::from(decoded_call.question_id),
+            payouts: decoded_call.payouts.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::SafeBatchTransferFrom::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::SafeBatchTransferFrom::decode(call).ok()?;
+        return Some(Call::SafeBatchTransferFrom(v1::CtfSafeBatchTransferFromCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            ids: decoded_call.ids.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            values: decoded_call.values.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            data: decoded_call.data,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::SafeTransferFrom::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::SafeTransferFrom::decode(call).ok()?;
+        return Some(Call::SafeTransferFrom(v1::CtfSafeTransferFromCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            id: decoded_call.id.to_string(),
+            value: decoded_call.value.to_string(),
+            data: decoded_call.data,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::SetApprovalForAll::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::SetApprovalForAll::decode(call).ok()?;
+        return Some(Call::SetApprovalForAll(v1::CtfSetApprovalForAllCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            operator: decoded_call.operator,
+            approved: decoded_call.approved,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::ctf_contract::functions::SplitPosition::match_call(call) {
+        let decoded_call = abi::ctf_contract::functions::SplitPosition::decode(call).ok()?;
+        return Some(Call::SplitPosition(v1::CtfSplitPositionCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            collateral_token: decoded_call.collateral_token,
+            parent_collection_id: Vec::from(decoded_call.parent_collection_id),
+            condition_id: Vec::from(decoded_call.condition_id),
+            partition: decoded_call.partition.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            amount: decoded_call.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+pub fn map_events(blk: &eth::Block, events: &mut v1::Events) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(event) = decode_log(log) else {
+                continue;
+            };
+            match event {
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::ApprovalForAll(event) => events.ctf_approval_for_alls.push(v1::CtfApprovalForAll {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::ConditionPreparation(event) => events.ctf_condition_preparations.push(v1::CtfConditionPreparation {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::ConditionResolution(event) => events.ctf_condition_resolutions.push(v1::CtfConditionResolution {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::PayoutRedemption(event) => events.ctf_payout_redemptions.push(v1::CtfPayoutRedemption {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::PositionSplit(event) => events.ctf_position_splits.push(v1::CtfPositionSplit {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::PositionsMerge(event) => events.ctf_positions_merges.push(v1::CtfPositionsMerge {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::TransferBatch(event) => events.ctf_transfer_batches.push(v1::CtfTransferBatch {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::TransferSingle(event) => events.ctf_transfer_singles.push(v1::CtfTransferSingle {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::Uri(event) => events.ctf_uris.push(v1::CtfUri {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+            }
+        }
+    }
+}
+
+pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls) {
+    for tx in blk.transactions() {
+        for call in tx.calls.iter() {
+            let Some(decoded_call) = decode_call(call) else {
+                continue;
+            };
+            match decoded_call {
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::MergePositions(decoded_call) => calls.ctf_call_merge_positions.push(v1::CtfMergePositionsCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::PrepareCondition(decoded_call) => calls.ctf_call_prepare_conditions.push(v1::CtfPrepareConditionCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::RedeemPositions(decoded_call) => calls.ctf_call_redeem_positions.push(v1::CtfRedeemPositionsCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::ReportPayouts(decoded_call) => calls.ctf_call_report_payouts.push(v1::CtfReportPayoutsCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::SafeBatchTransferFrom(decoded_call) => calls.ctf_call_safe_batch_transfer_froms.push(v1::CtfSafeBatchTransferFromCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::SafeTransferFrom(decoded_call) => calls.ctf_call_safe_transfer_froms.push(v1::CtfSafeTransferFromCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::SetApprovalForAll(decoded_call) => calls.ctf_call_set_approval_for_alls.push(v1::CtfSetApprovalForAllCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::SplitPosition(decoded_call) => calls.ctf_call_split_positions.push(v1::CtfSplitPositionCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+            }
+        }
+    }
+}
+
+pub(super) fn approval_for_all_v2(v1: v1::CtfApprovalForAll) -> v2::CtfApprovalForAll {
+    v2::CtfApprovalForAll {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        owner: address(&v1.owner),
+        operator: address(&v1.operator),
+        approved: v1.approved,
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn condition_preparation_v2(v1: v1::CtfConditionPreparation) -> v2::CtfConditionPreparation {
+    v2::CtfConditionPreparation {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        condition_id: v1.condition_id,
+        oracle: address(&v1.oracle),
+        question_id: v1.question_id,
+        outcome_slot_count: uint256(&v1.outcome_slot_count).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn condition_resolution_v2(v1: v1::CtfConditionResolution) -> v2::CtfConditionResolution {
+    v2::CtfConditionResolution {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        condition_id: v1.condition_id,
+        oracle: address(&v1.oracle),
+        question_id: v1.question_id,
+        outcome_slot_count: uint256(&v1.outcome_slot_count).unwrap_or_default(),
+        payout_numerators: v1.payout_numerators.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn payout_redemption_v2(v1: v1::CtfPayoutRedemption) -> v2::CtfPayoutRedemption {
+    v2::CtfPayoutRedemption {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        redeemer: address(&v1.redeemer),
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        index_sets: v1.index_sets.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        payout: uint256(&v1.payout).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn position_split_v2(v1: v1::CtfPositionSplit) -> v2::CtfPositionSplit {
+    v2::CtfPositionSplit {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        stakeholder: address(&v1.stakeholder),
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        partition: v1.partition.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn positions_merge_v2(v1: v1::CtfPositionsMerge) -> v2::CtfPositionsMerge {
+    v2::CtfPositionsMerge {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        stakeholder: address(&v1.stakeholder),
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        partition: v1.partition.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn transfer_batch_v2(v1: v1::CtfTransferBatch) -> v2::CtfTransferBatch {
+    v2::CtfTransferBatch {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        operator: address(&v1.operator),
+        from: address(&v1.from),
+        to: address(&v1.to),
+        ids: v1.ids.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        values: v1.values.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn transfer_single_v2(v1: v1::CtfTransferSingle) -> v2::CtfTransferSingle {
+    v2::CtfTransferSingle {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        operator: address(&v1.operator),
+        from: address(&v1.from),
+        to: address(&v1.to),
+        id: uint256(&v1.id).unwrap_or_default(),
+        value: uint256(&v1.value).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn uri_v2(v1: v1::CtfUri) -> v2::CtfUri {
+    v2::CtfUri {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        value: v1.value,
+        id: uint256(&v1.id).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn merge_positions_call_v2(v1: v1::CtfMergePositionsCall) -> v2::CtfMergePositionsCall {
+    v2::CtfMergePositionsCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        partition: v1.partition.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn prepare_condition_call_v2(v1: v1::CtfPrepareConditionCall) -> v2::CtfPrepareConditionCall {
+    v2::CtfPrepareConditionCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        oracle: address(&v1.oracle),
+        question_id: v1.question_id,
+        outcome_slot_count: uint256(&v1.outcome_slot_count).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn redeem_positions_call_v2(v1: v1::CtfRedeemPositionsCall) -> v2::CtfRedeemPositionsCall {
+    v2::CtfRedeemPositionsCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        index_sets: v1.index_sets.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn report_payouts_call_v2(v1: v1::CtfReportPayoutsCall) -> v2::CtfReportPayoutsCall {
+    v2::CtfReportPayoutsCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        question_id: v1.question_id,
+        payouts: v1.payouts.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn safe_batch_transfer_from_call_v2(v1: v1::CtfSafeBatchTransferFromCall) -> v2::CtfSafeBatchTransferFromCall {
+    v2::CtfSafeBatchTransferFromCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        from: address(&v1.from),
+        to: address(&v1.to),
+        ids: v1.ids.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        values: v1.values.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        data: v1.data,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn safe_transfer_from_call_v2(v1: v1::CtfSafeTransferFromCall) -> v2::CtfSafeTransferFromCall {
+    v2::CtfSafeTransferFromCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        from: address(&v1.from),
+        to: address(&v1.to),
+        id: uint256(&v1.id).unwrap_or_default(),
+        value: uint256(&v1.value).unwrap_or_default(),
+        data: v1.data,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn set_approval_for_all_call_v2(v1: v1::CtfSetApprovalForAllCall) -> v2::CtfSetApprovalForAllCall {
+    v2::CtfSetApprovalForAllCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        operator: address(&v1.operator),
+        approved: v1.approved,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn split_position_call_v2(v1: v1::CtfSplitPositionCall) -> v2::CtfSplitPositionCall {
+    v2::CtfSplitPositionCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        collateral_token: address(&v1.collateral_token),
+        parent_collection_id: v1.parent_collection_id,
+        condition_id: v1.condition_id,
+        partition: v1.partition.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
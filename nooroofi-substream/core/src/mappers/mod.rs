@@ -0,0 +1,97 @@
+// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.
+
+pub mod polymarketctf;
+pub mod ctf;
+pub mod vault;
+pub mod steth;
+pub mod usdc;
+
+use crate::pb::contract::{v1, v2};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Decodes the events of every contract tracked at a fixed address.
+pub fn map_events(blk: &eth::Block, events: &mut v1::Events) {
+    polymarketctf::map_events(blk, events);
+    ctf::map_events(blk, events);
+}
+
+/// Decodes the calls to every contract tracked at a fixed address, reverted ones included.
+pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls) {
+    polymarketctf::map_calls(blk, calls);
+    ctf::map_calls(blk, calls);
+}
+
+pub fn events_v2(v1: v1::Events) -> v2::Events {
+    v2::Events {
+        polymarketctf_fee_chargeds: v1.polymarketctf_fee_chargeds.into_iter().map(polymarketctf::fee_charged_v2).collect(),
+        polymarketctf_new_admins: v1.polymarketctf_new_admins.into_iter().map(polymarketctf::new_admin_v2).collect(),
+        polymarketctf_new_operators: v1.polymarketctf_new_operators.into_iter().map(polymarketctf::new_operator_v2).collect(),
+        polymarketctf_order_cancelleds: v1.polymarketctf_order_cancelleds.into_iter().map(polymarketctf::order_cancelled_v2).collect(),
+        polymarketctf_order_filleds: v1.polymarketctf_order_filleds.into_iter().map(polymarketctf::order_filled_v2).collect(),
+        polymarketctf_orders_matcheds: v1.polymarketctf_orders_matcheds.into_iter().map(polymarketctf::orders_matched_v2).collect(),
+        polymarketctf_proxy_factory_updateds: v1.polymarketctf_proxy_factory_updateds.into_iter().map(polymarketctf::proxy_factory_updated_v2).collect(),
+        polymarketctf_removed_admins: v1.polymarketctf_removed_admins.into_iter().map(polymarketctf::removed_admin_v2).collect(),
+        polymarketctf_removed_operators: v1.polymarketctf_removed_operators.into_iter().map(polymarketctf::removed_operator_v2).collect(),
+        polymarketctf_safe_factory_updateds: v1.polymarketctf_safe_factory_updateds.into_iter().map(polymarketctf::safe_factory_updated_v2).collect(),
+        polymarketctf_token_registereds: v1.polymarketctf_token_registereds.into_iter().map(polymarketctf::token_registered_v2).collect(),
+        polymarketctf_trading_pauseds: v1.polymarketctf_trading_pauseds.into_iter().map(polymarketctf::trading_paused_v2).collect(),
+        polymarketctf_trading_unpauseds: v1.polymarketctf_trading_unpauseds.into_iter().map(polymarketctf::trading_unpaused_v2).collect(),
+        ctf_approval_for_alls: v1.ctf_approval_for_alls.into_iter().map(ctf::approval_for_all_v2).collect(),
+        ctf_condition_preparations: v1.ctf_condition_preparations.into_iter().map(ctf::condition_preparation_v2).collect(),
+        ctf_condition_resolutions: v1.ctf_condition_resolutions.into_iter().map(ctf::condition_resolution_v2).collect(),
+        ctf_payout_redemptions: v1.ctf_payout_redemptions.into_iter().map(ctf::payout_redemption_v2).collect(),
+        ctf_position_splits: v1.ctf_position_splits.into_iter().map(ctf::position_split_v2).collect(),
+        ctf_positions_merges: v1.ctf_positions_merges.into_iter().map(ctf::positions_merge_v2).collect(),
+        ctf_transfer_batches: v1.ctf_transfer_batches.into_iter().map(ctf::transfer_batch_v2).collect(),
+        ctf_transfer_singles: v1.ctf_transfer_singles.into_iter().map(ctf::transfer_single_v2).collect(),
+        ctf_uris: v1.ctf_uris.into_iter().map(ctf::uri_v2).collect(),
+        vault_deposits: v1.vault_deposits.into_iter().map(vault::deposit_v2).collect(),
+        steth_approvals: v1.steth_approvals.into_iter().map(steth::approval_v2).collect(),
+        steth_transfers: v1.steth_transfers.into_iter().map(steth::transfer_v2).collect(),
+        usdc_approvals: v1.usdc_approvals.into_iter().map(usdc::approval_v2).collect(),
+        usdc_transfers: v1.usdc_transfers.into_iter().map(usdc::transfer_v2).collect(),
+    }
+}
+
+pub fn calls_v2(v1: v1::Calls) -> v2::Calls {
+    v2::Calls {
+        polymarketctf_call_add_admins: v1.polymarketctf_call_add_admins.into_iter().map(polymarketctf::add_admin_call_v2).collect(),
+        polymarketctf_call_add_operators: v1.polymarketctf_call_add_operators.into_iter().map(polymarketctf::add_operator_call_v2).collect(),
+        polymarketctf_call_cancel_orders: v1.polymarketctf_call_cancel_orders.into_iter().map(polymarketctf::cancel_order_call_v2).collect(),
+        polymarketctf_call_cancel_orders_batch: v1.polymarketctf_call_cancel_orders_batch.into_iter().map(polymarketctf::cancel_orders_call_v2).collect(),
+        polymarketctf_call_fill_orders: v1.polymarketctf_call_fill_orders.into_iter().map(polymarketctf::fill_order_call_v2).collect(),
+        polymarketctf_call_fill_orders_batch: v1.polymarketctf_call_fill_orders_batch.into_iter().map(polymarketctf::fill_orders_call_v2).collect(),
+        polymarketctf_call_increment_nonces: v1.polymarketctf_call_increment_nonces.into_iter().map(polymarketctf::increment_nonce_call_v2).collect(),
+        polymarketctf_call_match_orders: v1.polymarketctf_call_match_orders.into_iter().map(polymarketctf::match_orders_call_v2).collect(),
+        polymarketctf_call_on_erc1155_batch_receiveds: v1.polymarketctf_call_on_erc1155_batch_receiveds.into_iter().map(polymarketctf::on_erc1155_batch_received_call_v2).collect(),
+        polymarketctf_call_on_erc1155_receiveds: v1.polymarketctf_call_on_erc1155_receiveds.into_iter().map(polymarketctf::on_erc1155_received_call_v2).collect(),
+        polymarketctf_call_pause_tradings: v1.polymarketctf_call_pause_tradings.into_iter().map(polymarketctf::pause_trading_call_v2).collect(),
+        polymarketctf_call_register_tokens: v1.polymarketctf_call_register_tokens.into_iter().map(polymarketctf::register_token_call_v2).collect(),
+        polymarketctf_call_remove_admins: v1.polymarketctf_call_remove_admins.into_iter().map(polymarketctf::remove_admin_call_v2).collect(),
+        polymarketctf_call_remove_operators: v1.polymarketctf_call_remove_operators.into_iter().map(polymarketctf::remove_operator_call_v2).collect(),
+        polymarketctf_call_renounce_admin_roles: v1.polymarketctf_call_renounce_admin_roles.into_iter().map(polymarketctf::renounce_admin_role_call_v2).collect(),
+        polymarketctf_call_renounce_operator_roles: v1.polymarketctf_call_renounce_operator_roles.into_iter().map(polymarketctf::renounce_operator_role_call_v2).collect(),
+        polymarketctf_call_set_proxy_factories: v1.polymarketctf_call_set_proxy_factories.into_iter().map(polymarketctf::set_proxy_factory_call_v2).collect(),
+        polymarketctf_call_set_safe_factories: v1.polymarketctf_call_set_safe_factories.into_iter().map(polymarketctf::set_safe_factory_call_v2).collect(),
+        polymarketctf_call_unpause_tradings: v1.polymarketctf_call_unpause_tradings.into_iter().map(polymarketctf::unpause_trading_call_v2).collect(),
+        ctf_call_merge_positions: v1.ctf_call_merge_positions.into_iter().map(ctf::merge_positions_call_v2).collect(),
+        ctf_call_prepare_conditions: v1.ctf_call_prepare_conditions.into_iter().map(ctf::prepare_condition_call_v2).collect(),
+        ctf_call_redeem_positions: v1.ctf_call_redeem_positions.into_iter().map(ctf::redeem_positions_call_v2).collect(),
+        ctf_call_report_payouts: v1.ctf_call_report_payouts.into_iter().map(ctf::report_payouts_call_v2).collect(),
+        ctf_call_safe_batch_transfer_froms: v1.ctf_call_safe_batch_transfer_froms.into_iter().map(ctf::safe_batch_transfer_from_call_v2).collect(),
+        ctf_call_safe_transfer_froms: v1.ctf_call_safe_transfer_froms.into_iter().map(ctf::safe_transfer_from_call_v2).collect(),
+        ctf_call_set_approval_for_alls: v1.ctf_call_set_approval_for_alls.into_iter().map(ctf::set_approval_for_all_call_v2).collect(),
+        ctf_call_split_positions: v1.ctf_call_split_positions.into_iter().map(ctf::split_position_call_v2).collect(),
+        vault_call_deposit_and_mints: v1.vault_call_deposit_and_mints.into_iter().map(vault::deposit_and_mint_call_v2).collect(),
+        vault_call_withdraw_usdcs: v1.vault_call_withdraw_usdcs.into_iter().map(vault::withdraw_usdc_call_v2).collect(),
+        steth_call_approves: v1.steth_call_approves.into_iter().map(steth::approve_call_v2).collect(),
+        steth_call_mints: v1.steth_call_mints.into_iter().map(steth::mint_call_v2).collect(),
+        steth_call_transfers: v1.steth_call_transfers.into_iter().map(steth::transfer_call_v2).collect(),
+        steth_call_transfer_froms: v1.steth_call_transfer_froms.into_iter().map(steth::transfer_from_call_v2).collect(),
+        usdc_call_approves: v1.usdc_call_approves.into_iter().map(usdc::approve_call_v2).collect(),
+        usdc_call_decrease_allowances: v1.usdc_call_decrease_allowances.into_iter().map(usdc::decrease_allowance_call_v2).collect(),
+        usdc_call_increase_allowances: v1.usdc_call_increase_allowances.into_iter().map(usdc::increase_allowance_call_v2).collect(),
+        usdc_call_transfers: v1.usdc_call_transfers.into_iter().map(usdc::transfer_call_v2).collect(),
+        usdc_call_transfer_froms: v1.usdc_call_transfer_froms.into_iter().map(usdc::transfer_from_call_v2).collect(),
+    }
+}
@@ -0,0 +1,1328 @@
+// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.
+
+// Builds without every feature leave the decoding of the disabled entries unused.
+#![cfg_attr(
+    not(all(feature = "events", feature = "calls", feature = "admin", feature = "trading")),
+    allow(dead_code, unused_imports, unused_variables)
+)]
+
+use crate::abi;
+use crate::context::{call_context, call_context_v2, tx_context, tx_context_v2};
+use crate::pb::contract::{v1, v2};
+use crate::v2::{address, uint256};
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event as _;
+
+pub const TRACKED_CONTRACT: [u8; 20] = hex!("4bfb41d5b3570defd03c39a9a4d8de6bd8b8982e");
+
+/// An event of the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Event {
+    #[cfg(all(feature = "events", feature = "trading"))]
+    FeeCharged(v1::PolymarketctfFeeCharged),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    NewAdmin(v1::PolymarketctfNewAdmin),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    NewOperator(v1::PolymarketctfNewOperator),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    OrderCancelled(v1::PolymarketctfOrderCancelled),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    OrderFilled(v1::PolymarketctfOrderFilled),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    OrdersMatched(v1::PolymarketctfOrdersMatched),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    ProxyFactoryUpdated(v1::PolymarketctfProxyFactoryUpdated),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    RemovedAdmin(v1::PolymarketctfRemovedAdmin),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    RemovedOperator(v1::PolymarketctfRemovedOperator),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    SafeFactoryUpdated(v1::PolymarketctfSafeFactoryUpdated),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    TokenRegistered(v1::PolymarketctfTokenRegistered),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    TradingPaused(v1::PolymarketctfTradingPaused),
+    #[cfg(all(feature = "events", feature = "admin"))]
+    TradingUnpaused(v1::PolymarketctfTradingUnpaused),
+}
+
+/// A call to the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Call {
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    AddAdmin(v1::PolymarketctfAddAdminCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    AddOperator(v1::PolymarketctfAddOperatorCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    CancelOrder(v1::PolymarketctfCancelOrderCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    CancelOrders(v1::PolymarketctfCancelOrdersCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    FillOrder(v1::PolymarketctfFillOrderCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    FillOrders(v1::PolymarketctfFillOrdersCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    IncrementNonce(v1::PolymarketctfIncrementNonceCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    MatchOrders(v1::PolymarketctfMatchOrdersCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    OnErc1155BatchReceived(v1::PolymarketctfOnErc1155BatchReceivedCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    OnErc1155Received(v1::PolymarketctfOnErc1155ReceivedCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    PauseTrading(v1::PolymarketctfPauseTradingCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    RegisterToken(v1::PolymarketctfRegisterTokenCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    RemoveAdmin(v1::PolymarketctfRemoveAdminCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    RemoveOperator(v1::PolymarketctfRemoveOperatorCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    RenounceAdminRole(v1::PolymarketctfRenounceAdminRoleCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    RenounceOperatorRole(v1::PolymarketctfRenounceOperatorRoleCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    SetProxyFactory(v1::PolymarketctfSetProxyFactoryCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    SetSafeFactory(v1::PolymarketctfSetSafeFactoryCall),
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    UnpauseTrading(v1::PolymarketctfUnpauseTradingCall),
+}
+
+/// Decodes a log emitted by the contract. Only the params, `evt_index` and `evt_ordinal`
+/// are set, the transaction and block fields are left to the caller.
+pub fn decode_log(log: &eth::Log) -> Option<Event> {
+    if log.address != TRACKED_CONTRACT {
+        return None;
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::polymarketctf_contract::events::FeeCharged::match_and_decode(log) {
+        return Some(Event::FeeCharged(v1::PolymarketctfFeeCharged {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            receiver: event.receiver,
+            token_id: event.token_id.to_string(),
+            amount: event.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::NewAdmin::match_and_decode(log) {
+        return Some(Event::NewAdmin(v1::PolymarketctfNewAdmin {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            new_admin_address: event.new_admin_address,
+            admin: event.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::NewOperator::match_and_decode(log) {
+        return Some(Event::NewOperator(v1::PolymarketctfNewOperator {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            new_operator_address: event.new_operator_address,
+            admin: event.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::polymarketctf_contract::events::OrderCancelled::match_and_decode(log) {
+        return Some(Event::OrderCancelled(v1::PolymarketctfOrderCancelled {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            order_hash: Vec::from(event.order_hash),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::polymarketctf_contract::events::OrderFilled::match_and_decode(log) {
+        return Some(Event::OrderFilled(v1::PolymarketctfOrderFilled {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            order_hash: Vec::from(event.order_hash),
+            maker: event.maker,
+            taker: event.taker,
+            maker_asset_id: event.maker_asset_id.to_string(),
+            taker_asset_id: event.taker_asset_id.to_string(),
+            maker_amount_filled: event.maker_amount_filled.to_string(),
+            taker_amount_filled: event.taker_amount_filled.to_string(),
+            fee: event.fee.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::polymarketctf_contract::events::OrdersMatched::match_and_decode(log) {
+        return Some(Event::OrdersMatched(v1::PolymarketctfOrdersMatched {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            taker_order_hash: Vec::from(event.taker_order_hash),
+            taker_order_maker: event.taker_order_maker,
+            maker_asset_id: event.maker_asset_id.to_string(),
+            taker_asset_id: event.taker_asset_id.to_string(),
+            maker_amount_filled: event.maker_amount_filled.to_string(),
+            taker_amount_filled: event.taker_amount_filled.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::ProxyFactoryUpdated::match_and_decode(log) {
+        return Some(Event::ProxyFactoryUpdated(v1::PolymarketctfProxyFactoryUpdated {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            old_proxy_factory: event.old_proxy_factory,
+            new_proxy_factory: event.new_proxy_factory,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::RemovedAdmin::match_and_decode(log) {
+        return Some(Event::RemovedAdmin(v1::PolymarketctfRemovedAdmin {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            removed_admin: event.removed_admin,
+            admin: event.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::RemovedOperator::match_and_decode(log) {
+        return Some(Event::RemovedOperator(v1::PolymarketctfRemovedOperator {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            removed_operator: event.removed_operator,
+            admin: event.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::SafeFactoryUpdated::match_and_decode(log) {
+        return Some(Event::SafeFactoryUpdated(v1::PolymarketctfSafeFactoryUpdated {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            old_safe_factory: event.old_safe_factory,
+            new_safe_factory: event.new_safe_factory,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::polymarketctf_contract::events::TokenRegistered::match_and_decode(log) {
+        return Some(Event::TokenRegistered(v1::PolymarketctfTokenRegistered {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            token0: event.token0.to_string(),
+            token1: event.token1.to_string(),
+            condition_id: Vec::from(event.condition_id),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::TradingPaused::match_and_decode(log) {
+        return Some(Event::TradingPaused(v1::PolymarketctfTradingPaused {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            pauser: event.pauser,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "admin"))]
+    if let Some(event) = abi::polymarketctf_contract::events::TradingUnpaused::match_and_decode(log) {
+        return Some(Event::TradingUnpaused(v1::PolymarketctfTradingUnpaused {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            pauser: event.pauser,
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+/// Decodes a call to the contract, reverted ones included. Only the inputs, outputs,
+/// `call_ordinal`, `call_success` and `call_context` are set, the transaction and block
+/// fields are left to the caller.
+pub fn decode_call(call: &eth::Call) -> Option<Call> {
+    if call.address != TRACKED_CONTRACT {
+        return None;
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::AddAdmin::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::AddAdmin::decode(call).ok()?;
+        return Some(Call::AddAdmin(v1::PolymarketctfAddAdminCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            admin: decoded_call.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::AddOperator::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::AddOperator::decode(call).ok()?;
+        return Some(Call::AddOperator(v1::PolymarketctfAddOperatorCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            operator: decoded_call.operator,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::CancelOrder::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::CancelOrder::decode(call).ok()?;
+        return Some(Call::CancelOrder(v1::PolymarketctfCancelOrderCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            order: Some(order(decoded_call.order)),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::CancelOrders::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::CancelOrders::decode(call).ok()?;
+        return Some(Call::CancelOrders(v1::PolymarketctfCancelOrdersCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            orders: decoded_call.orders.into_iter().map(order).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::FillOrder::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::FillOrder::decode(call).ok()?;
+        return Some(Call::FillOrder(v1::PolymarketctfFillOrderCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            order: Some(order(decoded_call.order)),
+            fill_amount: decoded_call.fill_amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::FillOrders::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::FillOrders::decode(call).ok()?;
+        return Some(Call::FillOrders(v1::PolymarketctfFillOrdersCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            orders: decoded_call.orders.into_iter().map(order).collect::<Vec<_>>(),
+            fill_amounts: decoded_call.fill_amounts.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::IncrementNonce::match_call(call) {
+        let _decoded_call = abi::polymarketctf_contract::functions::IncrementNonce::decode(call).ok()?;
+        return Some(Call::IncrementNonce(v1::PolymarketctfIncrementNonceCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::MatchOrders::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::MatchOrders::decode(call).ok()?;
+        return Some(Call::MatchOrders(v1::PolymarketctfMatchOrdersCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            taker_order: Some(order(decoded_call.taker_order)),
+            maker_orders: decoded_call.maker_orders.into_iter().map(order).collect::<Vec<_>>(),
+            taker_fill_amount: decoded_call.taker_fill_amount.to_string(),
+            maker_fill_amounts: decoded_call.maker_fill_amounts.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::OnErc1155BatchReceived::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::OnErc1155BatchReceived::decode(call).ok()?;
+        let output = abi::polymarketctf_contract::functions::OnErc1155BatchReceived::output(&call.return_data).unwrap_or_default();
+        return Some(Call::OnErc1155BatchReceived(v1::PolymarketctfOnErc1155BatchReceivedCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            operator: decoded_call.operator,
+            from: decoded_call.from,
+            ids: decoded_call.ids.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            values: decoded_call.values.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
+            data: decoded_call.data,
+            output_param0: Vec::from(output),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::OnErc1155Received::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::OnErc1155Received::decode(call).ok()?;
+        let output = abi::polymarketctf_contract::functions::OnErc1155Received::output(&call.return_data).unwrap_or_default();
+        return Some(Call::OnErc1155Received(v1::PolymarketctfOnErc1155ReceivedCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            operator: decoded_call.operator,
+            from: decoded_call.from,
+            id: decoded_call.id.to_string(),
+            value: decoded_call.value.to_string(),
+            data: decoded_call.data,
+            output_param0: Vec::from(output),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::PauseTrading::match_call(call) {
+        let _decoded_call = abi::polymarketctf_contract::functions::PauseTrading::decode(call).ok()?;
+        return Some(Call::PauseTrading(v1::PolymarketctfPauseTradingCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::polymarketctf_contract::functions::RegisterToken::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::RegisterToken::decode(call).ok()?;
+        return Some(Call::RegisterToken(v1::PolymarketctfRegisterTokenCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            token: decoded_call.token.to_string(),
+            complement: decoded_call.complement.to_string(),
+            condition_id: Vec::from(decoded_call.condition_id),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::RemoveAdmin::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::RemoveAdmin::decode(call).ok()?;
+        return Some(Call::RemoveAdmin(v1::PolymarketctfRemoveAdminCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            admin: decoded_call.admin,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::RemoveOperator::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::RemoveOperator::decode(call).ok()?;
+        return Some(Call::RemoveOperator(v1::PolymarketctfRemoveOperatorCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            operator: decoded_call.operator,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::RenounceAdminRole::match_call(call) {
+        let _decoded_call = abi::polymarketctf_contract::functions::RenounceAdminRole::decode(call).ok()?;
+        return Some(Call::RenounceAdminRole(v1::PolymarketctfRenounceAdminRoleCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::RenounceOperatorRole::match_call(call) {
+        let _decoded_call = abi::polymarketctf_contract::functions::RenounceOperatorRole::decode(call).ok()?;
+        return Some(Call::RenounceOperatorRole(v1::PolymarketctfRenounceOperatorRoleCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::SetProxyFactory::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::SetProxyFactory::decode(call).ok()?;
+        return Some(Call::SetProxyFactory(v1::PolymarketctfSetProxyFactoryCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            new_proxy_factory: decoded_call.new_proxy_factory,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::SetSafeFactory::match_call(call) {
+        let decoded_call = abi::polymarketctf_contract::functions::SetSafeFactory::decode(call).ok()?;
+        return Some(Call::SetSafeFactory(v1::PolymarketctfSetSafeFactoryCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            new_safe_factory: decoded_call.new_safe_factory,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "admin"))]
+    if abi::polymarketctf_contract::functions::UnpauseTrading::match_call(call) {
+        let _decoded_call = abi::polymarketctf_contract::functions::UnpauseTrading::decode(call).ok()?;
+        return Some(Call::UnpauseTrading(v1::PolymarketctfUnpauseTradingCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+pub fn map_events(blk: &eth::Block, events: &mut v1::Events) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(event) = decode_log(log) else {
+                continue;
+            };
+            match event {
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::FeeCharged(event) => events.polymarketctf_fee_chargeds.push(v1::PolymarketctfFeeCharged {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::NewAdmin(event) => events.polymarketctf_new_admins.push(v1::PolymarketctfNewAdmin {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::NewOperator(event) => events.polymarketctf_new_operators.push(v1::PolymarketctfNewOperator {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::OrderCancelled(event) => events.polymarketctf_order_cancelleds.push(v1::PolymarketctfOrderCancelled {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::OrderFilled(event) => events.polymarketctf_order_filleds.push(v1::PolymarketctfOrderFilled {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::OrdersMatched(event) => events.polymarketctf_orders_matcheds.push(v1::PolymarketctfOrdersMatched {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::ProxyFactoryUpdated(event) => events.polymarketctf_proxy_factory_updateds.push(v1::PolymarketctfProxyFactoryUpdated {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::RemovedAdmin(event) => events.polymarketctf_removed_admins.push(v1::PolymarketctfRemovedAdmin {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::RemovedOperator(event) => events.polymarketctf_removed_operators.push(v1::PolymarketctfRemovedOperator {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::SafeFactoryUpdated(event) => events.polymarketctf_safe_factory_updateds.push(v1::PolymarketctfSafeFactoryUpdated {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::TokenRegistered(event) => events.polymarketctf_token_registereds.push(v1::PolymarketctfTokenRegistered {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::TradingPaused(event) => events.polymarketctf_trading_pauseds.push(v1::PolymarketctfTradingPaused {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "admin"))]
+                Event::TradingUnpaused(event) => events.polymarketctf_trading_unpauseds.push(v1::PolymarketctfTradingUnpaused {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+            }
+        }
+    }
+}
+
+pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls) {
+    for tx in blk.transactions() {
+        for call in tx.calls.iter() {
+            let Some(decoded_call) = decode_call(call) else {
+                continue;
+            };
+            match decoded_call {
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::AddAdmin(decoded_call) => calls.polymarketctf_call_add_admins.push(v1::PolymarketctfAddAdminCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::AddOperator(decoded_call) => calls.polymarketctf_call_add_operators.push(v1::PolymarketctfAddOperatorCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::CancelOrder(decoded_call) => calls.polymarketctf_call_cancel_orders.push(v1::PolymarketctfCancelOrderCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::CancelOrders(decoded_call) => calls.polymarketctf_call_cancel_orders_batch.push(v1::PolymarketctfCancelOrdersCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::FillOrder(decoded_call) => calls.polymarketctf_call_fill_orders.push(v1::PolymarketctfFillOrderCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::FillOrders(decoded_call) => calls.polymarketctf_call_fill_orders_batch.push(v1::PolymarketctfFillOrdersCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::IncrementNonce(decoded_call) => calls.polymarketctf_call_increment_nonces.push(v1::PolymarketctfIncrementNonceCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::MatchOrders(decoded_call) => calls.polymarketctf_call_match_orders.push(v1::PolymarketctfMatchOrdersCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::OnErc1155BatchReceived(decoded_call) => calls.polymarketctf_call_on_erc1155_batch_receiveds.push(v1::PolymarketctfOnErc1155BatchReceivedCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::OnErc1155Received(decoded_call) => calls.polymarketctf_call_on_erc1155_receiveds.push(v1::PolymarketctfOnErc1155ReceivedCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::PauseTrading(decoded_call) => calls.polymarketctf_call_pause_tradings.push(v1::PolymarketctfPauseTradingCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::RegisterToken(decoded_call) => calls.polymarketctf_call_register_tokens.push(v1::PolymarketctfRegisterTokenCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::RemoveAdmin(decoded_call) => calls.polymarketctf_call_remove_admins.push(v1::PolymarketctfRemoveAdminCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::RemoveOperator(decoded_call) => calls.polymarketctf_call_remove_operators.push(v1::PolymarketctfRemoveOperatorCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::RenounceAdminRole(decoded_call) => calls.polymarketctf_call_renounce_admin_roles.push(v1::PolymarketctfRenounceAdminRoleCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::RenounceOperatorRole(decoded_call) => calls.polymarketctf_call_renounce_operator_roles.push(v1::PolymarketctfRenounceOperatorRoleCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::SetProxyFactory(decoded_call) => calls.polymarketctf_call_set_proxy_factories.push(v1::PolymarketctfSetProxyFactoryCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::SetSafeFactory(decoded_call) => calls.polymarketctf_call_set_safe_factories.push(v1::PolymarketctfSetSafeFactoryCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "admin"))]
+                Call::UnpauseTrading(decoded_call) => calls.polymarketctf_call_unpause_tradings.push(v1::PolymarketctfUnpauseTradingCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+            }
+        }
+    }
+}
+
+/// Order tuple as decoded by the bindings.
+type AbiOrder = (BigInt, Vec<u8>, Vec<u8>, Vec<u8>, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, Vec<u8>,);
+
+fn order(value: AbiOrder) -> v1::PolymarketctfOrder {
+    v1::PolymarketctfOrder {
+        salt: value.0.to_string(),
+        maker: value.1,
+        signer: value.2,
+        taker: value.3,
+        token_id: value.4.to_string(),
+        maker_amount: value.5.to_string(),
+        taker_amount: value.6.to_string(),
+        expiration: value.7.to_string(),
+        nonce: value.8.to_string(),
+        fee_rate_bps: value.9.to_string(),
+        side: value.10.to_u64() as u32,
+        signature_type: value.11.to_u64() as u32,
+        signature: value.12,
+    }
+}
+
+pub(super) fn fee_charged_v2(v1: v1::PolymarketctfFeeCharged) -> v2::PolymarketctfFeeCharged {
+    v2::PolymarketctfFeeCharged {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        receiver: address(&v1.receiver),
+        token_id: uint256(&v1.token_id).unwrap_or_default(),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn new_admin_v2(v1: v1::PolymarketctfNewAdmin) -> v2::PolymarketctfNewAdmin {
+    v2::PolymarketctfNewAdmin {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        new_admin_address: address(&v1.new_admin_address),
+        admin: address(&v1.admin),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn new_operator_v2(v1: v1::PolymarketctfNewOperator) -> v2::PolymarketctfNewOperator {
+    v2::PolymarketctfNewOperator {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        new_operator_address: address(&v1.new_operator_address),
+        admin: address(&v1.admin),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn order_cancelled_v2(v1: v1::PolymarketctfOrderCancelled) -> v2::PolymarketctfOrderCancelled {
+    v2::PolymarketctfOrderCancelled {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        order_hash: v1.order_hash,
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn order_filled_v2(v1: v1::PolymarketctfOrderFilled) -> v2::PolymarketctfOrderFilled {
+    v2::PolymarketctfOrderFilled {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        order_hash: v1.order_hash,
+        maker: address(&v1.maker),
+        taker: address(&v1.taker),
+        maker_asset_id: uint256(&v1.maker_asset_id).unwrap_or_default(),
+        taker_asset_id: uint256(&v1.taker_asset_id).unwrap_or_default(),
+        maker_amount_filled: uint256(&v1.maker_amount_filled).unwrap_or_default(),
+        taker_amount_filled: uint256(&v1.taker_amount_filled).unwrap_or_default(),
+        fee: uint256(&v1.fee).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn orders_matched_v2(v1: v1::PolymarketctfOrdersMatched) -> v2::PolymarketctfOrdersMatched {
+    v2::PolymarketctfOrdersMatched {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        taker_order_hash: v1.taker_order_hash,
+        taker_order_maker: address(&v1.taker_order_maker),
+        maker_asset_id: uint256(&v1.maker_asset_id).unwrap_or_default(),
+        taker_asset_id: uint256(&v1.taker_asset_id).unwrap_or_default(),
+        maker_amount_filled: uint256(&v1.maker_amount_filled).unwrap_or_default(),
+        taker_amount_filled: uint256(&v1.taker_amount_filled).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn proxy_factory_updated_v2(v1: v1::PolymarketctfProxyFactoryUpdated) -> v2::PolymarketctfProxyFactoryUpdated {
+    v2::PolymarketctfProxyFactoryUpdated {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        old_proxy_factory: address(&v1.old_proxy_factory),
+        new_proxy_factory: address(&v1.new_proxy_factory),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn removed_admin_v2(v1: v1::PolymarketctfRemovedAdmin) -> v2::PolymarketctfRemovedAdmin {
+    v2::PolymarketctfRemovedAdmin {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        removed_admin: address(&v1.removed_admin),
+        admin: address(&v1.admin),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn removed_operator_v2(v1: v1::PolymarketctfRemovedOperator) -> v2::PolymarketctfRemovedOperator {
+    v2::PolymarketctfRemovedOperator {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        removed_operator: address(&v1.removed_operator),
+        admin: address(&v1.admin),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn safe_factory_updated_v2(v1: v1::PolymarketctfSafeFactoryUpdated) -> v2::PolymarketctfSafeFactoryUpdated {
+    v2::PolymarketctfSafeFactoryUpdated {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        old_safe_factory: address(&v1.old_safe_factory),
+        new_safe_factory: address(&v1.new_safe_factory),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn token_registered_v2(v1: v1::PolymarketctfTokenRegistered) -> v2::PolymarketctfTokenRegistered {
+    v2::PolymarketctfTokenRegistered {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        token0: uint256(&v1.token0).unwrap_or_default(),
+        token1: uint256(&v1.token1).unwrap_or_default(),
+        condition_id: v1.condition_id,
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn trading_paused_v2(v1: v1::PolymarketctfTradingPaused) -> v2::PolymarketctfTradingPaused {
+    v2::PolymarketctfTradingPaused {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        pauser: address(&v1.pauser),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn trading_unpaused_v2(v1: v1::PolymarketctfTradingUnpaused) -> v2::PolymarketctfTradingUnpaused {
+    v2::PolymarketctfTradingUnpaused {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        pauser: address(&v1.pauser),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn add_admin_call_v2(v1: v1::PolymarketctfAddAdminCall) -> v2::PolymarketctfAddAdminCall {
+    v2::PolymarketctfAddAdminCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        admin: address(&v1.admin),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn add_operator_call_v2(v1: v1::PolymarketctfAddOperatorCall) -> v2::PolymarketctfAddOperatorCall {
+    v2::PolymarketctfAddOperatorCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        operator: address(&v1.operator),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn cancel_order_call_v2(v1: v1::PolymarketctfCancelOrderCall) -> v2::PolymarketctfCancelOrderCall {
+    v2::PolymarketctfCancelOrderCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        order: v1.order.map(order_v2),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn cancel_orders_call_v2(v1: v1::PolymarketctfCancelOrdersCall) -> v2::PolymarketctfCancelOrdersCall {
+    v2::PolymarketctfCancelOrdersCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        orders: v1.orders.into_iter().map(order_v2).collect(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn fill_order_call_v2(v1: v1::PolymarketctfFillOrderCall) -> v2::PolymarketctfFillOrderCall {
+    v2::PolymarketctfFillOrderCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        order: v1.order.map(order_v2),
+        fill_amount: uint256(&v1.fill_amount).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn fill_orders_call_v2(v1: v1::PolymarketctfFillOrdersCall) -> v2::PolymarketctfFillOrdersCall {
+    v2::PolymarketctfFillOrdersCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        orders: v1.orders.into_iter().map(order_v2).collect(),
+        fill_amounts: v1.fill_amounts.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn increment_nonce_call_v2(v1: v1::PolymarketctfIncrementNonceCall) -> v2::PolymarketctfIncrementNonceCall {
+    v2::PolymarketctfIncrementNonceCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn match_orders_call_v2(v1: v1::PolymarketctfMatchOrdersCall) -> v2::PolymarketctfMatchOrdersCall {
+    v2::PolymarketctfMatchOrdersCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        taker_order: v1.taker_order.map(order_v2),
+        maker_orders: v1.maker_orders.into_iter().map(order_v2).collect(),
+        taker_fill_amount: uint256(&v1.taker_fill_amount).unwrap_or_default(),
+        maker_fill_amounts: v1.maker_fill_amounts.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn on_erc1155_batch_received_call_v2(v1: v1::PolymarketctfOnErc1155BatchReceivedCall) -> v2::PolymarketctfOnErc1155BatchReceivedCall {
+    v2::PolymarketctfOnErc1155BatchReceivedCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        operator: address(&v1.operator),
+        from: address(&v1.from),
+        ids: v1.ids.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        values: v1.values.iter().map(|v| uint256(v).unwrap_or_default()).collect(),
+        data: v1.data,
+        output_param0: v1.output_param0,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn on_erc1155_received_call_v2(v1: v1::PolymarketctfOnErc1155ReceivedCall) -> v2::PolymarketctfOnErc1155ReceivedCall {
+    v2::PolymarketctfOnErc1155ReceivedCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        operator: address(&v1.operator),
+        from: address(&v1.from),
+        id: uint256(&v1.id).unwrap_or_default(),
+        value: uint256(&v1.value).unwrap_or_default(),
+        data: v1.data,
+        output_param0: v1.output_param0,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn pause_trading_call_v2(v1: v1::PolymarketctfPauseTradingCall) -> v2::PolymarketctfPauseTradingCall {
+    v2::PolymarketctfPauseTradingCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn register_token_call_v2(v1: v1::PolymarketctfRegisterTokenCall) -> v2::PolymarketctfRegisterTokenCall {
+    v2::PolymarketctfRegisterTokenCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        token: uint256(&v1.token).unwrap_or_default(),
+        complement: uint256(&v1.complement).unwrap_or_default(),
+        condition_id: v1.condition_id,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn remove_admin_call_v2(v1: v1::PolymarketctfRemoveAdminCall) -> v2::PolymarketctfRemoveAdminCall {
+    v2::PolymarketctfRemoveAdminCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        admin: address(&v1.admin),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn remove_operator_call_v2(v1: v1::PolymarketctfRemoveOperatorCall) -> v2::PolymarketctfRemoveOperatorCall {
+    v2::PolymarketctfRemoveOperatorCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        operator: address(&v1.operator),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn renounce_admin_role_call_v2(v1: v1::PolymarketctfRenounceAdminRoleCall) -> v2::PolymarketctfRenounceAdminRoleCall {
+    v2::PolymarketctfRenounceAdminRoleCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn renounce_operator_role_call_v2(v1: v1::PolymarketctfRenounceOperatorRoleCall) -> v2::PolymarketctfRenounceOperatorRoleCall {
+    v2::PolymarketctfRenounceOperatorRoleCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn set_proxy_factory_call_v2(v1: v1::PolymarketctfSetProxyFactoryCall) -> v2::PolymarketctfSetProxyFactoryCall {
+    v2::PolymarketctfSetProxyFactoryCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        new_proxy_factory: address(&v1.new_proxy_factory),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn set_safe_factory_call_v2(v1: v1::PolymarketctfSetSafeFactoryCall) -> v2::PolymarketctfSetSafeFactoryCall {
+    v2::PolymarketctfSetSafeFactoryCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        new_safe_factory: address(&v1.new_safe_factory),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn unpause_trading_call_v2(v1: v1::PolymarketctfUnpauseTradingCall) -> v2::PolymarketctfUnpauseTradingCall {
+    v2::PolymarketctfUnpauseTradingCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+fn order_v2(v1: v1::PolymarketctfOrder) -> v2::PolymarketctfOrder {
+    v2::PolymarketctfOrder {
+        salt: uint256(&v1.salt).unwrap_or_default(),
+        maker: address(&v1.maker),
+        signer: address(&v1.signer),
+        taker: address(&v1.taker),
+        token_id: uint256(&v1.token_id).unwrap_or_default(),
+        maker_amount: uint256(&v1.maker_amount).unwrap_or_default(),
+        taker_amount: uint256(&v1.taker_amount).unwrap_or_default(),
+        expiration: uint256(&v1.expiration).unwrap_or_default(),
+        nonce: uint256(&v1.nonce).unwrap_or_default(),
+        fee_rate_bps: uint256(&v1.fee_rate_bps).unwrap_or_default(),
+        side: v1.side,
+        signature_type: v1.signature_type,
+        signature: v1.signature,
+    }
+}
@@ -0,0 +1,317 @@
+// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.
+
+// Builds without every feature leave the decoding of the disabled entries unused.
+#![cfg_attr(
+    not(all(feature = "events", feature = "calls", feature = "admin", feature = "trading")),
+    allow(dead_code, unused_imports, unused_variables)
+)]
+
+use crate::abi;
+use crate::context::{call_context, call_context_v2, tx_context, tx_context_v2};
+use crate::pb::contract::{v1, v2};
+use crate::v2::{address, uint256};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event as _;
+
+/// An event of the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Event {
+    #[cfg(all(feature = "events", feature = "trading"))]
+    Approval(v1::StethApproval),
+    #[cfg(all(feature = "events", feature = "trading"))]
+    Transfer(v1::StethTransfer),
+}
+
+/// A call to the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Call {
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    Approve(v1::StethApproveCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    Mint(v1::StethMintCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    Transfer(v1::StethTransferCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    TransferFrom(v1::StethTransferFromCall),
+}
+
+/// Decodes a log emitted by the contract. Only the params, `evt_index` and `evt_ordinal`
+/// are set, the transaction and block fields are left to the caller.
+///
+/// The contract has no fixed address, the one to decode is given as `contract_address`.
+pub fn decode_log(log: &eth::Log, contract_address: &[u8]) -> Option<Event> {
+    if log.address != contract_address {
+        return None;
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::steth_contract::events::Approval::match_and_decode(log) {
+        return Some(Event::Approval(v1::StethApproval {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            owner: event.owner,
+            spender: event.spender,
+            value: event.value.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::steth_contract::events::Transfer::match_and_decode(log) {
+        return Some(Event::Transfer(v1::StethTransfer {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            from: event.from,
+            to: event.to,
+            value: event.value.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+/// Decodes a call to the contract, reverted ones included. Only the inputs, outputs,
+/// `call_ordinal`, `call_success` and `call_context` are set, the transaction and block
+/// fields are left to the caller.
+///
+/// The contract has no fixed address, the one to decode is given as `contract_address`.
+pub fn decode_call(call: &eth::Call, contract_address: &[u8]) -> Option<Call> {
+    if call.address != contract_address {
+        return None;
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::steth_contract::functions::Approve::match_call(call) {
+        let decoded_call = abi::steth_contract::functions::Approve::decode(call).ok()?;
+        let output = abi::steth_contract::functions::Approve::output(&call.return_data).unwrap_or_default();
+        return Some(Call::Approve(v1::StethApproveCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            spender: decoded_call.spender,
+            amount: decoded_call.amount.to_string(),
+            output_param0: output,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::steth_contract::functions::Mint::match_call(call) {
+        let decoded_call = abi::steth_contract::functions::Mint::decode(call).ok()?;
+        return Some(Call::Mint(v1::StethMintCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            to: decoded_call.to,
+            amount: decoded_call.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::steth_contract::functions::Transfer::match_call(call) {
+        let decoded_call = abi::steth_contract::functions::Transfer::decode(call).ok()?;
+        let output = abi::steth_contract::functions::Transfer::output(&call.return_data).unwrap_or_default();
+        return Some(Call::Transfer(v1::StethTransferCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            to: decoded_call.to,
+            amount: decoded_call.amount.to_string(),
+            output_param0: output,
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::steth_contract::functions::TransferFrom::match_call(call) {
+        let decoded_call = abi::steth_contract::functions::TransferFrom::decode(call).ok()?;
+        let output = abi::steth_contract::functions::TransferFrom::output(&call.return_data).unwrap_or_default();
+        return Some(Call::TransferFrom(v1::StethTransferFromCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            from: decoded_call.from,
+            to: decoded_call.to,
+            amount: decoded_call.amount.to_string(),
+            output_param0: output,
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+pub fn map_events(blk: &eth::Block, events: &mut v1::Events, contract_address: &[u8]) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(event) = decode_log(log, contract_address) else {
+                continue;
+            };
+            match event {
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::Approval(event) => events.steth_approvals.push(v1::StethApproval {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::Transfer(event) => events.steth_transfers.push(v1::StethTransfer {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+            }
+        }
+    }
+}
+
+pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls, contract_address: &[u8]) {
+    for tx in blk.transactions() {
+        for call in tx.calls.iter() {
+            let Some(decoded_call) = decode_call(call, contract_address) else {
+                continue;
+            };
+            match decoded_call {
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::Approve(decoded_call) => calls.steth_call_approves.push(v1::StethApproveCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::Mint(decoded_call) => calls.steth_call_mints.push(v1::StethMintCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::Transfer(decoded_call) => calls.steth_call_transfers.push(v1::StethTransferCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::TransferFrom(decoded_call) => calls.steth_call_transfer_froms.push(v1::StethTransferFromCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+            }
+        }
+    }
+}
+
+pub(super) fn approval_v2(v1: v1::StethApproval) -> v2::StethApproval {
+    v2::StethApproval {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        owner: address(&v1.owner),
+        spender: address(&v1.spender),
+        value: uint256(&v1.value).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn transfer_v2(v1: v1::StethTransfer) -> v2::StethTransfer {
+    v2::StethTransfer {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        from: address(&v1.from),
+        to: address(&v1.to),
+        value: uint256(&v1.value).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn approve_call_v2(v1: v1::StethApproveCall) -> v2::StethApproveCall {
+    v2::StethApproveCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        spender: address(&v1.spender),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        output_param0: v1.output_param0,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn mint_call_v2(v1: v1::StethMintCall) -> v2::StethMintCall {
+    v2::StethMintCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        to: address(&v1.to),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn transfer_call_v2(v1: v1::StethTransferCall) -> v2::StethTransferCall {
+    v2::StethTransferCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        to: address(&v1.to),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        output_param0: v1.output_param0,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn transfer_from_call_v2(v1: v1::StethTransferFromCall) -> v2::StethTransferFromCall {
+    v2::StethTransferFromCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        from: address(&v1.from),
+        to: address(&v1.to),
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        output_param0: v1.output_param0,
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
@@ -0,0 +1,188 @@
+// @generated by build.rs from the ABIs listed in build/main.rs, do not edit.
+
+// Builds without every feature leave the decoding of the disabled entries unused.
+#![cfg_attr(
+    not(all(feature = "events", feature = "calls", feature = "admin", feature = "trading")),
+    allow(dead_code, unused_imports, unused_variables)
+)]
+
+use crate::abi;
+use crate::context::{call_context, call_context_v2, tx_context, tx_context_v2};
+use crate::pb::contract::{v1, v2};
+use crate::v2::{address, uint256};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event as _;
+
+/// An event of the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Event {
+    #[cfg(all(feature = "events", feature = "trading"))]
+    Deposit(v1::VaultDeposit),
+}
+
+/// A call to the contract, decoded into its `contract.v1` message.
+#[allow(clippy::large_enum_variant)]
+pub enum Call {
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    DepositAndMint(v1::VaultDepositAndMintCall),
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    WithdrawUsdc(v1::VaultWithdrawUsdcCall),
+}
+
+/// Decodes a log emitted by the contract. Only the params, `evt_index` and `evt_ordinal`
+/// are set, the transaction and block fields are left to the caller.
+///
+/// The contract has no fixed address, the one to decode is given as `contract_address`.
+pub fn decode_log(log: &eth::Log, contract_address: &[u8]) -> Option<Event> {
+    if log.address != contract_address {
+        return None;
+    }
+
+    #[cfg(all(feature = "events", feature = "trading"))]
+    if let Some(event) = abi::vault_contract::events::Deposit::match_and_decode(log) {
+        return Some(Event::Deposit(v1::VaultDeposit {
+            evt_index: log.block_index,
+            evt_ordinal: log.ordinal,
+            user: event.user,
+            usdc_amount: event.usdc_amount.to_string(),
+            steth_amount: event.steth_amount.to_string(),
+            fee_amount: event.fee_amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+/// Decodes a call to the contract, reverted ones included. Only the inputs, outputs,
+/// `call_ordinal`, `call_success` and `call_context` are set, the transaction and block
+/// fields are left to the caller.
+///
+/// The contract has no fixed address, the one to decode is given as `contract_address`.
+pub fn decode_call(call: &eth::Call, contract_address: &[u8]) -> Option<Call> {
+    if call.address != contract_address {
+        return None;
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::vault_contract::functions::DepositAndMint::match_call(call) {
+        let decoded_call = abi::vault_contract::functions::DepositAndMint::decode(call).ok()?;
+        return Some(Call::DepositAndMint(v1::VaultDepositAndMintCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            amount_in: decoded_call.amount_in.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    if abi::vault_contract::functions::WithdrawUsdc::match_call(call) {
+        let decoded_call = abi::vault_contract::functions::WithdrawUsdc::decode(call).ok()?;
+        return Some(Call::WithdrawUsdc(v1::VaultWithdrawUsdcCall {
+            call_ordinal: call.begin_ordinal,
+            call_success: !call.state_reverted,
+            call_context: Some(call_context(call)),
+            amount: decoded_call.amount.to_string(),
+            ..Default::default()
+        }));
+    }
+
+    None
+}
+
+pub fn map_events(blk: &eth::Block, events: &mut v1::Events, contract_address: &[u8]) {
+    for view in blk.receipts() {
+        for log in view.receipt.logs.iter() {
+            let Some(event) = decode_log(log, contract_address) else {
+                continue;
+            };
+            match event {
+                #[cfg(all(feature = "events", feature = "trading"))]
+                Event::Deposit(event) => events.vault_deposits.push(v1::VaultDeposit {
+                    evt_tx_hash: Hex(&view.transaction.hash).to_string(),
+                    evt_block_time: Some(blk.timestamp().to_owned()),
+                    evt_block_number: blk.number,
+                    evt_tx_context: Some(tx_context(view.transaction)),
+                    evt_block_hash: Hex(&blk.hash).to_string(),
+                    ..event
+                }),
+            }
+        }
+    }
+}
+
+pub fn map_calls(blk: &eth::Block, calls: &mut v1::Calls, contract_address: &[u8]) {
+    for tx in blk.transactions() {
+        for call in tx.calls.iter() {
+            let Some(decoded_call) = decode_call(call, contract_address) else {
+                continue;
+            };
+            match decoded_call {
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::DepositAndMint(decoded_call) => calls.vault_call_deposit_and_mints.push(v1::VaultDepositAndMintCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+                #[cfg(all(feature = "calls", feature = "trading"))]
+                Call::WithdrawUsdc(decoded_call) => calls.vault_call_withdraw_usdcs.push(v1::VaultWithdrawUsdcCall {
+                    call_tx_hash: Hex(&tx.hash).to_string(),
+                    call_block_time: Some(blk.timestamp().to_owned()),
+                    call_block_number: blk.number,
+                    call_tx_context: Some(tx_context(tx)),
+                    call_block_hash: Hex(&blk.hash).to_string(),
+                    ..decoded_call
+                }),
+            }
+        }
+    }
+}
+
+pub(super) fn deposit_v2(v1: v1::VaultDeposit) -> v2::VaultDeposit {
+    v2::VaultDeposit {
+        evt_tx_hash: v1.evt_tx_hash,
+        evt_index: v1.evt_index,
+        evt_block_time: v1.evt_block_time,
+        evt_block_number: v1.evt_block_number,
+        user: address(&v1.user),
+        usdc_amount: uint256(&v1.usdc_amount).unwrap_or_default(),
+        steth_amount: uint256(&v1.steth_amount).unwrap_or_default(),
+        fee_amount: uint256(&v1.fee_amount).unwrap_or_default(),
+        evt_tx_context: v1.evt_tx_context.map(tx_context_v2),
+        evt_ordinal: v1.evt_ordinal,
+        evt_block_hash: v1.evt_block_hash,
+    }
+}
+
+pub(super) fn deposit_and_mint_call_v2(v1: v1::VaultDepositAndMintCall) -> v2::VaultDepositAndMintCall {
+    v2::VaultDepositAndMintCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        amount_in: uint256(&v1.amount_in).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
+
+pub(super) fn withdraw_usdc_call_v2(v1: v1::VaultWithdrawUsdcCall) -> v2::VaultWithdrawUsdcCall {
+    v2::VaultWithdrawUsdcCall {
+        call_tx_hash: v1.call_tx_hash,
+        call_block_time: v1.call_block_time,
+        call_block_number: v1.call_block_number,
+        call_ordinal: v1.call_ordinal,
+        call_success: v1.call_success,
+        amount: uint256(&v1.amount).unwrap_or_default(),
+        call_tx_context: v1.call_tx_context.map(tx_context_v2),
+        call_context: v1.call_context.map(call_context_v2),
+        call_block_hash: v1.call_block_hash,
+    }
+}
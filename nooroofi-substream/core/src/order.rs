@@ -0,0 +1,72 @@
+//! EIP-712 hashes of exchange orders, the `orderHash` of `OrderFilled` / `OrderCancelled`
+//! and what the exchange's `hashOrder` returns.
+
+use crate::pb::contract::v1::PolymarketctfOrder;
+use crate::v2::uint256;
+use tiny_keccak::{Hasher, Keccak};
+
+pub const DOMAIN_NAME: &str = "Polymarket CTF Exchange";
+pub const DOMAIN_VERSION: &str = "1";
+pub const POLYGON_CHAIN_ID: u64 = 137;
+
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+const ORDER_TYPE: &str = concat!(
+    "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,",
+    "uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,",
+    "uint256 feeRateBps,uint8 side,uint8 signatureType)",
+);
+
+/// Domain separator of an exchange deployment, as returned by its `domainSeparator()`.
+pub fn domain_separator(chain_id: u64, exchange: &[u8]) -> [u8; 32] {
+    keccak(&[
+        keccak(DOMAIN_TYPE.as_bytes()).to_vec(),
+        keccak(DOMAIN_NAME.as_bytes()).to_vec(),
+        keccak(DOMAIN_VERSION.as_bytes()).to_vec(),
+        uint256(&chain_id.to_string()).expect("a u64 is a uint256"),
+        address(exchange),
+    ]
+    .concat())
+}
+
+/// Hash of `order` under `domain_separator`. The signature is not part of it. `None` when
+/// one of its amounts isn't a uint256, which no decoded order has.
+pub fn hash_order(order: &PolymarketctfOrder, domain_separator: &[u8; 32]) -> Option<[u8; 32]> {
+    let struct_hash = keccak(&[
+        keccak(ORDER_TYPE.as_bytes()).to_vec(),
+        uint256(&order.salt)?,
+        address(&order.maker),
+        address(&order.signer),
+        address(&order.taker),
+        uint256(&order.token_id)?,
+        uint256(&order.maker_amount)?,
+        uint256(&order.taker_amount)?,
+        uint256(&order.expiration)?,
+        uint256(&order.nonce)?,
+        uint256(&order.fee_rate_bps)?,
+        uint256(&order.side.to_string())?,
+        uint256(&order.signature_type.to_string())?,
+    ]
+    .concat());
+
+    Some(keccak(&[b"\x19\x01".as_slice(), domain_separator, &struct_hash].concat()))
+}
+
+/// Hash of `order` on the tracked Polygon exchange.
+pub fn exchange_order_hash(order: &PolymarketctfOrder) -> Option<[u8; 32]> {
+    hash_order(order, &domain_separator(POLYGON_CHAIN_ID, &crate::EXCHANGE))
+}
+
+/// An address left-padded to a 32-byte ABI word.
+fn address(bytes: &[u8]) -> Vec<u8> {
+    let mut word = vec![0u8; 32usize.saturating_sub(bytes.len())];
+    word.extend_from_slice(bytes);
+    word
+}
+
+pub(crate) fn keccak(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+    hash
+}
@@ -0,0 +1,2980 @@
+// @generated
+// This file is @generated by prost-build.
+/// Transaction carrying an event or call. Status values mirror sf.ethereum.type.v2.TransactionTraceStatus.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxContext {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(bytes="vec", tag="2")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub nonce: u64,
+    #[prost(string, tag="5")]
+    pub gas_price: ::prost::alloc::string::String,
+    #[prost(uint64, tag="6")]
+    pub gas_used: u64,
+    #[prost(enumeration="tx_context::Status", tag="7")]
+    pub status: i32,
+}
+/// Nested message and enum types in `TxContext`.
+pub mod tx_context {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Status {
+        Unknown = 0,
+        Succeeded = 1,
+        Failed = 2,
+        Reverted = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unknown => "STATUS_UNKNOWN",
+                Self::Succeeded => "SUCCEEDED",
+                Self::Failed => "FAILED",
+                Self::Reverted => "REVERTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNKNOWN" => Some(Self::Unknown),
+                "SUCCEEDED" => Some(Self::Succeeded),
+                "FAILED" => Some(Self::Failed),
+                "REVERTED" => Some(Self::Reverted),
+                _ => None,
+            }
+        }
+    }
+}
+/// Call frame of a decoded call. Type values mirror sf.ethereum.type.v2.CallType.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallContext {
+    #[prost(bytes="vec", tag="1")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="2")]
+    pub depth: u32,
+    #[prost(enumeration="call_context::Type", tag="3")]
+    pub call_type: i32,
+    #[prost(uint64, tag="4")]
+    pub gas_consumed: u64,
+}
+/// Nested message and enum types in `CallContext`.
+pub mod call_context {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        Call = 1,
+        Callcode = 2,
+        Delegate = 3,
+        Static = 4,
+        Create = 5,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "TYPE_UNSPECIFIED",
+                Self::Call => "CALL",
+                Self::Callcode => "CALLCODE",
+                Self::Delegate => "DELEGATE",
+                Self::Static => "STATIC",
+                Self::Create => "CREATE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CALL" => Some(Self::Call),
+                "CALLCODE" => Some(Self::Callcode),
+                "DELEGATE" => Some(Self::Delegate),
+                "STATIC" => Some(Self::Static),
+                "CREATE" => Some(Self::Create),
+                _ => None,
+            }
+        }
+    }
+}
+/// Fields of the containers are only filled when the module is built with the features in
+/// their comment, and stay empty otherwise. They are declared in every build, so that
+/// modules built with different features share one schema.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="1")]
+    pub polymarketctf_fee_chargeds: ::prost::alloc::vec::Vec<PolymarketctfFeeCharged>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="2")]
+    pub polymarketctf_new_admins: ::prost::alloc::vec::Vec<PolymarketctfNewAdmin>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="3")]
+    pub polymarketctf_new_operators: ::prost::alloc::vec::Vec<PolymarketctfNewOperator>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="4")]
+    pub polymarketctf_order_cancelleds: ::prost::alloc::vec::Vec<PolymarketctfOrderCancelled>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="5")]
+    pub polymarketctf_order_filleds: ::prost::alloc::vec::Vec<PolymarketctfOrderFilled>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="6")]
+    pub polymarketctf_orders_matcheds: ::prost::alloc::vec::Vec<PolymarketctfOrdersMatched>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="7")]
+    pub polymarketctf_proxy_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfProxyFactoryUpdated>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="8")]
+    pub polymarketctf_removed_admins: ::prost::alloc::vec::Vec<PolymarketctfRemovedAdmin>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="9")]
+    pub polymarketctf_removed_operators: ::prost::alloc::vec::Vec<PolymarketctfRemovedOperator>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="10")]
+    pub polymarketctf_safe_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfSafeFactoryUpdated>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="11")]
+    pub polymarketctf_token_registereds: ::prost::alloc::vec::Vec<PolymarketctfTokenRegistered>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="12")]
+    pub polymarketctf_trading_pauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingPaused>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="13")]
+    pub polymarketctf_trading_unpauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingUnpaused>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="14")]
+    pub ctf_approval_for_alls: ::prost::alloc::vec::Vec<CtfApprovalForAll>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="15")]
+    pub ctf_condition_preparations: ::prost::alloc::vec::Vec<CtfConditionPreparation>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="16")]
+    pub ctf_condition_resolutions: ::prost::alloc::vec::Vec<CtfConditionResolution>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="17")]
+    pub ctf_payout_redemptions: ::prost::alloc::vec::Vec<CtfPayoutRedemption>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="18")]
+    pub ctf_position_splits: ::prost::alloc::vec::Vec<CtfPositionSplit>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="19")]
+    pub ctf_positions_merges: ::prost::alloc::vec::Vec<CtfPositionsMerge>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="20")]
+    pub ctf_transfer_batches: ::prost::alloc::vec::Vec<CtfTransferBatch>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="21")]
+    pub ctf_transfer_singles: ::prost::alloc::vec::Vec<CtfTransferSingle>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="22")]
+    pub ctf_uris: ::prost::alloc::vec::Vec<CtfUri>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="23")]
+    pub vault_deposits: ::prost::alloc::vec::Vec<VaultDeposit>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="24")]
+    pub steth_approvals: ::prost::alloc::vec::Vec<StethApproval>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="25")]
+    pub steth_transfers: ::prost::alloc::vec::Vec<StethTransfer>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="26")]
+    pub usdc_approvals: ::prost::alloc::vec::Vec<UsdcApproval>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="27")]
+    pub usdc_transfers: ::prost::alloc::vec::Vec<UsdcTransfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Calls {
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="1")]
+    pub polymarketctf_call_add_admins: ::prost::alloc::vec::Vec<PolymarketctfAddAdminCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="2")]
+    pub polymarketctf_call_add_operators: ::prost::alloc::vec::Vec<PolymarketctfAddOperatorCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="3")]
+    pub polymarketctf_call_cancel_orders: ::prost::alloc::vec::Vec<PolymarketctfCancelOrderCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="4")]
+    pub polymarketctf_call_cancel_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfCancelOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="5")]
+    pub polymarketctf_call_fill_orders: ::prost::alloc::vec::Vec<PolymarketctfFillOrderCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="6")]
+    pub polymarketctf_call_fill_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfFillOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="7")]
+    pub polymarketctf_call_increment_nonces: ::prost::alloc::vec::Vec<PolymarketctfIncrementNonceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="8")]
+    pub polymarketctf_call_match_orders: ::prost::alloc::vec::Vec<PolymarketctfMatchOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="9")]
+    pub polymarketctf_call_on_erc1155_batch_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155BatchReceivedCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="10")]
+    pub polymarketctf_call_on_erc1155_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155ReceivedCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="11")]
+    pub polymarketctf_call_pause_tradings: ::prost::alloc::vec::Vec<PolymarketctfPauseTradingCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="12")]
+    pub polymarketctf_call_register_tokens: ::prost::alloc::vec::Vec<PolymarketctfRegisterTokenCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="13")]
+    pub polymarketctf_call_remove_admins: ::prost::alloc::vec::Vec<PolymarketctfRemoveAdminCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="14")]
+    pub polymarketctf_call_remove_operators: ::prost::alloc::vec::Vec<PolymarketctfRemoveOperatorCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="15")]
+    pub polymarketctf_call_renounce_admin_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceAdminRoleCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="16")]
+    pub polymarketctf_call_renounce_operator_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceOperatorRoleCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="17")]
+    pub polymarketctf_call_set_proxy_factories: ::prost::alloc::vec::Vec<PolymarketctfSetProxyFactoryCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="18")]
+    pub polymarketctf_call_set_safe_factories: ::prost::alloc::vec::Vec<PolymarketctfSetSafeFactoryCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="19")]
+    pub polymarketctf_call_unpause_tradings: ::prost::alloc::vec::Vec<PolymarketctfUnpauseTradingCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="20")]
+    pub ctf_call_merge_positions: ::prost::alloc::vec::Vec<CtfMergePositionsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="21")]
+    pub ctf_call_prepare_conditions: ::prost::alloc::vec::Vec<CtfPrepareConditionCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="22")]
+    pub ctf_call_redeem_positions: ::prost::alloc::vec::Vec<CtfRedeemPositionsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="23")]
+    pub ctf_call_report_payouts: ::prost::alloc::vec::Vec<CtfReportPayoutsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="24")]
+    pub ctf_call_safe_batch_transfer_froms: ::prost::alloc::vec::Vec<CtfSafeBatchTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="25")]
+    pub ctf_call_safe_transfer_froms: ::prost::alloc::vec::Vec<CtfSafeTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="26")]
+    pub ctf_call_set_approval_for_alls: ::prost::alloc::vec::Vec<CtfSetApprovalForAllCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="27")]
+    pub ctf_call_split_positions: ::prost::alloc::vec::Vec<CtfSplitPositionCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="28")]
+    pub vault_call_deposit_and_mints: ::prost::alloc::vec::Vec<VaultDepositAndMintCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="29")]
+    pub vault_call_withdraw_usdcs: ::prost::alloc::vec::Vec<VaultWithdrawUsdcCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="30")]
+    pub steth_call_approves: ::prost::alloc::vec::Vec<StethApproveCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="31")]
+    pub steth_call_mints: ::prost::alloc::vec::Vec<StethMintCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="32")]
+    pub steth_call_transfers: ::prost::alloc::vec::Vec<StethTransferCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="33")]
+    pub steth_call_transfer_froms: ::prost::alloc::vec::Vec<StethTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="34")]
+    pub usdc_call_approves: ::prost::alloc::vec::Vec<UsdcApproveCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="35")]
+    pub usdc_call_decrease_allowances: ::prost::alloc::vec::Vec<UsdcDecreaseAllowanceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="36")]
+    pub usdc_call_increase_allowances: ::prost::alloc::vec::Vec<UsdcIncreaseAllowanceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="37")]
+    pub usdc_call_transfers: ::prost::alloc::vec::Vec<UsdcTransferCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="38")]
+    pub usdc_call_transfer_froms: ::prost::alloc::vec::Vec<UsdcTransferFromCall>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventsCalls {
+    #[prost(message, optional, tag="1")]
+    pub events: ::core::option::Option<Events>,
+    #[prost(message, optional, tag="2")]
+    pub calls: ::core::option::Option<Calls>,
+}
+/// POLYMARKETCTF EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFeeCharged {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub receiver: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfNewAdmin {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub new_admin_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfNewOperator {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub new_operator_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrderCancelled {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrderFilled {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub taker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub maker_asset_id: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub taker_asset_id: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub maker_amount_filled: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub taker_amount_filled: ::prost::alloc::string::String,
+    #[prost(string, tag="12")]
+    pub fee: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="13")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="14")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="15")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrdersMatched {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub taker_order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub taker_order_maker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub maker_asset_id: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub taker_asset_id: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub maker_amount_filled: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub taker_amount_filled: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfProxyFactoryUpdated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub old_proxy_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub new_proxy_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemovedAdmin {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub removed_admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemovedOperator {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub removed_operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSafeFactoryUpdated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub old_safe_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub new_safe_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTokenRegistered {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub token0: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub token1: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTradingPaused {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub pauser: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTradingUnpaused {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub pauser: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// POLYMARKETCTF CALL MESSAGES
+/// Order tuple taken by cancelOrder, cancelOrders, fillOrder, fillOrders, matchOrders
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrder {
+    #[prost(string, tag="1")]
+    pub salt: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub signer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub taker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="5")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub maker_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub taker_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub expiration: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub nonce: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub fee_rate_bps: ::prost::alloc::string::String,
+    /// enum Side
+    #[prost(uint32, tag="11")]
+    pub side: u32,
+    /// enum SignatureType
+    #[prost(uint32, tag="12")]
+    pub signature_type: u32,
+    #[prost(bytes="vec", tag="13")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfAddAdminCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `admin_` in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfAddOperatorCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `operator_` in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfCancelOrderCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfCancelOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, repeated, tag="6")]
+    pub orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFillOrderCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub fill_amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFillOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, repeated, tag="6")]
+    pub fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="7")]
+    pub orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfIncrementNonceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfMatchOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub taker_fill_amount: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="7")]
+    pub maker_fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="8")]
+    pub taker_order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, repeated, tag="9")]
+    pub maker_orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOnErc1155BatchReceivedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="7")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(string, repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// unnamed in the ABI
+    #[prost(string, repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="11")]
+    pub output_param0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="12")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="13")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="14")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOnErc1155ReceivedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="7")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(string, tag="9")]
+    pub value: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="11")]
+    pub output_param0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="12")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="13")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="14")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfPauseTradingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRegisterTokenCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub token: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub complement: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemoveAdminCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub admin: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemoveOperatorCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRenounceAdminRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRenounceOperatorRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSetProxyFactoryCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `_newProxyFactory` in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub new_proxy_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSetSafeFactoryCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `_newSafeFactory` in the ABI
+    #[prost(bytes="vec", tag="6")]
+    pub new_safe_factory: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfUnpauseTradingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// CTF EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfApprovalForAll {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="7")]
+    pub approved: bool,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfConditionPreparation {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub oracle: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub outcome_slot_count: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="9")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfConditionResolution {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub oracle: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub outcome_slot_count: ::prost::alloc::string::String,
+    #[prost(string, repeated, tag="9")]
+    pub payout_numerators: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPayoutRedemption {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub redeemer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub index_sets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub payout: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPositionSplit {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub stakeholder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPositionsMerge {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub stakeholder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfTransferBatch {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfTransferSingle {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfUri {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// CTF CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfMergePositionsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPrepareConditionCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub oracle: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub outcome_slot_count: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfRedeemPositionsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub index_sets: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfReportPayoutsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="7")]
+    pub payouts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSafeBatchTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSafeTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSetApprovalForAllCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="7")]
+    pub approved: bool,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSplitPositionCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub collateral_token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// VAULT EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultDeposit {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub usdc_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub steth_amount: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub fee_amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="9")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// VAULT CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultDepositAndMintCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub amount_in: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultWithdrawUsdcCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// STETH EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethApproval {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// STETH CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethApproveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethMintCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransferCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="9")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// USDC EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcApproval {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// USDC CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcApproveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcDecreaseAllowanceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub subtracted_value: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcIncreaseAllowanceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub added_value: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransferCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bool, tag="9")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// Events and calls of a block in execution order, sorted by `ordinal`. A call's
+/// ordinal is its begin ordinal, so it precedes the events it emitted.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeRecords {
+    #[prost(message, repeated, tag="1")]
+    pub records: ::prost::alloc::vec::Vec<ExchangeRecord>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeRecord {
+    #[prost(uint64, tag="1")]
+    pub ordinal: u64,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(string, tag="3")]
+    pub block_hash: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(oneof="exchange_record::Body", tags="10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48")]
+    pub body: ::core::option::Option<exchange_record::Body>,
+}
+/// Nested message and enum types in `ExchangeRecord`.
+pub mod exchange_record {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Body {
+        #[prost(message, tag="10")]
+        FeeCharged(super::PolymarketctfFeeCharged),
+        #[prost(message, tag="11")]
+        NewAdmin(super::PolymarketctfNewAdmin),
+        #[prost(message, tag="12")]
+        NewOperator(super::PolymarketctfNewOperator),
+        #[prost(message, tag="13")]
+        OrderCancelled(super::PolymarketctfOrderCancelled),
+        #[prost(message, tag="14")]
+        OrderFilled(super::PolymarketctfOrderFilled),
+        #[prost(message, tag="15")]
+        OrdersMatched(super::PolymarketctfOrdersMatched),
+        #[prost(message, tag="16")]
+        ProxyFactoryUpdated(super::PolymarketctfProxyFactoryUpdated),
+        #[prost(message, tag="17")]
+        RemovedAdmin(super::PolymarketctfRemovedAdmin),
+        #[prost(message, tag="18")]
+        RemovedOperator(super::PolymarketctfRemovedOperator),
+        #[prost(message, tag="19")]
+        SafeFactoryUpdated(super::PolymarketctfSafeFactoryUpdated),
+        #[prost(message, tag="20")]
+        TokenRegistered(super::PolymarketctfTokenRegistered),
+        #[prost(message, tag="21")]
+        TradingPaused(super::PolymarketctfTradingPaused),
+        #[prost(message, tag="22")]
+        TradingUnpaused(super::PolymarketctfTradingUnpaused),
+        #[prost(message, tag="30")]
+        AddAdminCall(super::PolymarketctfAddAdminCall),
+        #[prost(message, tag="31")]
+        AddOperatorCall(super::PolymarketctfAddOperatorCall),
+        #[prost(message, tag="32")]
+        CancelOrderCall(super::PolymarketctfCancelOrderCall),
+        #[prost(message, tag="33")]
+        CancelOrdersCall(super::PolymarketctfCancelOrdersCall),
+        #[prost(message, tag="34")]
+        FillOrderCall(super::PolymarketctfFillOrderCall),
+        #[prost(message, tag="35")]
+        FillOrdersCall(super::PolymarketctfFillOrdersCall),
+        #[prost(message, tag="36")]
+        IncrementNonceCall(super::PolymarketctfIncrementNonceCall),
+        #[prost(message, tag="37")]
+        MatchOrdersCall(super::PolymarketctfMatchOrdersCall),
+        #[prost(message, tag="38")]
+        OnErc1155BatchReceivedCall(super::PolymarketctfOnErc1155BatchReceivedCall),
+        #[prost(message, tag="39")]
+        OnErc1155ReceivedCall(super::PolymarketctfOnErc1155ReceivedCall),
+        #[prost(message, tag="40")]
+        PauseTradingCall(super::PolymarketctfPauseTradingCall),
+        #[prost(message, tag="41")]
+        RegisterTokenCall(super::PolymarketctfRegisterTokenCall),
+        #[prost(message, tag="42")]
+        RemoveAdminCall(super::PolymarketctfRemoveAdminCall),
+        #[prost(message, tag="43")]
+        RemoveOperatorCall(super::PolymarketctfRemoveOperatorCall),
+        #[prost(message, tag="44")]
+        RenounceAdminRoleCall(super::PolymarketctfRenounceAdminRoleCall),
+        #[prost(message, tag="45")]
+        RenounceOperatorRoleCall(super::PolymarketctfRenounceOperatorRoleCall),
+        #[prost(message, tag="46")]
+        SetProxyFactoryCall(super::PolymarketctfSetProxyFactoryCall),
+        #[prost(message, tag="47")]
+        SetSafeFactoryCall(super::PolymarketctfSetSafeFactoryCall),
+        #[prost(message, tag="48")]
+        UnpauseTradingCall(super::PolymarketctfUnpauseTradingCall),
+    }
+}
+/// RECONCILIATION MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Reconciliation {
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    #[prost(uint32, tag="2")]
+    pub transactions_checked: u32,
+    #[prost(message, repeated, tag="3")]
+    pub anomalies: ::prost::alloc::vec::Vec<ReconciliationAnomaly>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReconciliationAnomaly {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(enumeration="reconciliation_anomaly::Kind", tag="2")]
+    pub kind: i32,
+    #[prost(uint32, tag="3")]
+    pub expected: u32,
+    #[prost(uint32, tag="4")]
+    pub actual: u32,
+    #[prost(string, tag="5")]
+    pub detail: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `ReconciliationAnomaly`.
+pub mod reconciliation_anomaly {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// OrderFilled events vs. orders filled by fillOrder(s)/matchOrders
+        OrderFilledCountMismatch = 1,
+        /// OrdersMatched events vs. matchOrders calls
+        OrdersMatchedCountMismatch = 2,
+        /// OrderCancelled events vs. orders cancelled by cancelOrder(s)
+        OrderCancelledCountMismatch = 3,
+        /// an order passed to fillOrder(s)/matchOrders has no OrderFilled for its maker
+        OrderFilledMakerMismatch = 4,
+        /// a matchOrders taker order has no OrdersMatched for its maker
+        OrdersMatchedTakerMismatch = 5,
+        /// a FeeCharged has no OrderFilled carrying the same fee
+        UnmatchedFeeCharged = 6,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "KIND_UNSPECIFIED",
+                Self::OrderFilledCountMismatch => "ORDER_FILLED_COUNT_MISMATCH",
+                Self::OrdersMatchedCountMismatch => "ORDERS_MATCHED_COUNT_MISMATCH",
+                Self::OrderCancelledCountMismatch => "ORDER_CANCELLED_COUNT_MISMATCH",
+                Self::OrderFilledMakerMismatch => "ORDER_FILLED_MAKER_MISMATCH",
+                Self::OrdersMatchedTakerMismatch => "ORDERS_MATCHED_TAKER_MISMATCH",
+                Self::UnmatchedFeeCharged => "UNMATCHED_FEE_CHARGED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "ORDER_FILLED_COUNT_MISMATCH" => Some(Self::OrderFilledCountMismatch),
+                "ORDERS_MATCHED_COUNT_MISMATCH" => Some(Self::OrdersMatchedCountMismatch),
+                "ORDER_CANCELLED_COUNT_MISMATCH" => Some(Self::OrderCancelledCountMismatch),
+                "ORDER_FILLED_MAKER_MISMATCH" => Some(Self::OrderFilledMakerMismatch),
+                "ORDERS_MATCHED_TAKER_MISMATCH" => Some(Self::OrdersMatchedTakerMismatch),
+                "UNMATCHED_FEE_CHARGED" => Some(Self::UnmatchedFeeCharged),
+                _ => None,
+            }
+        }
+    }
+}
+/// OPERATOR MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorBatches {
+    #[prost(message, repeated, tag="1")]
+    pub batches: ::prost::alloc::vec::Vec<OperatorBatch>,
+}
+/// One transaction submitting fillOrder, fillOrders or matchOrders to the exchange.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorBatch {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub tx_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    /// caller of the trading calls
+    #[prost(bytes="vec", tag="5")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="6")]
+    pub match_orders_calls: u32,
+    /// fillOrder and fillOrders calls
+    #[prost(uint32, tag="7")]
+    pub fill_orders_calls: u32,
+    /// maker orders matched or filled
+    #[prost(uint32, tag="8")]
+    pub makers_matched: u32,
+    /// collateral moved by the fills, in collateral base units
+    #[prost(string, tag="9")]
+    pub notional: ::prost::alloc::string::String,
+    #[prost(uint64, tag="10")]
+    pub gas_used: u64,
+    /// wei
+    #[prost(string, tag="11")]
+    pub gas_price: ::prost::alloc::string::String,
+    /// MATIC
+    #[prost(string, tag="12")]
+    pub gas_cost: ::prost::alloc::string::String,
+    /// FeeCharged on the collateral, in base units
+    #[prost(string, tag="13")]
+    pub collateral_fees: ::prost::alloc::string::String,
+    /// FeeCharged on outcome tokens, in token base units
+    #[prost(string, tag="14")]
+    pub outcome_token_fees: ::prost::alloc::string::String,
+    #[prost(bool, tag="15")]
+    pub reverted: bool,
+}
+/// POSITION MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionChanges {
+    #[prost(message, repeated, tag="1")]
+    pub changes: ::prost::alloc::vec::Vec<PositionChange>,
+}
+/// One holder's side of a CTF TransferSingle / TransferBatch: a transfer between two holders
+/// gives two changes, a mint (split) or burn (merge, redemption) only one.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionChange {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// evt_ordinal of the transfer
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub position_id: ::prost::alloc::string::String,
+    /// signed, in position base units
+    #[prost(string, tag="7")]
+    pub delta: ::prost::alloc::string::String,
+    /// zero address for mints and burns
+    #[prost(bytes="vec", tag="8")]
+    pub counterparty: ::prost::alloc::vec::Vec<u8>,
+    /// sender of the transfer, e.g. the exchange for fills
+    #[prost(bytes="vec", tag="9")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionBalances {
+    #[prost(message, repeated, tag="1")]
+    pub balances: ::prost::alloc::vec::Vec<PositionBalance>,
+}
+/// A holder's balance of a position after the block's last change to it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub position_id: ::prost::alloc::string::String,
+    /// before the block
+    #[prost(string, tag="3")]
+    pub old_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub new_balance: ::prost::alloc::string::String,
+    /// of the last change
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+}
+/// SETTLEMENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Settlements {
+    #[prost(message, repeated, tag="1")]
+    pub resolutions: ::prost::alloc::vec::Vec<MarketResolution>,
+    #[prost(message, repeated, tag="2")]
+    pub redemptions: ::prost::alloc::vec::Vec<RedemptionCheck>,
+    /// by market and holder
+    #[prost(message, repeated, tag="3")]
+    pub holder_payouts: ::prost::alloc::vec::Vec<HolderPayout>,
+    #[prost(message, repeated, tag="4")]
+    pub markets: ::prost::alloc::vec::Vec<MarketSettlement>,
+}
+/// A ConditionResolution of a market registered on the exchange.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketResolution {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="3")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub block_number: u64,
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+    #[prost(message, repeated, tag="6")]
+    pub outcomes: ::prost::alloc::vec::Vec<OutcomePayout>,
+    /// registered tokens that are no outcome of the condition
+    #[prost(string, repeated, tag="7")]
+    pub unmatched_token_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// collateral owed for all outcome tokens at resolution, in base units
+    #[prost(string, tag="8")]
+    pub total_payout: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OutcomePayout {
+    #[prost(string, tag="1")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub outcome_index: u32,
+    #[prost(string, tag="3")]
+    pub payout_numerator: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub payout_denominator: ::prost::alloc::string::String,
+    /// pays out anything
+    #[prost(bool, tag="5")]
+    pub winning: bool,
+    /// tokens outstanding at resolution
+    #[prost(string, tag="6")]
+    pub supply: ::prost::alloc::string::String,
+    /// collateral owed for them, in base units
+    #[prost(string, tag="7")]
+    pub payout: ::prost::alloc::string::String,
+}
+/// A PayoutRedemption on a resolved market, checked against the outcome tokens it burned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RedemptionCheck {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub redeemer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    /// outcome tokens burned by the redemption
+    #[prost(string, tag="5")]
+    pub burned: ::prost::alloc::string::String,
+    /// what they are owed, in collateral base units
+    #[prost(string, tag="6")]
+    pub expected_payout: ::prost::alloc::string::String,
+    /// what PayoutRedemption paid
+    #[prost(string, tag="7")]
+    pub payout: ::prost::alloc::string::String,
+    #[prost(bool, tag="8")]
+    pub matches: bool,
+}
+/// What a holder can still redeem on a resolved market, when it resolves or after a change to
+/// their positions in it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HolderPayout {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    /// collateral base units
+    #[prost(string, tag="3")]
+    pub redeemable: ::prost::alloc::string::String,
+    /// of the resolution or the last change, whichever comes last
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+}
+/// A resolved market whose outcome tokens were minted, burned or redeemed in the block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketSettlement {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    /// paid by PayoutRedemption in the block
+    #[prost(string, tag="2")]
+    pub redeemed: ::prost::alloc::string::String,
+    /// still unredeemed after the block, in collateral base units
+    #[prost(string, tag="3")]
+    pub outstanding: ::prost::alloc::string::String,
+}
+/// OPEN INTEREST MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenInterestChanges {
+    #[prost(message, repeated, tag="1")]
+    pub changes: ::prost::alloc::vec::Vec<OpenInterestChange>,
+}
+/// How a block moved the collateral locked in complete sets of a market, in collateral base units.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OpenInterestChange {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// PositionSplit of collateral, outside the exchange
+    #[prost(string, tag="4")]
+    pub split: ::prost::alloc::string::String,
+    /// PositionsMerge back into collateral, outside the exchange
+    #[prost(string, tag="5")]
+    pub merged: ::prost::alloc::string::String,
+    /// MINT matches of matchOrders, split by the exchange
+    #[prost(string, tag="6")]
+    pub exchange_minted: ::prost::alloc::string::String,
+    /// MERGE matches of matchOrders, merged by the exchange
+    #[prost(string, tag="7")]
+    pub exchange_merged: ::prost::alloc::string::String,
+    /// PayoutRedemption of resolved positions
+    #[prost(string, tag="8")]
+    pub redeemed: ::prost::alloc::string::String,
+    /// net change, signed
+    #[prost(string, tag="9")]
+    pub delta: ::prost::alloc::string::String,
+    /// after the block, set by map_open_interest
+    #[prost(string, tag="10")]
+    pub open_interest: ::prost::alloc::string::String,
+}
+/// PROBABILITY MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImpliedProbabilities {
+    #[prost(message, repeated, tag="1")]
+    pub probabilities: ::prost::alloc::vec::Vec<ImpliedProbability>,
+    #[prost(message, repeated, tag="2")]
+    pub inconsistencies: ::prost::alloc::vec::Vec<PriceInconsistency>,
+}
+/// A market's last trade prices after a block trading either of its outcome tokens, the tokens
+/// in the order of `store_registered_tokens`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImpliedProbability {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(string, tag="4")]
+    pub token0: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1: ::prost::alloc::string::String,
+    /// last trade price, empty until the token trades
+    #[prost(string, tag="6")]
+    pub price0: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub price1: ::prost::alloc::string::String,
+    /// price0 / (price0 + price1), or 1 - price1 without a price0
+    #[prost(string, tag="8")]
+    pub probability0: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub probability1: ::prost::alloc::string::String,
+}
+/// Last trade prices of a market's two outcome tokens not summing to 1 within the tolerance.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceInconsistency {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(string, tag="4")]
+    pub token0: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub token1: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub price0: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub price1: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub sum: ::prost::alloc::string::String,
+    #[prost(string, tag="9")]
+    pub tolerance: ::prost::alloc::string::String,
+}
+/// USER ACTIVITY MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserActivities {
+    #[prost(message, repeated, tag="1")]
+    pub activities: ::prost::alloc::vec::Vec<UserActivity>,
+}
+/// One step of a vault depositor's funnel: a deposit into the vault, the stETH it minted, or an
+/// exchange fill after the first deposit, by the depositor or a proxy wallet they sign orders for.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserActivity {
+    /// the depositor
+    #[prost(bytes="vec", tag="1")]
+    pub wallet: ::prost::alloc::vec::Vec<u8>,
+    /// address on the record: the depositor, or their proxy wallet on fills
+    #[prost(bytes="vec", tag="2")]
+    pub actor: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="user_activity::Kind", tag="3")]
+    pub kind: i32,
+    #[prost(string, tag="4")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="5")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// USDC deposited (gross), stETH minted, or collateral traded
+    #[prost(string, tag="8")]
+    pub amount: ::prost::alloc::string::String,
+    /// deposit fee, or the fee of the order for a maker's fill
+    #[prost(string, tag="9")]
+    pub fee: ::prost::alloc::string::String,
+    /// outcome token, fills only
+    #[prost(string, tag="10")]
+    pub token_id: ::prost::alloc::string::String,
+    /// enum Side of the actor's side of the fill, fills only
+    #[prost(uint32, tag="11")]
+    pub side: u32,
+    /// outcome tokens traded, fills only
+    #[prost(string, tag="12")]
+    pub size: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `UserActivity`.
+pub mod user_activity {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// vault Deposit
+        Deposit = 1,
+        /// stETH minted to the depositor by depositAndMint
+        Mint = 2,
+        /// OrderFilled with the depositor or their proxy as maker or taker
+        Fill = 3,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "KIND_UNSPECIFIED",
+                Self::Deposit => "DEPOSIT",
+                Self::Mint => "MINT",
+                Self::Fill => "FILL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "KIND_UNSPECIFIED" => Some(Self::Unspecified),
+                "DEPOSIT" => Some(Self::Deposit),
+                "MINT" => Some(Self::Mint),
+                "FILL" => Some(Self::Fill),
+                _ => None,
+            }
+        }
+    }
+}
+/// COLLATERAL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralFlows {
+    #[prost(message, repeated, tag="1")]
+    pub flows: ::prost::alloc::vec::Vec<CollateralFlow>,
+    #[prost(message, repeated, tag="2")]
+    pub allowances: ::prost::alloc::vec::Vec<CollateralAllowance>,
+    #[prost(message, repeated, tag="3")]
+    pub failed_fills: ::prost::alloc::vec::Vec<FailedFill>,
+}
+/// A USDC Transfer between a trader and the exchange or the conditional tokens, seen from the trader.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralFlow {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub trader: ::prost::alloc::vec::Vec<u8>,
+    /// the exchange or the conditional tokens
+    #[prost(bytes="vec", tag="6")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// signed: positive into the trader, negative out of them
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// A USDC Approval given to the exchange or the conditional tokens, with the allowance it leaves.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollateralAllowance {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub allowance: ::prost::alloc::string::String,
+}
+/// A fillOrder, fillOrders or matchOrders reverted by a token transfer it made: USDC or outcome
+/// tokens its owner didn't hold or hadn't approved the exchange for.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedFill {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    /// of the exchange call
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    /// fillOrder, fillOrders or matchOrders
+    #[prost(string, tag="5")]
+    pub call: ::prost::alloc::string::String,
+    #[prost(enumeration="failed_fill::Reason", tag="6")]
+    pub reason: i32,
+    /// USDC or the conditional tokens
+    #[prost(bytes="vec", tag="7")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    /// outcome token, empty for USDC
+    #[prost(string, tag="8")]
+    pub token_id: ::prost::alloc::string::String,
+    /// whose allowance or balance fell short, the sender of the transfer
+    #[prost(bytes="vec", tag="9")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// of the failing transfer
+    #[prost(string, tag="10")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub revert_reason: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `FailedFill`.
+pub mod failed_fill {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Reason {
+        Unspecified = 0,
+        /// USDC allowance of the exchange
+        InsufficientAllowance = 1,
+        InsufficientBalance = 2,
+        /// outcome tokens without setApprovalForAll(exchange, true)
+        MissingOperatorApproval = 3,
+    }
+    impl Reason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "REASON_UNSPECIFIED",
+                Self::InsufficientAllowance => "INSUFFICIENT_ALLOWANCE",
+                Self::InsufficientBalance => "INSUFFICIENT_BALANCE",
+                Self::MissingOperatorApproval => "MISSING_OPERATOR_APPROVAL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "INSUFFICIENT_ALLOWANCE" => Some(Self::InsufficientAllowance),
+                "INSUFFICIENT_BALANCE" => Some(Self::InsufficientBalance),
+                "MISSING_OPERATOR_APPROVAL" => Some(Self::MissingOperatorApproval),
+                _ => None,
+            }
+        }
+    }
+}
+/// APPROVAL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorApprovals {
+    #[prost(message, repeated, tag="1")]
+    pub changes: ::prost::alloc::vec::Vec<OperatorApprovalChange>,
+    #[prost(message, repeated, tag="2")]
+    pub sell_fills: ::prost::alloc::vec::Vec<SellFillApproval>,
+}
+/// A CTF ApprovalForAll, with the approval it replaced.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OperatorApprovalChange {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="7")]
+    pub approved: bool,
+    #[prost(bool, tag="8")]
+    pub previously_approved: bool,
+}
+/// A fill selling outcome tokens to or through the exchange, with the seller's approval of the
+/// exchange as operator when it executed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SellFillApproval {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// the maker of a sell order, or the taker filling a buy order
+    #[prost(bytes="vec", tag="6")]
+    pub seller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="7")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub size: ::prost::alloc::string::String,
+    /// false when the approval predates the indexed blocks
+    #[prost(bool, tag="9")]
+    pub approved: bool,
+    /// when the approval in effect was granted
+    #[prost(uint64, tag="10")]
+    pub approved_block_number: u64,
+    #[prost(message, optional, tag="11")]
+    pub approved_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// LEADERBOARD MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TraderStats {
+    #[prost(message, optional, tag="1")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    /// days since epoch
+    #[prost(int64, tag="3")]
+    pub day: i64,
+    /// weeks since epoch, starting on Monday
+    #[prost(int64, tag="4")]
+    pub week: i64,
+    #[prost(message, repeated, tag="5")]
+    pub stats: ::prost::alloc::vec::Vec<TraderStat>,
+}
+/// What a wallet's fills of the block add to its totals. Amounts are in collateral.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TraderStat {
+    #[prost(bytes="vec", tag="1")]
+    pub wallet: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub trades: u64,
+    #[prost(string, tag="4")]
+    pub maker_volume: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub taker_volume: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub fees: ::prost::alloc::string::String,
+    /// signed
+    #[prost(string, tag="7")]
+    pub realized_pnl: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Leaderboards {
+    #[prost(message, optional, tag="1")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    #[prost(message, repeated, tag="3")]
+    pub boards: ::prost::alloc::vec::Vec<Leaderboard>,
+}
+/// The wallets with the highest total of a metric over a window, after the block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Leaderboard {
+    /// volume, trades, maker_volume, taker_volume, fees or realized_pnl
+    #[prost(string, tag="1")]
+    pub metric: ::prost::alloc::string::String,
+    /// all, day:<days since epoch> or week:<weeks since epoch>
+    #[prost(string, tag="2")]
+    pub window: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="3")]
+    pub entries: ::prost::alloc::vec::Vec<LeaderboardEntry>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LeaderboardEntry {
+    /// from 1
+    #[prost(uint32, tag="1")]
+    pub rank: u32,
+    #[prost(bytes="vec", tag="2")]
+    pub wallet: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub value: ::prost::alloc::string::String,
+}
+/// MARKET STATS MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketStatsUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub markets: ::prost::alloc::vec::Vec<MarketStats>,
+}
+/// A market's trading stats after a block trading it, its tokens in the order of
+/// `store_registered_tokens`. The 24h figures cover the current hour and the 23 before it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketStats {
+    #[prost(bytes="vec", tag="1")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub trades: u64,
+    /// distinct makers and takers
+    #[prost(uint64, tag="5")]
+    pub traders: u64,
+    /// collateral
+    #[prost(string, tag="6")]
+    pub volume: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub trades_24h: u64,
+    #[prost(uint64, tag="8")]
+    pub traders_24h: u64,
+    #[prost(string, tag="9")]
+    pub volume_24h: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub token0: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub token1: ::prost::alloc::string::String,
+    /// last trade price, empty until the token trades
+    #[prost(string, tag="12")]
+    pub price0: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub price1: ::prost::alloc::string::String,
+    /// last trade price before the window, or the first in it
+    #[prost(string, tag="14")]
+    pub price0_24h_ago: ::prost::alloc::string::String,
+    #[prost(string, tag="15")]
+    pub price1_24h_ago: ::prost::alloc::string::String,
+    /// price0 - price0_24h_ago, signed
+    #[prost(string, tag="16")]
+    pub change0_24h: ::prost::alloc::string::String,
+    #[prost(string, tag="17")]
+    pub change1_24h: ::prost::alloc::string::String,
+}
+/// TOKEN ANALYTICS MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenAnalyticsUpdates {
+    #[prost(message, repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<TokenAnalytics>,
+}
+/// An outcome token's trading over each window of the params, after a block trading it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenAnalytics {
+    #[prost(string, tag="1")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(message, repeated, tag="4")]
+    pub windows: ::prost::alloc::vec::Vec<TokenWindow>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenWindow {
+    /// <n>h for the current hour and the n - 1 before, <n>d for days
+    #[prost(string, tag="1")]
+    pub window: ::prost::alloc::string::String,
+    #[prost(uint64, tag="2")]
+    pub trades: u64,
+    /// collateral
+    #[prost(string, tag="3")]
+    pub volume: ::prost::alloc::string::String,
+    /// outcome tokens
+    #[prost(string, tag="4")]
+    pub size: ::prost::alloc::string::String,
+    /// volume / size
+    #[prost(string, tag="5")]
+    pub vwap: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="6")]
+    pub size_buckets: ::prost::alloc::vec::Vec<SizeBucket>,
+}
+/// Trades of a window by collateral traded, from min_usdc up to max_usdc.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SizeBucket {
+    #[prost(uint64, tag="1")]
+    pub min_usdc: u64,
+    /// 0 for the last bucket, which has no upper bound
+    #[prost(uint64, tag="2")]
+    pub max_usdc: u64,
+    #[prost(uint64, tag="3")]
+    pub trades: u64,
+    #[prost(string, tag="4")]
+    pub volume: ::prost::alloc::string::String,
+}
+/// EXECUTION MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Executions {
+    #[prost(message, repeated, tag="1")]
+    pub fills: ::prost::alloc::vec::Vec<FillExecution>,
+}
+/// An OrderFilled of an order passed to fillOrder, fillOrders or matchOrders, its execution price
+/// next to the limit price the maker signed. Prices are in collateral per outcome token, before fees.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FillExecution {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    /// fillOrder, fillOrders or matchOrders
+    #[prost(string, tag="5")]
+    pub call: ::prost::alloc::string::String,
+    #[prost(enumeration="fill_execution::Role", tag="6")]
+    pub role: i32,
+    #[prost(bytes="vec", tag="7")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="9")]
+    pub token_id: ::prost::alloc::string::String,
+    /// 0 buy, 1 sell
+    #[prost(uint32, tag="10")]
+    pub side: u32,
+    /// outcome tokens
+    #[prost(string, tag="11")]
+    pub size: ::prost::alloc::string::String,
+    /// collateral
+    #[prost(string, tag="12")]
+    pub notional: ::prost::alloc::string::String,
+    /// as in OrderFilled
+    #[prost(string, tag="13")]
+    pub fee: ::prost::alloc::string::String,
+    #[prost(string, tag="14")]
+    pub limit_price: ::prost::alloc::string::String,
+    #[prost(string, tag="15")]
+    pub execution_price: ::prost::alloc::string::String,
+    /// better than the limit by, in basis points to 2 decimals; negative for slippage
+    #[prost(string, tag="16")]
+    pub improvement_bps: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `FillExecution`.
+pub mod fill_execution {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Role {
+        Unspecified = 0,
+        /// an order of fillOrder(s) or a maker order of matchOrders
+        Maker = 1,
+        /// the taker order of matchOrders, filled against all its maker orders at once
+        Taker = 2,
+    }
+    impl Role {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "ROLE_UNSPECIFIED",
+                Self::Maker => "MAKER",
+                Self::Taker => "TAKER",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "ROLE_UNSPECIFIED" => Some(Self::Unspecified),
+                "MAKER" => Some(Self::Maker),
+                "TAKER" => Some(Self::Taker),
+                _ => None,
+            }
+        }
+    }
+}
+/// WASH TRADING MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspiciousTrades {
+    #[prost(message, repeated, tag="1")]
+    pub trades: ::prost::alloc::vec::Vec<SuspiciousTrade>,
+}
+/// A fill that doesn't move tokens between independent owners. Wallets are resolved to the signer
+/// owning them when they are proxy or safe wallets, seller and buyer being those owners.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuspiciousTrade {
+    #[prost(string, tag="1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="suspicious_trade::Reason", tag="6")]
+    pub reason: i32,
+    #[prost(string, tag="7")]
+    pub token_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub maker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub taker: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub seller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub buyer: ::prost::alloc::vec::Vec<u8>,
+    /// outcome tokens
+    #[prost(string, tag="12")]
+    pub size: ::prost::alloc::string::String,
+    /// collateral
+    #[prost(string, tag="13")]
+    pub notional: ::prost::alloc::string::String,
+    /// owners around the cycle, from the seller, for CIRCULAR_TRADE
+    #[prost(bytes="vec", repeated, tag="14")]
+    pub wallets: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+/// Nested message and enum types in `SuspiciousTrade`.
+pub mod suspicious_trade {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Reason {
+        Unspecified = 0,
+        /// maker and taker have the same owner
+        SelfTrade = 1,
+        /// the buyer passed the tokens back to the seller, through the other wallets, within the window
+        CircularTrade = 2,
+    }
+    impl Reason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "REASON_UNSPECIFIED",
+                Self::SelfTrade => "SELF_TRADE",
+                Self::CircularTrade => "CIRCULAR_TRADE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "REASON_UNSPECIFIED" => Some(Self::Unspecified),
+                "SELF_TRADE" => Some(Self::SelfTrade),
+                "CIRCULAR_TRADE" => Some(Self::CircularTrade),
+                _ => None,
+            }
+        }
+    }
+}
+// @@protoc_insertion_point(module)
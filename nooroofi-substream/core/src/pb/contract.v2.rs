@@ -0,0 +1,1941 @@
+// @generated
+// This file is @generated by prost-build.
+/// Transaction carrying an event or call. Status values mirror sf.ethereum.type.v2.TransactionTraceStatus.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TxContext {
+    #[prost(uint32, tag="1")]
+    pub index: u32,
+    #[prost(string, tag="2")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub nonce: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub gas_price: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub gas_used: u64,
+    #[prost(enumeration="tx_context::Status", tag="7")]
+    pub status: i32,
+}
+/// Nested message and enum types in `TxContext`.
+pub mod tx_context {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Status {
+        Unknown = 0,
+        Succeeded = 1,
+        Failed = 2,
+        Reverted = 3,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unknown => "STATUS_UNKNOWN",
+                Self::Succeeded => "SUCCEEDED",
+                Self::Failed => "FAILED",
+                Self::Reverted => "REVERTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNKNOWN" => Some(Self::Unknown),
+                "SUCCEEDED" => Some(Self::Succeeded),
+                "FAILED" => Some(Self::Failed),
+                "REVERTED" => Some(Self::Reverted),
+                _ => None,
+            }
+        }
+    }
+}
+/// Call frame of a decoded call. Type values mirror sf.ethereum.type.v2.CallType.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallContext {
+    #[prost(string, tag="1")]
+    pub caller: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub depth: u32,
+    #[prost(enumeration="call_context::Type", tag="3")]
+    pub call_type: i32,
+    #[prost(uint64, tag="4")]
+    pub gas_consumed: u64,
+}
+/// Nested message and enum types in `CallContext`.
+pub mod call_context {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Type {
+        Unspecified = 0,
+        Call = 1,
+        Callcode = 2,
+        Delegate = 3,
+        Static = 4,
+        Create = 5,
+    }
+    impl Type {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Unspecified => "TYPE_UNSPECIFIED",
+                Self::Call => "CALL",
+                Self::Callcode => "CALLCODE",
+                Self::Delegate => "DELEGATE",
+                Self::Static => "STATIC",
+                Self::Create => "CREATE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TYPE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CALL" => Some(Self::Call),
+                "CALLCODE" => Some(Self::Callcode),
+                "DELEGATE" => Some(Self::Delegate),
+                "STATIC" => Some(Self::Static),
+                "CREATE" => Some(Self::Create),
+                _ => None,
+            }
+        }
+    }
+}
+// Same messages and field numbers as contract.v1, with uint256 values encoded as 32-byte
+// big-endian `bytes` (so they sort numerically, empty if the v1 value isn't a uint256)
+// and addresses as EIP-55 checksummed `0x` strings.
+
+/// Fields of the containers are only filled when the module is built with the features in
+/// their comment, and stay empty otherwise. They are declared in every build, so that
+/// modules built with different features share one schema.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Events {
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="1")]
+    pub polymarketctf_fee_chargeds: ::prost::alloc::vec::Vec<PolymarketctfFeeCharged>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="2")]
+    pub polymarketctf_new_admins: ::prost::alloc::vec::Vec<PolymarketctfNewAdmin>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="3")]
+    pub polymarketctf_new_operators: ::prost::alloc::vec::Vec<PolymarketctfNewOperator>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="4")]
+    pub polymarketctf_order_cancelleds: ::prost::alloc::vec::Vec<PolymarketctfOrderCancelled>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="5")]
+    pub polymarketctf_order_filleds: ::prost::alloc::vec::Vec<PolymarketctfOrderFilled>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="6")]
+    pub polymarketctf_orders_matcheds: ::prost::alloc::vec::Vec<PolymarketctfOrdersMatched>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="7")]
+    pub polymarketctf_proxy_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfProxyFactoryUpdated>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="8")]
+    pub polymarketctf_removed_admins: ::prost::alloc::vec::Vec<PolymarketctfRemovedAdmin>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="9")]
+    pub polymarketctf_removed_operators: ::prost::alloc::vec::Vec<PolymarketctfRemovedOperator>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="10")]
+    pub polymarketctf_safe_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfSafeFactoryUpdated>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="11")]
+    pub polymarketctf_token_registereds: ::prost::alloc::vec::Vec<PolymarketctfTokenRegistered>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="12")]
+    pub polymarketctf_trading_pauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingPaused>,
+    /// `events` + `admin`
+    #[prost(message, repeated, tag="13")]
+    pub polymarketctf_trading_unpauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingUnpaused>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="14")]
+    pub ctf_approval_for_alls: ::prost::alloc::vec::Vec<CtfApprovalForAll>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="15")]
+    pub ctf_condition_preparations: ::prost::alloc::vec::Vec<CtfConditionPreparation>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="16")]
+    pub ctf_condition_resolutions: ::prost::alloc::vec::Vec<CtfConditionResolution>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="17")]
+    pub ctf_payout_redemptions: ::prost::alloc::vec::Vec<CtfPayoutRedemption>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="18")]
+    pub ctf_position_splits: ::prost::alloc::vec::Vec<CtfPositionSplit>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="19")]
+    pub ctf_positions_merges: ::prost::alloc::vec::Vec<CtfPositionsMerge>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="20")]
+    pub ctf_transfer_batches: ::prost::alloc::vec::Vec<CtfTransferBatch>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="21")]
+    pub ctf_transfer_singles: ::prost::alloc::vec::Vec<CtfTransferSingle>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="22")]
+    pub ctf_uris: ::prost::alloc::vec::Vec<CtfUri>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="23")]
+    pub vault_deposits: ::prost::alloc::vec::Vec<VaultDeposit>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="24")]
+    pub steth_approvals: ::prost::alloc::vec::Vec<StethApproval>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="25")]
+    pub steth_transfers: ::prost::alloc::vec::Vec<StethTransfer>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="26")]
+    pub usdc_approvals: ::prost::alloc::vec::Vec<UsdcApproval>,
+    /// `events` + `trading`
+    #[prost(message, repeated, tag="27")]
+    pub usdc_transfers: ::prost::alloc::vec::Vec<UsdcTransfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Calls {
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="1")]
+    pub polymarketctf_call_add_admins: ::prost::alloc::vec::Vec<PolymarketctfAddAdminCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="2")]
+    pub polymarketctf_call_add_operators: ::prost::alloc::vec::Vec<PolymarketctfAddOperatorCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="3")]
+    pub polymarketctf_call_cancel_orders: ::prost::alloc::vec::Vec<PolymarketctfCancelOrderCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="4")]
+    pub polymarketctf_call_cancel_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfCancelOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="5")]
+    pub polymarketctf_call_fill_orders: ::prost::alloc::vec::Vec<PolymarketctfFillOrderCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="6")]
+    pub polymarketctf_call_fill_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfFillOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="7")]
+    pub polymarketctf_call_increment_nonces: ::prost::alloc::vec::Vec<PolymarketctfIncrementNonceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="8")]
+    pub polymarketctf_call_match_orders: ::prost::alloc::vec::Vec<PolymarketctfMatchOrdersCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="9")]
+    pub polymarketctf_call_on_erc1155_batch_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155BatchReceivedCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="10")]
+    pub polymarketctf_call_on_erc1155_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155ReceivedCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="11")]
+    pub polymarketctf_call_pause_tradings: ::prost::alloc::vec::Vec<PolymarketctfPauseTradingCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="12")]
+    pub polymarketctf_call_register_tokens: ::prost::alloc::vec::Vec<PolymarketctfRegisterTokenCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="13")]
+    pub polymarketctf_call_remove_admins: ::prost::alloc::vec::Vec<PolymarketctfRemoveAdminCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="14")]
+    pub polymarketctf_call_remove_operators: ::prost::alloc::vec::Vec<PolymarketctfRemoveOperatorCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="15")]
+    pub polymarketctf_call_renounce_admin_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceAdminRoleCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="16")]
+    pub polymarketctf_call_renounce_operator_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceOperatorRoleCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="17")]
+    pub polymarketctf_call_set_proxy_factories: ::prost::alloc::vec::Vec<PolymarketctfSetProxyFactoryCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="18")]
+    pub polymarketctf_call_set_safe_factories: ::prost::alloc::vec::Vec<PolymarketctfSetSafeFactoryCall>,
+    /// `calls` + `admin`
+    #[prost(message, repeated, tag="19")]
+    pub polymarketctf_call_unpause_tradings: ::prost::alloc::vec::Vec<PolymarketctfUnpauseTradingCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="20")]
+    pub ctf_call_merge_positions: ::prost::alloc::vec::Vec<CtfMergePositionsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="21")]
+    pub ctf_call_prepare_conditions: ::prost::alloc::vec::Vec<CtfPrepareConditionCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="22")]
+    pub ctf_call_redeem_positions: ::prost::alloc::vec::Vec<CtfRedeemPositionsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="23")]
+    pub ctf_call_report_payouts: ::prost::alloc::vec::Vec<CtfReportPayoutsCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="24")]
+    pub ctf_call_safe_batch_transfer_froms: ::prost::alloc::vec::Vec<CtfSafeBatchTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="25")]
+    pub ctf_call_safe_transfer_froms: ::prost::alloc::vec::Vec<CtfSafeTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="26")]
+    pub ctf_call_set_approval_for_alls: ::prost::alloc::vec::Vec<CtfSetApprovalForAllCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="27")]
+    pub ctf_call_split_positions: ::prost::alloc::vec::Vec<CtfSplitPositionCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="28")]
+    pub vault_call_deposit_and_mints: ::prost::alloc::vec::Vec<VaultDepositAndMintCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="29")]
+    pub vault_call_withdraw_usdcs: ::prost::alloc::vec::Vec<VaultWithdrawUsdcCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="30")]
+    pub steth_call_approves: ::prost::alloc::vec::Vec<StethApproveCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="31")]
+    pub steth_call_mints: ::prost::alloc::vec::Vec<StethMintCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="32")]
+    pub steth_call_transfers: ::prost::alloc::vec::Vec<StethTransferCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="33")]
+    pub steth_call_transfer_froms: ::prost::alloc::vec::Vec<StethTransferFromCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="34")]
+    pub usdc_call_approves: ::prost::alloc::vec::Vec<UsdcApproveCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="35")]
+    pub usdc_call_decrease_allowances: ::prost::alloc::vec::Vec<UsdcDecreaseAllowanceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="36")]
+    pub usdc_call_increase_allowances: ::prost::alloc::vec::Vec<UsdcIncreaseAllowanceCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="37")]
+    pub usdc_call_transfers: ::prost::alloc::vec::Vec<UsdcTransferCall>,
+    /// `calls` + `trading`
+    #[prost(message, repeated, tag="38")]
+    pub usdc_call_transfer_froms: ::prost::alloc::vec::Vec<UsdcTransferFromCall>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventsCalls {
+    #[prost(message, optional, tag="1")]
+    pub events: ::core::option::Option<Events>,
+    #[prost(message, optional, tag="2")]
+    pub calls: ::core::option::Option<Calls>,
+}
+/// POLYMARKETCTF EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFeeCharged {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub receiver: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfNewAdmin {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub new_admin_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfNewOperator {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub new_operator_address: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrderCancelled {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrderFilled {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub maker: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub taker: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub maker_asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub taker_asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub maker_amount_filled: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub taker_amount_filled: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="12")]
+    pub fee: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="13")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="14")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="15")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrdersMatched {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub taker_order_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub taker_order_maker: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub maker_asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub taker_asset_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub maker_amount_filled: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub taker_amount_filled: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfProxyFactoryUpdated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub old_proxy_factory: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub new_proxy_factory: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemovedAdmin {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub removed_admin: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemovedOperator {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub removed_operator: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSafeFactoryUpdated {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub old_safe_factory: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub new_safe_factory: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTokenRegistered {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub token0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token1: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTradingPaused {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub pauser: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfTradingUnpaused {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub pauser: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="6")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="7")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="8")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// POLYMARKETCTF CALL MESSAGES
+/// Order tuple taken by cancelOrder, cancelOrders, fillOrder, fillOrders, matchOrders
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOrder {
+    #[prost(bytes="vec", tag="1")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub maker: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub signer: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub taker: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub maker_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub taker_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub expiration: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub fee_rate_bps: ::prost::alloc::vec::Vec<u8>,
+    /// enum Side
+    #[prost(uint32, tag="11")]
+    pub side: u32,
+    /// enum SignatureType
+    #[prost(uint32, tag="12")]
+    pub signature_type: u32,
+    #[prost(bytes="vec", tag="13")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfAddAdminCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `admin_` in the ABI
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfAddOperatorCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `operator_` in the ABI
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfCancelOrderCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfCancelOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, repeated, tag="6")]
+    pub orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFillOrderCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub fill_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfFillOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", repeated, tag="6")]
+    pub fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, repeated, tag="7")]
+    pub orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfIncrementNonceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfMatchOrdersCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub taker_fill_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="7")]
+    pub maker_fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag="8")]
+    pub taker_order: ::core::option::Option<PolymarketctfOrder>,
+    #[prost(message, repeated, tag="9")]
+    pub maker_orders: ::prost::alloc::vec::Vec<PolymarketctfOrder>,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOnErc1155BatchReceivedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// unnamed in the ABI
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(string, tag="7")]
+    pub from: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="11")]
+    pub output_param0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="12")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="13")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="14")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfOnErc1155ReceivedCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// unnamed in the ABI
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(string, tag="7")]
+    pub from: ::prost::alloc::string::String,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="8")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="9")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bytes="vec", tag="11")]
+    pub output_param0: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="12")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="13")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="14")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfPauseTradingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRegisterTokenCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub complement: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemoveAdminCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub admin: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRemoveOperatorCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRenounceAdminRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfRenounceOperatorRoleCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSetProxyFactoryCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `_newProxyFactory` in the ABI
+    #[prost(string, tag="6")]
+    pub new_proxy_factory: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfSetSafeFactoryCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    /// `_newSafeFactory` in the ABI
+    #[prost(string, tag="6")]
+    pub new_safe_factory: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PolymarketctfUnpauseTradingCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(message, optional, tag="6")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="7")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="8")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// CTF EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfApprovalForAll {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(bool, tag="7")]
+    pub approved: bool,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfConditionPreparation {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub oracle: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub outcome_slot_count: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfConditionResolution {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="6")]
+    pub oracle: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub outcome_slot_count: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub payout_numerators: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPayoutRedemption {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub redeemer: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub index_sets: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub payout: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPositionSplit {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub stakeholder: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPositionsMerge {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub stakeholder: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="12")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="13")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfTransferBatch {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfTransferSingle {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="10")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="11")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="12")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfUri {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="8")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="9")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// CTF CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfMergePositionsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfPrepareConditionCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub oracle: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub outcome_slot_count: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfRedeemPositionsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub index_sets: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfReportPayoutsCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub question_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="7")]
+    pub payouts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSafeBatchTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", repeated, tag="8")]
+    pub ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub values: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSafeTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="9")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="10")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSetApprovalForAllCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub operator: ::prost::alloc::string::String,
+    #[prost(bool, tag="7")]
+    pub approved: bool,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CtfSplitPositionCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub collateral_token: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub parent_collection_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub condition_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub partition: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", tag="10")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="11")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="12")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="13")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// VAULT EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultDeposit {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub user: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub usdc_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub steth_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub fee_amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="9")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="10")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="11")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// VAULT CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultDepositAndMintCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub amount_in: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VaultWithdrawUsdcCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(bytes="vec", tag="6")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="8")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="9")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// STETH EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethApproval {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// STETH CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethApproveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethMintCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="9")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="10")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransferCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StethTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="9")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+/// USDC EVENT MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcApproval {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub owner: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransfer {
+    #[prost(string, tag="1")]
+    pub evt_tx_hash: ::prost::alloc::string::String,
+    #[prost(uint32, tag="2")]
+    pub evt_index: u32,
+    #[prost(message, optional, tag="3")]
+    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="4")]
+    pub evt_block_number: u64,
+    #[prost(string, tag="5")]
+    pub from: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub to: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub value: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="8")]
+    pub evt_tx_context: ::core::option::Option<TxContext>,
+    #[prost(uint64, tag="9")]
+    pub evt_ordinal: u64,
+    #[prost(string, tag="10")]
+    pub evt_block_hash: ::prost::alloc::string::String,
+}
+/// USDC CALL MESSAGES
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcApproveCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcDecreaseAllowanceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub subtracted_value: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcIncreaseAllowanceCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub spender: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub added_value: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransferCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="8")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="9")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="10")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="11")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UsdcTransferFromCall {
+    #[prost(string, tag="1")]
+    pub call_tx_hash: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="2")]
+    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub call_block_number: u64,
+    #[prost(uint64, tag="4")]
+    pub call_ordinal: u64,
+    #[prost(bool, tag="5")]
+    pub call_success: bool,
+    #[prost(string, tag="6")]
+    pub sender: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub amount: ::prost::alloc::vec::Vec<u8>,
+    /// unnamed in the ABI
+    #[prost(bool, tag="9")]
+    pub output_param0: bool,
+    #[prost(message, optional, tag="10")]
+    pub call_tx_context: ::core::option::Option<TxContext>,
+    #[prost(message, optional, tag="11")]
+    pub call_context: ::core::option::Option<CallContext>,
+    #[prost(string, tag="12")]
+    pub call_block_hash: ::prost::alloc::string::String,
+}
+// @@protoc_insertion_point(module)
@@ -5,6 +5,11 @@ pub mod contract {
         include!("contract.v1.rs");
         // @@protoc_insertion_point(contract.v1)
     }
+    // @@protoc_insertion_point(attribute:contract.v2)
+    pub mod v2 {
+        include!("contract.v2.rs");
+        // @@protoc_insertion_point(contract.v2)
+    }
 }
 pub mod sf {
     pub mod ethereum {
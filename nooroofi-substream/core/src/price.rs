@@ -0,0 +1,95 @@
+//! Prices of exchange fills and orders, in collateral per outcome token. Collateral (USDC)
+//! and outcome tokens both have 6 decimals, so a price is a plain ratio of raw amounts
+//! between 0 and 1.
+
+use crate::pb::contract::v1::{PolymarketctfOrder, PolymarketctfOrderFilled};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+
+/// Asset id the exchange uses for the collateral (USDC) side of an order.
+pub const COLLATERAL_ASSET_ID: &str = "0";
+
+/// Side of an order, numbered as in its `side` field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Buy = 0,
+    Sell = 1,
+}
+
+/// An `OrderFilled` seen from its maker: the outcome token bought or sold, how many of
+/// them and for how much collateral.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    pub token_id: String,
+    pub side: Side,
+    /// Outcome tokens traded.
+    pub size: BigInt,
+    /// Collateral traded.
+    pub notional: BigInt,
+    pub price: BigDecimal,
+}
+
+/// Normalizes a fill, or `None` when neither side is collateral or an amount is zero.
+pub fn fill(event: &PolymarketctfOrderFilled) -> Option<Fill> {
+    let (side, token_id, size, notional) = if event.maker_asset_id == COLLATERAL_ASSET_ID {
+        (Side::Buy, &event.taker_asset_id, &event.taker_amount_filled, &event.maker_amount_filled)
+    } else if event.taker_asset_id == COLLATERAL_ASSET_ID {
+        (Side::Sell, &event.maker_asset_id, &event.maker_amount_filled, &event.taker_amount_filled)
+    } else {
+        return None;
+    };
+
+    let size = parse_amount(size);
+    let notional = parse_amount(notional);
+    Some(Fill {
+        token_id: token_id.clone(),
+        side,
+        price: price(&notional, &size)?,
+        size,
+        notional,
+    })
+}
+
+/// The fill of each `matchOrders` taker order among `fills`, by transaction and taker: the one
+/// against `exchange`, whose maker is the taker. Only a COMPLEMENTARY match trades the token of
+/// a maker fill with the taker, on the other side; in a MINT match both buy complementary
+/// tokens and in a MERGE match both sell them. The taker's side, token and notional are those
+/// of this fill.
+pub fn taker_order_fills<'a>(
+    fills: &'a [PolymarketctfOrderFilled],
+    exchange: &[u8],
+) -> BTreeMap<(&'a str, &'a [u8]), Fill> {
+    let mut taker_fills = BTreeMap::new();
+    for event in fills.iter().filter(|e| e.taker == exchange) {
+        if let Some(fill) = fill(event) {
+            taker_fills.entry((event.evt_tx_hash.as_str(), event.maker.as_slice())).or_insert(fill);
+        }
+    }
+    taker_fills
+}
+
+/// Limit price of an order: the most its maker pays per outcome token when buying, the
+/// least they accept when selling.
+pub fn limit_price(order: &PolymarketctfOrder) -> Option<BigDecimal> {
+    let maker_amount = parse_amount(&order.maker_amount);
+    let taker_amount = parse_amount(&order.taker_amount);
+    if order.side == Side::Buy as u32 {
+        price(&maker_amount, &taker_amount)
+    } else {
+        price(&taker_amount, &maker_amount)
+    }
+}
+
+/// `notional / size`, or `None` when either is zero.
+pub fn price(notional: &BigInt, size: &BigInt) -> Option<BigDecimal> {
+    if notional.is_zero() || size.is_zero() {
+        return None;
+    }
+    Some(BigDecimal::from(notional.clone()) / BigDecimal::from(size.clone()))
+}
+
+/// Parses a decimal amount from a decoded record, treating malformed values as zero.
+pub fn parse_amount(amount: &str) -> BigInt {
+    BigInt::from_str(amount).unwrap_or_default()
+}
@@ -0,0 +1,55 @@
+use crate::mappers;
+use crate::pb::contract::{v1, v2};
+use num_bigint::BigUint;
+use std::str::FromStr;
+use tiny_keccak::{Hasher, Keccak};
+
+/// Encodes a decimal uint256 as 32 big-endian bytes, so that byte order is numeric order.
+/// `None` when `value` isn't a decimal number or doesn't fit in 256 bits.
+pub fn uint256(value: &str) -> Option<Vec<u8>> {
+    let bytes = BigUint::from_str(value).ok()?.to_bytes_be();
+    if bytes.len() > 32 {
+        return None;
+    }
+    let mut out = vec![0u8; 32 - bytes.len()];
+    out.extend(bytes);
+    Some(out)
+}
+
+/// Formats an address as an EIP-55 checksummed `0x` string.
+pub fn address(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+
+    let lower = substreams::Hex(bytes).to_string();
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(lower.as_bytes());
+    keccak.finalize(&mut hash);
+
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 { c.to_ascii_uppercase() } else { c }
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+pub fn events(v1: v1::Events) -> v2::Events {
+    mappers::events_v2(v1)
+}
+
+pub fn calls(v1: v1::Calls) -> v2::Calls {
+    mappers::calls_v2(v1)
+}
+
+pub fn events_calls(v1: v1::EventsCalls) -> v2::EventsCalls {
+    v2::EventsCalls {
+        events: v1.events.map(events),
+        calls: v1.calls.map(calls),
+    }
+}
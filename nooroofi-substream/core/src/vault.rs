@@ -0,0 +1,13 @@
+//! The vault deployment: `NoorooVault` creates its `StETHToken` in its constructor, so the
+//! token's address follows from the vault's.
+
+use crate::order::keccak;
+
+/// Address of the stETH token of the vault at `vault`, the first contract the vault created.
+pub fn steth_address(vault: &[u8]) -> [u8; 20] {
+    // `keccak(rlp([sender, nonce]))`, contract nonces starting at 1.
+    let rlp = [&[0xd6, 0x94], vault, &[0x01]].concat();
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&keccak(&rlp)[12..]);
+    address
+}
@@ -0,0 +1,318 @@
+//! Synthetic blocks and golden-file assertions shared by the integration tests.
+
+#![allow(dead_code)]
+
+use ethabi::{Contract, Token};
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, SerializeOptions};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+pub const EXCHANGE: [u8; 20] = nooroo_core::EXCHANGE;
+pub const CONDITIONAL_TOKENS: [u8; 20] = nooroo_core::CONDITIONAL_TOKENS;
+/// The vault has no fixed address, tests deploy it here.
+pub const VAULT: [u8; 20] = [0x7a; 20];
+pub const BLOCK_NUMBER: u64 = 75_000_000;
+pub const BLOCK_TIME: i64 = 1_750_000_000;
+
+/// A deterministic 20-byte address derived from `seed`.
+pub fn address(seed: u8) -> Vec<u8> {
+    vec![seed; 20]
+}
+
+/// A deterministic 32-byte hash derived from `seed`.
+pub fn hash(seed: u8) -> Vec<u8> {
+    vec![seed; 32]
+}
+
+pub fn uint(value: &str) -> BigInt {
+    BigInt::from_str(value).unwrap()
+}
+
+/// An exchange order in the tuple form of the generated ABI bindings.
+pub struct Order {
+    pub salt: u64,
+    pub maker: Vec<u8>,
+    pub token_id: &'static str,
+    pub maker_amount: &'static str,
+    pub taker_amount: &'static str,
+    pub side: u64,
+}
+
+pub type AbiOrder = (
+    BigInt,
+    Vec<u8>,
+    Vec<u8>,
+    Vec<u8>,
+    BigInt,
+    BigInt,
+    BigInt,
+    BigInt,
+    BigInt,
+    BigInt,
+    BigInt,
+    BigInt,
+    Vec<u8>,
+);
+
+impl Order {
+    pub fn abi(&self) -> AbiOrder {
+        (
+            BigInt::from(self.salt),
+            self.maker.clone(),
+            self.maker.clone(),
+            address(0),
+            uint(self.token_id),
+            uint(self.maker_amount),
+            uint(self.taker_amount),
+            BigInt::from(1_760_000_000u64),
+            BigInt::from(self.salt % 7),
+            BigInt::from(100),
+            BigInt::from(self.side),
+            BigInt::from(0),
+            vec![0xab; 65],
+        )
+    }
+}
+
+/// Encodes an event into a log, splitting its params between topics and data the way the ABI
+/// declares them. Logs of the conditional tokens, `VAULT` and its stETH token use their ABI, any
+/// other address the exchange's.
+pub fn event(address: &[u8], name: &str, params: Vec<Token>) -> eth::Log {
+    let contract = if address == CONDITIONAL_TOKENS {
+        load_abi("abi/ctf_contract.abi.json")
+    } else if address == VAULT {
+        load_abi("abi/vault_contract.abi.json")
+    } else if address == nooroo_core::vault::steth_address(&VAULT) {
+        load_abi("abi/steth_contract.abi.json")
+    } else {
+        load_abi("abi/polymarketctf_contract.abi.json")
+    };
+    let event = contract.event(name).unwrap_or_else(|_| panic!("unknown event {}", name));
+    assert_eq!(event.inputs.len(), params.len(), "wrong param count for {}", name);
+
+    let mut topics = vec![event.signature().as_bytes().to_vec()];
+    let mut data = Vec::new();
+    for (input, param) in event.inputs.iter().zip(params) {
+        if input.indexed {
+            topics.push(ethabi::encode(&[param]));
+        } else {
+            data.push(param);
+        }
+    }
+
+    eth::Log {
+        address: address.to_vec(),
+        topics,
+        data: ethabi::encode(&data),
+        ..Default::default()
+    }
+}
+
+fn load_abi(path: &str) -> Contract {
+    let abi = fs::read(manifest_path(path)).unwrap();
+    Contract::load(abi.as_slice()).unwrap()
+}
+
+pub fn token_address(seed: u8) -> Token {
+    Token::Address(ethabi::Address::from_slice(&address(seed)))
+}
+
+pub fn token_hash(seed: u8) -> Token {
+    Token::FixedBytes(hash(seed))
+}
+
+pub fn token_uint(value: &str) -> Token {
+    Token::Uint(ethabi::Uint::from_dec_str(value).unwrap())
+}
+
+/// A call frame of a transaction, either the root call or one made by the contract it hit.
+pub struct Frame {
+    call: eth::Call,
+    logs: Vec<eth::Log>,
+}
+
+impl Frame {
+    /// Calls `address` with `input`, as the transaction signer for the root frame and as the
+    /// previous frame's contract for nested ones.
+    pub fn call(address: &[u8], input: Vec<u8>) -> Self {
+        Frame {
+            call: eth::Call {
+                call_type: eth::CallType::Call as i32,
+                address: address.to_vec(),
+                input,
+                gas_consumed: 21_000 + address[0] as u64 * 1_000,
+                ..Default::default()
+            },
+            logs: Vec::new(),
+        }
+    }
+
+    pub fn returning(mut self, return_data: Vec<u8>) -> Self {
+        self.call.return_data = return_data;
+        self
+    }
+
+    pub fn reverted(mut self) -> Self {
+        self.call.state_reverted = true;
+        self.call.status_failed = true;
+        self
+    }
+
+    pub fn log(mut self, log: eth::Log) -> Self {
+        self.logs.push(log);
+        self
+    }
+}
+
+/// A transaction made of call frames, the first one being the root call.
+pub struct Tx {
+    from: Vec<u8>,
+    status: eth::TransactionTraceStatus,
+    frames: Vec<Frame>,
+}
+
+impl Tx {
+    pub fn new(from: &[u8]) -> Self {
+        Tx {
+            from: from.to_vec(),
+            status: eth::TransactionTraceStatus::Succeeded,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frames.push(frame);
+        self
+    }
+
+    pub fn failed(mut self) -> Self {
+        self.status = eth::TransactionTraceStatus::Reverted;
+        self
+    }
+}
+
+/// Lays transactions out in a block, numbering calls, logs and ordinals the way Firehose
+/// does: one ordinal counter for the whole block, shared by call starts and logs.
+pub fn block(txs: Vec<Tx>) -> eth::Block {
+    let mut ordinal = 0;
+    let mut block_index = 0;
+
+    let transaction_traces = txs
+        .into_iter()
+        .enumerate()
+        .map(|(tx_index, tx)| {
+            let to = tx.frames.first().map(|f| f.call.address.clone()).unwrap_or_default();
+            let mut caller = tx.from.clone();
+            let mut calls = Vec::new();
+            let mut receipt_logs = Vec::new();
+
+            for (index, frame) in tx.frames.into_iter().enumerate() {
+                let mut call = frame.call;
+                ordinal += 1;
+                call.index = index as u32 + 1;
+                call.parent_index = index as u32;
+                call.depth = index as u32;
+                call.caller = caller.clone();
+                call.begin_ordinal = ordinal;
+                caller = call.address.clone();
+
+                for mut log in frame.logs {
+                    ordinal += 1;
+                    log.ordinal = ordinal;
+                    log.block_index = block_index;
+                    block_index += 1;
+                    if !call.state_reverted {
+                        receipt_logs.push(log.clone());
+                    }
+                    call.logs.push(log);
+                }
+
+                ordinal += 1;
+                call.end_ordinal = ordinal;
+                calls.push(call);
+            }
+
+            let gas_used = calls.first().map(|c| c.gas_consumed).unwrap_or_default();
+            eth::TransactionTrace {
+                hash: hash(0xa0 + tx_index as u8),
+                index: tx_index as u32,
+                from: tx.from,
+                to,
+                nonce: 40 + tx_index as u64,
+                gas_price: Some(eth::BigInt {
+                    bytes: BigInt::from(30_000_000_000u64).to_bytes_be().1,
+                }),
+                gas_used,
+                status: tx.status as i32,
+                receipt: Some(eth::TransactionReceipt {
+                    logs: receipt_logs,
+                    ..Default::default()
+                }),
+                calls,
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    eth::Block {
+        hash: hash(0xbb),
+        number: BLOCK_NUMBER,
+        header: Some(eth::BlockHeader {
+            number: BLOCK_NUMBER,
+            timestamp: Some(prost_types::Timestamp {
+                seconds: BLOCK_TIME,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }),
+        transaction_traces,
+        ..Default::default()
+    }
+}
+
+fn manifest_path(path: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+fn descriptors() -> DescriptorPool {
+    let files = protox::compile(["contract.proto", "contract_v2.proto", "exchange.proto"], [manifest_path("proto")]).unwrap();
+    DescriptorPool::from_file_descriptor_set(files).unwrap()
+}
+
+/// Compares `message` with the checked-in `tests/golden/<name>.json`, rendered as proto
+/// JSON. Run with `UPDATE_GOLDEN=1` to rewrite the golden file instead.
+pub fn assert_golden<M: Message>(name: &str, type_name: &str, message: &M) {
+    let descriptor = descriptors()
+        .get_message_by_name(type_name)
+        .unwrap_or_else(|| panic!("unknown message {}", type_name));
+    let message = DynamicMessage::decode(descriptor, message.encode_to_vec().as_slice()).unwrap();
+
+    let mut json = Vec::new();
+    let mut serializer = serde_json::Serializer::pretty(&mut json);
+    message
+        .serialize_with_options(&mut serializer, &SerializeOptions::new().use_proto_field_name(true))
+        .unwrap();
+    json.push(b'\n');
+    let actual = String::from_utf8(json).unwrap();
+
+    let path = manifest_path(&format!("tests/golden/{}.json", name));
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing {}, run with UPDATE_GOLDEN=1 to create it", path.display()));
+    assert!(
+        actual == expected,
+        "{} differs from {}, run with UPDATE_GOLDEN=1 to update it:\n{}",
+        name,
+        path.display(),
+        actual
+    );
+}
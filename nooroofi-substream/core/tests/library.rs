@@ -0,0 +1,274 @@
+// These cover every event and call, so they need the default features.
+#![cfg(all(feature = "events", feature = "calls", feature = "admin", feature = "trading"))]
+
+mod common;
+
+use common::*;
+use ethabi::Token;
+use hex_literal::hex;
+use nooroo_core::abi::polymarketctf_contract::functions;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, Side};
+use nooroo_core::{ctf, decode_call, decode_log, mappers, order, v2, vault, ExchangeCall, ExchangeEvent};
+use num_bigint::BigUint;
+use substreams::scalar::BigDecimal;
+use substreams_ethereum::pb::eth::v2 as eth;
+use tiny_keccak::{Hasher, Keccak};
+
+const TOKEN: &str = "1234";
+
+fn order_filled(maker_asset: &str, taker_asset: &str, maker_amount: &str, taker_amount: &str) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        maker_asset_id: maker_asset.to_string(),
+        taker_asset_id: taker_asset.to_string(),
+        maker_amount_filled: maker_amount.to_string(),
+        taker_amount_filled: taker_amount.to_string(),
+        ..Default::default()
+    }
+}
+
+fn fill_order_call(reverted: bool) -> eth::Call {
+    let order = Order {
+        salt: 42,
+        maker: address(0x34),
+        token_id: TOKEN,
+        maker_amount: "30000000",
+        taker_amount: "100000000",
+        side: 0,
+    };
+    eth::Call {
+        address: EXCHANGE.to_vec(),
+        input: functions::FillOrder {
+            order: order.abi(),
+            fill_amount: uint("30000000"),
+        }
+        .encode(),
+        begin_ordinal: 7,
+        state_reverted: reverted,
+        ..Default::default()
+    }
+}
+
+fn decoded_order() -> contract::PolymarketctfOrder {
+    match decode_call(&fill_order_call(false)) {
+        Some(ExchangeCall::FillOrder(call)) => call.order.unwrap(),
+        _ => panic!("fillOrder not decoded"),
+    }
+}
+
+fn keccak(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+    hash
+}
+
+#[test]
+fn decode_log_leaves_transaction_fields_to_the_caller() {
+    let mut log = event(
+        &EXCHANGE,
+        "FeeCharged",
+        vec![token_address(0x34), token_uint(TOKEN), token_uint("250000")],
+    );
+    log.block_index = 3;
+    log.ordinal = 12;
+
+    let Some(ExchangeEvent::FeeCharged(fee)) = decode_log(&log) else {
+        panic!("FeeCharged not decoded");
+    };
+    assert_eq!(
+        fee,
+        contract::PolymarketctfFeeCharged {
+            evt_index: 3,
+            evt_ordinal: 12,
+            receiver: address(0x34),
+            token_id: TOKEN.to_string(),
+            amount: "250000".to_string(),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn decode_log_ignores_other_contracts() {
+    let log = event(&address(0xc7), "TradingPaused", vec![token_address(0x0a)]);
+    assert!(decode_log(&log).is_none());
+}
+
+#[test]
+fn decode_call_keeps_reverted_calls() {
+    let Some(ExchangeCall::FillOrder(call)) = decode_call(&fill_order_call(true)) else {
+        panic!("fillOrder not decoded");
+    };
+    assert!(!call.call_success);
+    assert_eq!(call.call_ordinal, 7);
+    assert_eq!(call.fill_amount, "30000000");
+    assert_eq!(call.order.unwrap().maker, address(0x34));
+}
+
+#[test]
+fn hash_order_matches_the_abi_encoding() {
+    let order = decoded_order();
+    let domain_separator = order::domain_separator(order::POLYGON_CHAIN_ID, &EXCHANGE);
+
+    let expected_domain = keccak(&ethabi::encode(&[
+        Token::FixedBytes(
+            keccak(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)").to_vec(),
+        ),
+        Token::FixedBytes(keccak(b"Polymarket CTF Exchange").to_vec()),
+        Token::FixedBytes(keccak(b"1").to_vec()),
+        token_uint("137"),
+        Token::Address(ethabi::Address::from_slice(&EXCHANGE)),
+    ]));
+    assert_eq!(domain_separator, expected_domain);
+
+    let struct_hash = keccak(&ethabi::encode(&[
+        Token::FixedBytes(
+            keccak(
+                b"Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,\
+                uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,\
+                uint256 feeRateBps,uint8 side,uint8 signatureType)",
+            )
+            .to_vec(),
+        ),
+        token_uint("42"),
+        token_address(0x34),
+        token_address(0x34),
+        token_address(0),
+        token_uint(TOKEN),
+        token_uint("30000000"),
+        token_uint("100000000"),
+        token_uint("1760000000"),
+        token_uint("0"),
+        token_uint("100"),
+        token_uint("0"),
+        token_uint("0"),
+    ]));
+    let expected = keccak(&[b"\x19\x01".as_slice(), &expected_domain, &struct_hash].concat());
+
+    assert_eq!(order::hash_order(&order, &domain_separator), Some(expected));
+    assert_eq!(order::exchange_order_hash(&order), Some(expected));
+
+    let malformed = contract::PolymarketctfOrder { maker_amount: "30e6".to_string(), ..order };
+    assert_eq!(order::exchange_order_hash(&malformed), None);
+}
+
+#[test]
+fn v2_addresses_follow_the_eip55_vectors() {
+    for expected in [
+        // All caps, all lowercase, then mixed case.
+        "0x52908400098527886E0F7030069857D2E4169EE7",
+        "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+        "0xde709f2102306220921060314715629080e2fb77",
+        "0x27b1fdb04752bbc536007a920d24acb045561c26",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        let bytes = substreams::Hex::decode(&expected[2..]).unwrap();
+        assert_eq!(v2::address(&bytes), expected);
+    }
+    assert_eq!(v2::address(&[]), "");
+}
+
+#[test]
+fn v2_uint256s_are_32_big_endian_bytes() {
+    assert_eq!(v2::uint256("0"), Some(vec![0; 32]));
+    assert_eq!(v2::uint256("258").unwrap()[30..], [1, 2]);
+    assert_eq!(v2::uint256("258").unwrap()[..30], [0; 30]);
+
+    let max = (BigUint::from(1u32) << 256u32) - 1u32;
+    assert_eq!(v2::uint256(&max.to_string()), Some(vec![0xff; 32]));
+    // Byte order is numeric order.
+    assert!(v2::uint256("1000000").unwrap() > v2::uint256("999999").unwrap());
+
+    let overflow = BigUint::from(1u32) << 256u32;
+    for malformed in ["", "-1", "0x10", "1.5", " 1", &overflow.to_string()] {
+        assert_eq!(v2::uint256(malformed), None, "{:?}", malformed);
+    }
+}
+
+#[test]
+fn fills_are_priced_from_the_maker_side() {
+    let buy = price::fill(&order_filled("0", TOKEN, "30000000", "100000000")).unwrap();
+    assert_eq!(buy.side, Side::Buy);
+    assert_eq!(buy.token_id, TOKEN);
+    assert_eq!(buy.size, uint("100000000"));
+    assert_eq!(buy.notional, uint("30000000"));
+    assert_eq!(buy.price, BigDecimal::try_from("0.3").unwrap());
+
+    let sell = price::fill(&order_filled(TOKEN, "0", "100000000", "70000000")).unwrap();
+    assert_eq!(sell.side, Side::Sell);
+    assert_eq!(sell.price, BigDecimal::try_from("0.7").unwrap());
+
+    assert!(price::fill(&order_filled(TOKEN, "5678", "1", "1")).is_none());
+    assert!(price::fill(&order_filled("0", TOKEN, "0", "100")).is_none());
+}
+
+#[test]
+fn limit_price_follows_the_order_side() {
+    let mut order = decoded_order();
+    assert_eq!(price::limit_price(&order), Some(BigDecimal::try_from("0.3").unwrap()));
+
+    order.side = Side::Sell as u32;
+    order.maker_amount = "100000000".to_string();
+    order.taker_amount = "70000000".to_string();
+    assert_eq!(price::limit_price(&order), Some(BigDecimal::try_from("0.7").unwrap()));
+}
+
+#[test]
+fn collection_ids_are_points_of_the_curve() {
+    let p = BigUint::parse_bytes(b"21888242871839275222246405745257275088696311157297823662689037894645226208583", 10).unwrap();
+    let condition_id = hash(0xcc);
+
+    for index_set in [1, 2, 3] {
+        let mut x = BigUint::from_bytes_be(&ctf::collection_id(&condition_id, index_set));
+        x.set_bit(254, false);
+        let yy = (&x * &x * &x + 3u32) % &p;
+        assert_eq!(yy.modpow(&((&p - 1u32) / 2u32), &p), BigUint::from(1u32), "no y for index set {}", index_set);
+    }
+    assert_ne!(ctf::collection_id(&condition_id, 1), ctf::collection_id(&condition_id, 2));
+}
+
+#[test]
+fn outcome_index_finds_the_position_of_each_outcome() {
+    let condition_id = hash(0xcc);
+    let yes = ctf::position_id(&ctf::EXCHANGE_COLLATERAL, &ctf::collection_id(&condition_id, 1));
+    let no = ctf::position_id(&ctf::EXCHANGE_COLLATERAL, &ctf::collection_id(&condition_id, 2));
+
+    assert_eq!(ctf::outcome_index(&ctf::EXCHANGE_COLLATERAL, &condition_id, 2, &yes), Some(0));
+    assert_eq!(ctf::outcome_index(&ctf::EXCHANGE_COLLATERAL, &condition_id, 2, &no), Some(1));
+    assert_eq!(ctf::outcome_index(&ctf::EXCHANGE_COLLATERAL, &hash(0xdd), 2, &yes), None);
+    assert_eq!(ctf::outcome_index(&address(0x01), &condition_id, 2, &yes), None);
+}
+
+#[test]
+fn steth_is_the_first_contract_the_vault_creates() {
+    let vault = hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+    assert_eq!(vault::steth_address(&vault), hex!("343c43a37d37dff08ae8c4a11544c718abb4fcf8"));
+}
+
+#[test]
+fn vault_logs_are_decoded_at_the_given_address() {
+    let log = event(
+        &VAULT,
+        "Deposit",
+        vec![token_address(0x51), token_uint("1000000"), token_uint("995"), token_uint("5000")],
+    );
+
+    let Some(mappers::vault::Event::Deposit(deposit)) = mappers::vault::decode_log(&log, &VAULT) else {
+        panic!("Deposit not decoded");
+    };
+    assert_eq!(deposit.user, address(0x51));
+    assert_eq!(
+        [deposit.usdc_amount, deposit.steth_amount, deposit.fee_amount],
+        ["1000000", "995", "5000"]
+    );
+    assert!(mappers::vault::decode_log(&log, &address(0x7b)).is_none());
+
+    let steth = vault::steth_address(&VAULT);
+    let transfer = event(&steth, "Transfer", vec![token_address(0), token_address(0x51), token_uint("995")]);
+    assert!(matches!(mappers::steth::decode_log(&transfer, &steth), Some(mappers::steth::Event::Transfer(_))));
+}
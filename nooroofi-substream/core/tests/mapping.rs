@@ -0,0 +1,529 @@
+// These cover every event and call, so they need the default features.
+#![cfg(all(feature = "events", feature = "calls", feature = "admin", feature = "trading"))]
+
+mod common;
+
+use common::*;
+use nooroo_core::abi::ctf_contract::functions as ctf_functions;
+use nooroo_core::abi::polymarketctf_contract::functions;
+use nooroo_core::pb::contract::v1 as contract;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const ADMIN: u8 = 0x0a;
+const OPERATOR: u8 = 0x0b;
+const OTHER: u8 = 0xc7;
+const PROXY: u8 = 0xd0;
+
+const YES: &str = "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+const NO: &str = "52114319501245915516055106046884209969926127482827954674443846427813813222426";
+const COLLATERAL: &str = "0";
+
+/// `onERC1155Received` / `onERC1155BatchReceived` acknowledgement, left-aligned in a word.
+fn erc1155_ack(selector: [u8; 4]) -> Vec<u8> {
+    ethabi::encode(&[ethabi::Token::FixedBytes(selector.to_vec())])
+}
+
+fn exchange_address() -> ethabi::Token {
+    ethabi::Token::Address(ethabi::Address::from_slice(&EXCHANGE))
+}
+
+fn admin_tx(input: Vec<u8>, logs: Vec<eth::Log>) -> Tx {
+    let frame = logs.into_iter().fold(Frame::call(&EXCHANGE, input), Frame::log);
+    Tx::new(&address(ADMIN)).frame(frame)
+}
+
+fn maker_order(salt: u64, maker: u8, token_id: &'static str, side: u64) -> Order {
+    Order {
+        salt,
+        maker: address(maker),
+        token_id,
+        maker_amount: "50000000",
+        taker_amount: "100000000",
+        side,
+    }
+}
+
+fn order_filled(order_hash: u8, maker: &[u8], taker: &[u8], maker_asset: &str, taker_asset: &str, fee: &str) -> eth::Log {
+    event(
+        &EXCHANGE,
+        "OrderFilled",
+        vec![
+            token_hash(order_hash),
+            ethabi::Token::Address(ethabi::Address::from_slice(maker)),
+            ethabi::Token::Address(ethabi::Address::from_slice(taker)),
+            token_uint(maker_asset),
+            token_uint(taker_asset),
+            token_uint("50000000"),
+            token_uint("100000000"),
+            token_uint(fee),
+        ],
+    )
+}
+
+/// One block exercising every event and call of the exchange and the position transfers of
+/// the CTF, next to a reverted call frame, a failed transaction and look-alike logs and calls
+/// on other contracts.
+fn fixture_block() -> eth::Block {
+    let condition_id = hash(0xcc);
+    let cancelled = maker_order(1, 0x31, YES, 0);
+    let batch_cancelled = [maker_order(2, 0x32, YES, 0), maker_order(3, 0x33, NO, 1)];
+    let filled = maker_order(4, 0x34, YES, 1);
+    let batch_filled = [maker_order(5, 0x35, YES, 1), maker_order(6, 0x36, NO, 1)];
+    let taker = maker_order(7, 0x37, YES, 0);
+    let makers = [maker_order(8, 0x38, YES, 1), maker_order(9, 0x39, YES, 1)];
+
+    let txs = vec![
+        admin_tx(
+            functions::AddAdmin { admin: address(0x11) }.encode(),
+            vec![event(&EXCHANGE, "NewAdmin", vec![token_address(0x11), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::AddOperator { operator: address(OPERATOR) }.encode(),
+            vec![event(&EXCHANGE, "NewOperator", vec![token_address(OPERATOR), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::RemoveAdmin { admin: address(0x11) }.encode(),
+            vec![event(&EXCHANGE, "RemovedAdmin", vec![token_address(0x11), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::RemoveOperator { operator: address(0x12) }.encode(),
+            vec![event(&EXCHANGE, "RemovedOperator", vec![token_address(0x12), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::RenounceAdminRole {}.encode(),
+            vec![event(&EXCHANGE, "RemovedAdmin", vec![token_address(ADMIN), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::RenounceOperatorRole {}.encode(),
+            vec![event(&EXCHANGE, "RemovedOperator", vec![token_address(ADMIN), token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::SetProxyFactory { new_proxy_factory: address(0x21) }.encode(),
+            vec![event(&EXCHANGE, "ProxyFactoryUpdated", vec![token_address(0x20), token_address(0x21)])],
+        ),
+        admin_tx(
+            functions::SetSafeFactory { new_safe_factory: address(0x23) }.encode(),
+            vec![event(&EXCHANGE, "SafeFactoryUpdated", vec![token_address(0x22), token_address(0x23)])],
+        ),
+        admin_tx(
+            functions::PauseTrading {}.encode(),
+            vec![event(&EXCHANGE, "TradingPaused", vec![token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::UnpauseTrading {}.encode(),
+            vec![event(&EXCHANGE, "TradingUnpaused", vec![token_address(ADMIN)])],
+        ),
+        admin_tx(
+            functions::RegisterToken {
+                token: uint(YES),
+                complement: uint(NO),
+                condition_id: condition_id.clone().try_into().unwrap(),
+            }
+            .encode(),
+            vec![
+                event(&EXCHANGE, "TokenRegistered", vec![token_uint(YES), token_uint(NO), token_hash(0xcc)]),
+                event(&EXCHANGE, "TokenRegistered", vec![token_uint(NO), token_uint(YES), token_hash(0xcc)]),
+            ],
+        ),
+        Tx::new(&address(0x31)).frame(Frame::call(&EXCHANGE, functions::IncrementNonce {}.encode())),
+        Tx::new(&address(0x31)).frame(
+            Frame::call(&EXCHANGE, functions::CancelOrder { order: cancelled.abi() }.encode())
+                .log(event(&EXCHANGE, "OrderCancelled", vec![token_hash(0x01)])),
+        ),
+        Tx::new(&address(0x32)).frame(
+            Frame::call(
+                &EXCHANGE,
+                functions::CancelOrders {
+                    orders: batch_cancelled.iter().map(Order::abi).collect(),
+                }
+                .encode(),
+            )
+            .log(event(&EXCHANGE, "OrderCancelled", vec![token_hash(0x02)]))
+            .log(event(&EXCHANGE, "OrderCancelled", vec![token_hash(0x03)])),
+        ),
+        // The exchange pulls the maker's outcome tokens through the CTF, which calls back
+        // `onERC1155Received` on the exchange.
+        Tx::new(&address(OPERATOR))
+            .frame(
+                Frame::call(
+                    &EXCHANGE,
+                    functions::FillOrder {
+                        order: filled.abi(),
+                        fill_amount: uint("100000000"),
+                    }
+                    .encode(),
+                )
+                .log(order_filled(0x04, &filled.maker, &address(OPERATOR), YES, COLLATERAL, "1000000"))
+                .log(event(
+                    &EXCHANGE,
+                    "FeeCharged",
+                    vec![token_address(0xfe), token_uint(COLLATERAL), token_uint("1000000")],
+                )),
+            )
+            .frame(
+                Frame::call(
+                    &CONDITIONAL_TOKENS,
+                    ctf_functions::SafeTransferFrom {
+                        from: filled.maker.clone(),
+                        to: EXCHANGE.to_vec(),
+                        id: uint(YES),
+                        value: uint("50000000"),
+                        data: Vec::new(),
+                    }
+                    .encode(),
+                )
+                .log(event(
+                    &CONDITIONAL_TOKENS,
+                    "TransferSingle",
+                    vec![
+                        exchange_address(),
+                        token_address(0x34),
+                        exchange_address(),
+                        token_uint(YES),
+                        token_uint("50000000"),
+                    ],
+                )),
+            )
+            .frame(
+                Frame::call(
+                    &EXCHANGE,
+                    functions::OnErc1155Received {
+                        operator: EXCHANGE.to_vec(),
+                        from: filled.maker.clone(),
+                        id: uint(YES),
+                        value: uint("50000000"),
+                        data: Vec::new(),
+                    }
+                    .encode(),
+                )
+                .returning(erc1155_ack([0xf2, 0x3a, 0x6e, 0x61])),
+            ),
+        Tx::new(&address(OPERATOR)).frame(
+            Frame::call(
+                &EXCHANGE,
+                functions::FillOrders {
+                    orders: batch_filled.iter().map(Order::abi).collect(),
+                    fill_amounts: vec![uint("100000000"), uint("40000000")],
+                }
+                .encode(),
+            )
+            .log(order_filled(0x05, &batch_filled[0].maker, &address(OPERATOR), YES, COLLATERAL, "0"))
+            .log(order_filled(0x06, &batch_filled[1].maker, &address(OPERATOR), NO, COLLATERAL, "0")),
+        ),
+        // The maker orders are filled against the taker order, then the taker order against
+        // the exchange itself; the CTF hands the batch of outcome tokens to the exchange.
+        Tx::new(&address(OPERATOR))
+            .frame(
+                Frame::call(
+                    &EXCHANGE,
+                    functions::MatchOrders {
+                        taker_order: taker.abi(),
+                        maker_orders: makers.iter().map(Order::abi).collect(),
+                        taker_fill_amount: uint("100000000"),
+                        maker_fill_amounts: vec![uint("50000000"), uint("50000000")],
+                    }
+                    .encode(),
+                )
+                .log(order_filled(0x08, &makers[0].maker, &taker.maker, YES, COLLATERAL, "0"))
+                .log(order_filled(0x09, &makers[1].maker, &taker.maker, YES, COLLATERAL, "0"))
+                .log(order_filled(0x07, &taker.maker, &EXCHANGE, COLLATERAL, YES, "2000000"))
+                .log(event(
+                    &EXCHANGE,
+                    "OrdersMatched",
+                    vec![
+                        token_hash(0x07),
+                        token_address(0x37),
+                        token_uint(COLLATERAL),
+                        token_uint(YES),
+                        token_uint("100000000"),
+                        token_uint("200000000"),
+                    ],
+                ))
+                .log(event(
+                    &EXCHANGE,
+                    "FeeCharged",
+                    vec![token_address(0xfe), token_uint(YES), token_uint("2000000")],
+                )),
+            )
+            .frame(
+                Frame::call(
+                    &CONDITIONAL_TOKENS,
+                    ctf_functions::SafeBatchTransferFrom {
+                        from: taker.maker.clone(),
+                        to: EXCHANGE.to_vec(),
+                        ids: vec![uint(YES), uint(NO)],
+                        values: vec![uint("50000000"), uint("50000000")],
+                        data: vec![0x01, 0x02],
+                    }
+                    .encode(),
+                )
+                .log(event(
+                    &CONDITIONAL_TOKENS,
+                    "TransferBatch",
+                    vec![
+                        exchange_address(),
+                        token_address(0x37),
+                        exchange_address(),
+                        ethabi::Token::Array(vec![token_uint(YES), token_uint(NO)]),
+                        ethabi::Token::Array(vec![token_uint("50000000"), token_uint("50000000")]),
+                    ],
+                )),
+            )
+            .frame(
+                Frame::call(
+                    &EXCHANGE,
+                    functions::OnErc1155BatchReceived {
+                        operator: EXCHANGE.to_vec(),
+                        from: taker.maker.clone(),
+                        ids: vec![uint(YES), uint(NO)],
+                        values: vec![uint("50000000"), uint("50000000")],
+                        data: vec![0x01, 0x02],
+                    }
+                    .encode(),
+                )
+                .returning(erc1155_ack([0xbc, 0x19, 0x7c, 0x81])),
+            ),
+        // A proxy wallet's fill reverts inside a transaction that still succeeds: the call is
+        // kept as unsuccessful and its log never reaches the receipt.
+        Tx::new(&address(0x40))
+            .frame(Frame::call(&address(PROXY), vec![0x12, 0x34, 0x56, 0x78]))
+            .frame(
+                Frame::call(
+                    &EXCHANGE,
+                    functions::FillOrder {
+                        order: maker_order(10, 0x3a, NO, 0).abi(),
+                        fill_amount: uint("1"),
+                    }
+                    .encode(),
+                )
+                .log(order_filled(0x0a, &address(0x3a), &address(PROXY), COLLATERAL, NO, "0"))
+                .reverted(),
+            ),
+        // Failed transactions are dropped with everything they touched.
+        admin_tx(
+            functions::PauseTrading {}.encode(),
+            vec![event(&EXCHANGE, "TradingPaused", vec![token_address(0x50)])],
+        )
+        .failed(),
+        // Look-alike logs and calls on other contracts are ignored.
+        Tx::new(&address(ADMIN)).frame(
+            Frame::call(&address(OTHER), functions::AddAdmin { admin: address(0x51) }.encode())
+                .log(event(&address(OTHER), "TradingPaused", vec![token_address(0x51)]))
+                .log(event(&address(OTHER), "OrderCancelled", vec![token_hash(0x51)])),
+        ),
+    ];
+
+    block(txs)
+}
+
+fn events() -> contract::Events {
+    let mut events = contract::Events::default();
+    nooroo_core::mappers::map_events(&fixture_block(), &mut events);
+    events
+}
+
+fn calls() -> contract::Calls {
+    let mut calls = contract::Calls::default();
+    nooroo_core::mappers::map_calls(&fixture_block(), &mut calls);
+    calls
+}
+
+#[test]
+fn events_match_golden() {
+    assert_golden("events", "contract.v1.Events", &events());
+}
+
+#[test]
+fn calls_match_golden() {
+    assert_golden("calls", "contract.v1.Calls", &calls());
+}
+
+#[test]
+fn every_event_is_decoded() {
+    let events = events();
+    let counts = [
+        ("FeeCharged", events.polymarketctf_fee_chargeds.len()),
+        ("NewAdmin", events.polymarketctf_new_admins.len()),
+        ("NewOperator", events.polymarketctf_new_operators.len()),
+        ("OrderCancelled", events.polymarketctf_order_cancelleds.len()),
+        ("OrderFilled", events.polymarketctf_order_filleds.len()),
+        ("OrdersMatched", events.polymarketctf_orders_matcheds.len()),
+        ("ProxyFactoryUpdated", events.polymarketctf_proxy_factory_updateds.len()),
+        ("RemovedAdmin", events.polymarketctf_removed_admins.len()),
+        ("RemovedOperator", events.polymarketctf_removed_operators.len()),
+        ("SafeFactoryUpdated", events.polymarketctf_safe_factory_updateds.len()),
+        ("TokenRegistered", events.polymarketctf_token_registereds.len()),
+        ("TradingPaused", events.polymarketctf_trading_pauseds.len()),
+        ("TradingUnpaused", events.polymarketctf_trading_unpauseds.len()),
+    ];
+
+    assert_eq!(
+        counts,
+        [
+            ("FeeCharged", 2),
+            ("NewAdmin", 1),
+            ("NewOperator", 1),
+            ("OrderCancelled", 3),
+            ("OrderFilled", 6),
+            ("OrdersMatched", 1),
+            ("ProxyFactoryUpdated", 1),
+            ("RemovedAdmin", 2),
+            ("RemovedOperator", 2),
+            ("SafeFactoryUpdated", 1),
+            ("TokenRegistered", 2),
+            ("TradingPaused", 1),
+            ("TradingUnpaused", 1),
+        ]
+    );
+}
+
+#[test]
+fn every_call_is_decoded() {
+    let calls = calls();
+    let counts = [
+        ("addAdmin", calls.polymarketctf_call_add_admins.len()),
+        ("addOperator", calls.polymarketctf_call_add_operators.len()),
+        ("cancelOrder", calls.polymarketctf_call_cancel_orders.len()),
+        ("cancelOrders", calls.polymarketctf_call_cancel_orders_batch.len()),
+        ("fillOrder", calls.polymarketctf_call_fill_orders.len()),
+        ("fillOrders", calls.polymarketctf_call_fill_orders_batch.len()),
+        ("incrementNonce", calls.polymarketctf_call_increment_nonces.len()),
+        ("matchOrders", calls.polymarketctf_call_match_orders.len()),
+        ("onERC1155BatchReceived", calls.polymarketctf_call_on_erc1155_batch_receiveds.len()),
+        ("onERC1155Received", calls.polymarketctf_call_on_erc1155_receiveds.len()),
+        ("pauseTrading", calls.polymarketctf_call_pause_tradings.len()),
+        ("registerToken", calls.polymarketctf_call_register_tokens.len()),
+        ("removeAdmin", calls.polymarketctf_call_remove_admins.len()),
+        ("removeOperator", calls.polymarketctf_call_remove_operators.len()),
+        ("renounceAdminRole", calls.polymarketctf_call_renounce_admin_roles.len()),
+        ("renounceOperatorRole", calls.polymarketctf_call_renounce_operator_roles.len()),
+        ("setProxyFactory", calls.polymarketctf_call_set_proxy_factories.len()),
+        ("setSafeFactory", calls.polymarketctf_call_set_safe_factories.len()),
+        ("unpauseTrading", calls.polymarketctf_call_unpause_tradings.len()),
+    ];
+
+    assert_eq!(
+        counts,
+        [
+            ("addAdmin", 1),
+            ("addOperator", 1),
+            ("cancelOrder", 1),
+            ("cancelOrders", 1),
+            ("fillOrder", 2),
+            ("fillOrders", 1),
+            ("incrementNonce", 1),
+            ("matchOrders", 1),
+            ("onERC1155BatchReceived", 1),
+            ("onERC1155Received", 1),
+            ("pauseTrading", 1),
+            ("registerToken", 1),
+            ("removeAdmin", 1),
+            ("removeOperator", 1),
+            ("renounceAdminRole", 1),
+            ("renounceOperatorRole", 1),
+            ("setProxyFactory", 1),
+            ("setSafeFactory", 1),
+            ("unpauseTrading", 1),
+        ]
+    );
+}
+
+#[test]
+fn ctf_transfers_are_decoded() {
+    let events = events();
+    let singles: Vec<(Vec<u8>, Vec<u8>, &str, &str)> = events
+        .ctf_transfer_singles
+        .iter()
+        .map(|e| (e.from.clone(), e.to.clone(), e.id.as_str(), e.value.as_str()))
+        .collect();
+    assert_eq!(singles, [(address(0x34), EXCHANGE.to_vec(), YES, "50000000")]);
+
+    let [batch] = events.ctf_transfer_batches.as_slice() else {
+        panic!("expected one TransferBatch");
+    };
+    assert_eq!(batch.operator, EXCHANGE);
+    assert_eq!(batch.ids, [YES, NO]);
+    assert_eq!(batch.values, ["50000000", "50000000"]);
+
+    let calls = calls();
+    assert_eq!(calls.ctf_call_safe_transfer_froms.len(), 1);
+    assert_eq!(calls.ctf_call_safe_batch_transfer_froms.len(), 1);
+    assert_eq!(calls.ctf_call_safe_batch_transfer_froms[0].call_context.as_ref().unwrap().depth, 1);
+}
+
+#[test]
+fn reverted_call_frames_are_unsuccessful() {
+    let calls = calls();
+    let fills: Vec<(bool, u32)> = calls
+        .polymarketctf_call_fill_orders
+        .iter()
+        .map(|c| (c.call_success, c.call_context.as_ref().unwrap().depth))
+        .collect();
+    assert_eq!(fills, [(true, 0), (false, 1)]);
+
+    let events = events();
+    assert!(events.polymarketctf_order_filleds.iter().all(|e| e.order_hash != hash(0x0a)));
+}
+
+#[test]
+fn failed_transactions_and_other_contracts_are_ignored() {
+    let events = events();
+    assert!(events.polymarketctf_trading_pauseds.iter().all(|e| e.pauser == address(ADMIN)));
+    assert!(events.polymarketctf_order_cancelleds.iter().all(|e| e.order_hash != hash(0x51)));
+
+    let calls = calls();
+    assert!(calls.polymarketctf_call_add_admins.iter().all(|c| c.admin == address(0x11)));
+}
+
+/// The transaction of the fixture block with hash `tx_hash`.
+fn transaction(tx_hash: &str) -> eth::TransactionTrace {
+    fixture_block()
+        .transaction_traces
+        .into_iter()
+        .find(|tx| substreams::Hex(&tx.hash).to_string() == tx_hash)
+        .unwrap_or_else(|| panic!("no transaction {}", tx_hash))
+}
+
+#[test]
+fn events_carry_their_transaction_context_in_ordinal_order() {
+    let events = events();
+    let fills = &events.polymarketctf_order_filleds;
+    let ordinals: Vec<u64> = fills.iter().map(|e| e.evt_ordinal).collect();
+    assert!(ordinals.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", ordinals);
+
+    for fill in fills {
+        let tx = transaction(&fill.evt_tx_hash);
+        let log = tx.receipt.as_ref().unwrap().logs.iter().find(|l| l.ordinal == fill.evt_ordinal).unwrap();
+        assert_eq!(fill.evt_index, log.block_index);
+
+        let context = fill.evt_tx_context.as_ref().unwrap();
+        assert_eq!((context.index, &context.from, &context.to), (tx.index, &tx.from, &tx.to));
+        assert_eq!((context.nonce, context.gas_used, context.status), (tx.nonce, tx.gas_used, tx.status));
+        assert_eq!(context.gas_price, "30000000000");
+        assert_eq!((fill.evt_block_number, fill.evt_block_time.as_ref().unwrap().seconds), (BLOCK_NUMBER, BLOCK_TIME));
+    }
+}
+
+#[test]
+fn calls_carry_their_transaction_and_frame_context() {
+    let calls = calls();
+    let ordinals: Vec<u64> = calls.polymarketctf_call_fill_orders.iter().map(|c| c.call_ordinal).collect();
+    assert!(ordinals.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", ordinals);
+
+    for call in &calls.polymarketctf_call_fill_orders {
+        let tx = transaction(&call.call_tx_hash);
+        let frame = tx.calls.iter().find(|c| c.begin_ordinal == call.call_ordinal).unwrap();
+
+        let context = call.call_context.as_ref().unwrap();
+        assert_eq!((&context.caller, context.depth), (&frame.caller, frame.depth));
+        assert_eq!((context.call_type, context.gas_consumed), (frame.call_type, frame.gas_consumed));
+
+        let tx_context = call.call_tx_context.as_ref().unwrap();
+        assert_eq!((tx_context.index, &tx_context.from, tx_context.gas_used), (tx.index, &tx.from, tx.gas_used));
+    }
+
+    // The nested fillOrder is called by the contract of its parent frame, not the signer.
+    let nested = calls.polymarketctf_call_fill_orders.iter().find(|c| !c.call_success).unwrap();
+    let context = nested.call_context.as_ref().unwrap();
+    assert_ne!(context.caller, nested.call_tx_context.as_ref().unwrap().from);
+}
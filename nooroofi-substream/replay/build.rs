@@ -0,0 +1,18 @@
+use anyhow::Result;
+use prost::Message;
+use std::{env, fs, path::PathBuf};
+
+// Compiles the package's proto files into a descriptor set, so that the replayed module
+// outputs can be written as JSON without the substreams CLI.
+fn main() -> Result<()> {
+    let files = ["../core/proto/contract.proto", "../core/proto/contract_v2.proto", "../core/proto/exchange.proto"];
+    for f in files {
+        println!("cargo:rerun-if-changed={}", f);
+    }
+
+    let descriptors = protox::compile(files, ["../core/proto"])?;
+    let out = PathBuf::from(env::var("OUT_DIR")?).join("descriptors.bin");
+    fs::write(out, descriptors.encode_to_vec())?;
+
+    Ok(())
+}
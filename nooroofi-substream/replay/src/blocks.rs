@@ -0,0 +1,174 @@
+use anyhow::{bail, Context, Result};
+use prost::Message;
+use std::fs;
+use std::path::{Path, PathBuf};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const DBIN_MAGIC: &[u8] = b"dbin";
+/// Frame magic of zstd, which merged-blocks stores compress their bundles with.
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Envelope of each block in a merged-blocks bundle. Older bundles carry the chain block in
+/// `payload_buffer`, newer ones in the `payload` Any; the other fields are not needed here.
+#[derive(Clone, PartialEq, Message)]
+struct BstreamBlock {
+    #[prost(bytes = "vec", tag = "8")]
+    payload_buffer: Vec<u8>,
+    #[prost(message, optional, tag = "11")]
+    payload: Option<prost_types::Any>,
+}
+
+/// Lists the block files of a directory in name order, which is block order for the usual
+/// zero-padded `<number>.pb` exports and merged-blocks bundle names.
+pub fn block_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = fs::read_dir(dir)
+        .with_context(|| format!("reading block directory {}", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    files.retain(|path| path.is_file());
+    files.sort();
+
+    Ok(files)
+}
+
+/// Reads either a single serialized `sf.ethereum.type.v2.Block` or a merged-blocks bundle,
+/// zstd-compressed or not.
+pub fn read_blocks(path: &Path) -> Result<Vec<eth::Block>> {
+    let data = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    decode_blocks(&data).with_context(|| format!("reading blocks from {}", path.display()))
+}
+
+fn decode_blocks(data: &[u8]) -> Result<Vec<eth::Block>> {
+    if data.starts_with(ZSTD_MAGIC) {
+        let data = zstd::decode_all(data).context("decompressing zstd")?;
+        return decode_blocks(&data);
+    }
+
+    if !data.starts_with(DBIN_MAGIC) {
+        let block = eth::Block::decode(data).context("decoding block")?;
+        return Ok(vec![block]);
+    }
+
+    dbin_messages(data)
+        .context("reading merged-blocks bundle")?
+        .into_iter()
+        .map(|message| {
+            let envelope = BstreamBlock::decode(message)?;
+            let payload = match envelope.payload {
+                Some(any) => any.value,
+                None => envelope.payload_buffer,
+            };
+            Ok(eth::Block::decode(payload.as_slice())?)
+        })
+        .collect()
+}
+
+/// Splits a `dbin` file into its length-prefixed messages.
+fn dbin_messages(data: &[u8]) -> Result<Vec<&[u8]>> {
+    let mut pos = match data.get(DBIN_MAGIC.len()) {
+        // v0: 3-byte content type and 2-byte content version
+        Some(0) => DBIN_MAGIC.len() + 1 + 3 + 2,
+        // v1: 2-byte length followed by the content type
+        Some(1) => {
+            let len = read_be(data, DBIN_MAGIC.len() + 1, 2)?;
+            DBIN_MAGIC.len() + 1 + 2 + len
+        }
+        Some(version) => bail!("unsupported dbin version {}", version),
+        None => bail!("truncated dbin header"),
+    };
+
+    let mut messages = Vec::new();
+    while pos < data.len() {
+        let len = read_be(data, pos, 4)?;
+        let message = data
+            .get(pos + 4..pos + 4 + len)
+            .context("truncated dbin message")?;
+        messages.push(message);
+        pos += 4 + len;
+    }
+
+    Ok(messages)
+}
+
+fn read_be(data: &[u8], pos: usize, width: usize) -> Result<usize> {
+    let bytes = data.get(pos..pos + width).context("truncated dbin file")?;
+    Ok(bytes.iter().fold(0usize, |acc, b| (acc << 8) | *b as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(number: u64) -> eth::Block {
+        eth::Block {
+            number,
+            hash: vec![number as u8; 32],
+            ..Default::default()
+        }
+    }
+
+    /// A `dbin` bundle of `blocks` with a `version` header, the envelopes carrying them in
+    /// `payload_buffer` or, with `any`, in `payload`.
+    fn bundle(version: u8, blocks: &[eth::Block], any: bool) -> Vec<u8> {
+        let mut data = DBIN_MAGIC.to_vec();
+        data.push(version);
+        match version {
+            0 => data.extend(b"ETH\x00\x01"),
+            _ => {
+                let content_type = b"type.googleapis.com/sf.bstream.v1.Block";
+                data.extend((content_type.len() as u16).to_be_bytes());
+                data.extend(content_type);
+            }
+        }
+        for blk in blocks {
+            let envelope = if any {
+                BstreamBlock {
+                    payload: Some(prost_types::Any {
+                        type_url: "type.googleapis.com/sf.ethereum.type.v2.Block".to_string(),
+                        value: blk.encode_to_vec(),
+                    }),
+                    ..Default::default()
+                }
+            } else {
+                BstreamBlock { payload_buffer: blk.encode_to_vec(), ..Default::default() }
+            };
+            let message = envelope.encode_to_vec();
+            data.extend((message.len() as u32).to_be_bytes());
+            data.extend(message);
+        }
+        data
+    }
+
+    #[test]
+    fn bundles_round_trip() {
+        let blocks = vec![block(100), block(101), block(102)];
+        for (version, any) in [(0, false), (1, false), (1, true)] {
+            let data = bundle(version, &blocks, any);
+            assert_eq!(decode_blocks(&data).unwrap(), blocks, "v{} any={}", version, any);
+        }
+        assert!(decode_blocks(&bundle(1, &[], false)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn zstd_files_are_decompressed() {
+        let blocks = vec![block(100), block(101)];
+        let compressed = zstd::encode_all(bundle(1, &blocks, true).as_slice(), 0).unwrap();
+        assert_eq!(decode_blocks(&compressed).unwrap(), blocks);
+
+        let compressed = zstd::encode_all(block(7).encode_to_vec().as_slice(), 0).unwrap();
+        assert_eq!(decode_blocks(&compressed).unwrap(), [block(7)]);
+    }
+
+    #[test]
+    fn single_blocks_are_read_as_is() {
+        assert_eq!(decode_blocks(&block(7).encode_to_vec()).unwrap(), [block(7)]);
+    }
+
+    #[test]
+    fn malformed_bundles_are_rejected() {
+        let data = bundle(1, &[block(100)], false);
+        assert!(decode_blocks(&data[..data.len() - 1]).is_err());
+        assert!(decode_blocks(b"dbin\x02").is_err());
+        assert!(decode_blocks(b"dbin").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+//! Replays captured blocks through the nooroo mapping functions and writes their outputs
+//! as JSONL, in the same `@module` / `@block` / `@type` / `@data` shape as
+//! `substreams run -o jsonl`.
+//!
+//! ```bash
+//! cargo run -p nooroo-replay -- <blocks-dir> [--module map_events]... [--params MODULE=PARAMS]...
+//!     [--output out.jsonl]
+//! ```
+//!
+//! Only map modules that depend on the block alone can be replayed; store-backed modules
+//! need the substreams runtime.
+
+mod blocks;
+
+use anyhow::{anyhow, bail, Context, Result};
+use nooroo_core::pb::contract::v1 as contract;
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const DESCRIPTORS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/descriptors.bin"));
+
+const MODULES: &[&str] = &[
+    "map_events",
+    "map_calls",
+    "map_events_calls",
+    "map_events_v2",
+    "map_calls_v2",
+    "map_events_calls_v2",
+    "map_exchange_records",
+    "map_reconciliation",
+    "map_operator_batches",
+    "map_position_changes",
+    "map_vault_events",
+    "map_vault_calls",
+    "map_collateral_flows",
+    "map_executions",
+];
+
+struct Args {
+    blocks_dir: PathBuf,
+    modules: Vec<String>,
+    /// Params of the modules taking some, as `substreams run -p` gives them.
+    params: HashMap<String, String>,
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut blocks_dir = None;
+    let mut modules = Vec::new();
+    let mut params = HashMap::new();
+    let mut output = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--module" | "-m" => {
+                let module = args.next().context("--module needs a value")?;
+                if !MODULES.contains(&module.as_str()) {
+                    bail!("unknown module {:?}, expected one of {}", module, MODULES.join(", "));
+                }
+                modules.push(module);
+            }
+            "--params" | "-p" => {
+                let value = args.next().context("--params needs a value")?;
+                let (module, module_params) = value
+                    .split_once('=')
+                    .with_context(|| format!("invalid params {:?}, expected MODULE=PARAMS", value))?;
+                if !MODULES.contains(&module) {
+                    bail!("unknown module {:?}, expected one of {}", module, MODULES.join(", "));
+                }
+                params.insert(module.to_string(), module_params.to_string());
+            }
+            "--output" | "-o" => output = Some(PathBuf::from(args.next().context("--output needs a value")?)),
+            _ if blocks_dir.is_none() => blocks_dir = Some(PathBuf::from(arg)),
+            _ => bail!("unexpected argument {:?}", arg),
+        }
+    }
+
+    if modules.is_empty() {
+        modules.push("map_events_calls".to_string());
+    }
+
+    Ok(Args {
+        blocks_dir: blocks_dir
+            .context("usage: nooroo-replay <blocks-dir> [--module NAME]... [--params NAME=PARAMS]... [--output FILE]")?,
+        modules,
+        params,
+        output,
+    })
+}
+
+/// Runs a module on a block with its `params`, returning the full name of its output type and
+/// the encoded output.
+fn run_module(module: &str, blk: &eth::Block, params: &str) -> Result<(&'static str, Vec<u8>)> {
+    let events = || {
+        let mut events = contract::Events::default();
+        nooroo_core::mappers::map_events(blk, &mut events);
+        events
+    };
+    let calls = || {
+        let mut calls = contract::Calls::default();
+        nooroo_core::mappers::map_calls(blk, &mut calls);
+        calls
+    };
+    let events_calls = || contract::EventsCalls {
+        events: Some(events()),
+        calls: Some(calls()),
+    };
+
+    Ok(match module {
+        "map_events" => ("contract.v1.Events", events().encode_to_vec()),
+        "map_calls" => ("contract.v1.Calls", calls().encode_to_vec()),
+        "map_events_calls" => ("contract.v1.EventsCalls", events_calls().encode_to_vec()),
+        "map_events_v2" => ("contract.v2.Events", nooroo_core::v2::events(events()).encode_to_vec()),
+        "map_calls_v2" => ("contract.v2.Calls", nooroo_core::v2::calls(calls()).encode_to_vec()),
+        "map_events_calls_v2" => ("contract.v2.EventsCalls", nooroo_core::v2::events_calls(events_calls()).encode_to_vec()),
+        "map_exchange_records" => (
+            "contract.v1.ExchangeRecords",
+            nooroo::records::exchange_records(events(), calls()).encode_to_vec(),
+        ),
+        "map_reconciliation" => (
+            "contract.v1.Reconciliation",
+            nooroo::reconciliation::reconcile(&events(), &calls()).encode_to_vec(),
+        ),
+        "map_operator_batches" => (
+            "contract.v1.OperatorBatches",
+            nooroo::operator::operator_batches(blk, &events(), &nooroo_core::EXCHANGE).encode_to_vec(),
+        ),
+        "map_position_changes" => (
+            "contract.v1.PositionChanges",
+            nooroo::positions::position_changes(&events()).encode_to_vec(),
+        ),
+        "map_vault_events" => ("contract.v1.Events", nooroo::vault::vault_events(blk, params)?.encode_to_vec()),
+        "map_vault_calls" => ("contract.v1.Calls", nooroo::vault::vault_calls(blk, params)?.encode_to_vec()),
+        "map_collateral_flows" => (
+            "contract.v1.CollateralFlows",
+            nooroo::collateral::block_flows(blk).encode_to_vec(),
+        ),
+        "map_executions" => (
+            "contract.v1.Executions",
+            nooroo::execution::executions(&events(), &calls()).encode_to_vec(),
+        ),
+        _ => unreachable!("modules are validated when parsing arguments"),
+    })
+}
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    let pool = DescriptorPool::decode(DESCRIPTORS)?;
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    for path in blocks::block_files(&args.blocks_dir)? {
+        for blk in blocks::read_blocks(&path)? {
+            for module in &args.modules {
+                let params = args.params.get(module).map(String::as_str).unwrap_or_default();
+                let (type_name, output) = run_module(module, &blk, params)?;
+                let descriptor = pool
+                    .get_message_by_name(type_name)
+                    .ok_or_else(|| anyhow!("missing descriptor for {}", type_name))?;
+                let data = DynamicMessage::decode(descriptor, output.as_slice())?;
+
+                let line = serde_json::json!({
+                    "@module": module,
+                    "@block": blk.number,
+                    "@type": type_name,
+                    "@data": data,
+                });
+                writeln!(out, "{}", line)?;
+            }
+        }
+    }
+
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Checks that the modules build, without warnings, with every lean set of features a deployment
+# may pick: each `events` / `calls` and `admin` / `trading` pair, each feature left out of the
+# full set, and none at all. The checks share a target directory of their own, so they don't
+# invalidate the default build's.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+feature_sets=(
+    "events,trading"
+    "events,admin"
+    "calls,trading"
+    "calls,admin"
+    "calls,admin,trading"
+    "events,admin,trading"
+    "events,calls,trading"
+    "events,calls,admin"
+    ""
+)
+
+for features in "${feature_sets[@]}"; do
+    echo "checking features \"$features\""
+    CARGO_TARGET_DIR=target/features RUSTFLAGS="-D warnings" \
+        cargo check --quiet --package nooroo --lib --no-default-features --features "$features"
+done
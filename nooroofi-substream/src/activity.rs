@@ -0,0 +1,121 @@
+use crate::positions::ZERO_ADDRESS;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, Side};
+use substreams::Hex;
+
+use contract::user_activity::Kind;
+
+/// Key of the signer of a proxy wallet in `store_wallet_owners`.
+pub fn owner_key(proxy: &[u8]) -> String {
+    format!("owner:{}", Hex(proxy))
+}
+
+/// `(ordinal, proxy, signer)` of every order filled or matched by a successful exchange call
+/// whose maker isn't its signer: the maker is then a proxy or safe wallet the signer owns.
+pub fn proxy_owners(calls: &contract::Calls) -> Vec<(u64, Vec<u8>, Vec<u8>)> {
+    let mut orders: Vec<(u64, &contract::PolymarketctfOrder)> = Vec::new();
+    for call in calls.polymarketctf_call_fill_orders.iter().filter(|c| c.call_success) {
+        orders.extend(call.order.iter().map(|o| (call.call_ordinal, o)));
+    }
+    for call in calls.polymarketctf_call_fill_orders_batch.iter().filter(|c| c.call_success) {
+        orders.extend(call.orders.iter().map(|o| (call.call_ordinal, o)));
+    }
+    for call in calls.polymarketctf_call_match_orders.iter().filter(|c| c.call_success) {
+        orders.extend(call.taker_order.iter().chain(&call.maker_orders).map(|o| (call.call_ordinal, o)));
+    }
+
+    orders.sort_by_key(|(ordinal, _)| *ordinal);
+    orders
+        .into_iter()
+        .filter(|(_, order)| order.maker != order.signer && !order.signer.is_empty())
+        .map(|(ordinal, order)| (ordinal, order.maker.clone(), order.signer.clone()))
+        .collect()
+}
+
+/// Timeline of the vault depositors in the block, by ordinal. Deposits and mints are always
+/// reported; a fill only when `deposited` says its wallet had deposited by then, the wallet
+/// of a maker or taker being the signer `owner_of` gives for it, or the address itself.
+pub fn user_activities<O, D>(
+    vault_events: &contract::Events,
+    events: &contract::Events,
+    exchange: &[u8],
+    owner_of: O,
+    deposited: D,
+) -> contract::UserActivities
+where
+    O: Fn(&[u8]) -> Option<Vec<u8>>,
+    D: Fn(&[u8], u64) -> bool,
+{
+    let mut activities = Vec::new();
+
+    for deposit in &vault_events.vault_deposits {
+        activities.push(contract::UserActivity {
+            wallet: deposit.user.clone(),
+            actor: deposit.user.clone(),
+            kind: Kind::Deposit as i32,
+            tx_hash: deposit.evt_tx_hash.clone(),
+            block_time: deposit.evt_block_time,
+            block_number: deposit.evt_block_number,
+            ordinal: deposit.evt_ordinal,
+            amount: deposit.usdc_amount.clone(),
+            fee: deposit.fee_amount.clone(),
+            ..Default::default()
+        });
+    }
+    for mint in vault_events.steth_transfers.iter().filter(|t| t.from == ZERO_ADDRESS) {
+        activities.push(contract::UserActivity {
+            wallet: mint.to.clone(),
+            actor: mint.to.clone(),
+            kind: Kind::Mint as i32,
+            tx_hash: mint.evt_tx_hash.clone(),
+            block_time: mint.evt_block_time,
+            block_number: mint.evt_block_number,
+            ordinal: mint.evt_ordinal,
+            amount: mint.value.clone(),
+            ..Default::default()
+        });
+    }
+
+    let taker_fills = price::taker_order_fills(&events.polymarketctf_order_filleds, exchange);
+    for event in &events.polymarketctf_order_filleds {
+        let Some(fill) = price::fill(event) else {
+            continue;
+        };
+        let taker_side = match fill.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        // The exchange takes the taker order of `matchOrders` itself, and the taker of its maker
+        // orders is reported from that fill, with its own side and token.
+        let matched = taker_fills.contains_key(&(event.evt_tx_hash.as_str(), event.taker.as_slice()));
+        let sides = [(&event.maker, fill.side, event.fee.as_str()), (&event.taker, taker_side, "0")];
+        for (actor, side, fee) in sides.into_iter().take(if matched { 1 } else { 2 }) {
+            if actor.as_slice() == exchange {
+                continue;
+            }
+            let wallet = owner_of(actor).unwrap_or_else(|| actor.clone());
+            if !deposited(&wallet, event.evt_ordinal) {
+                continue;
+            }
+            activities.push(contract::UserActivity {
+                wallet,
+                actor: actor.clone(),
+                kind: Kind::Fill as i32,
+                tx_hash: event.evt_tx_hash.clone(),
+                block_time: event.evt_block_time,
+                block_number: event.evt_block_number,
+                ordinal: event.evt_ordinal,
+                amount: fill.notional.to_string(),
+                fee: fee.to_string(),
+                token_id: fill.token_id.clone(),
+                side: side as u32,
+                size: fill.size.to_string(),
+            });
+        }
+    }
+
+    // Stable, so the maker of a fill stays ahead of its taker.
+    activities.sort_by_key(|a| a.ordinal);
+
+    contract::UserActivities { activities }
+}
@@ -0,0 +1,103 @@
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, Side};
+use prost_types::Timestamp;
+use substreams::Hex;
+
+/// An owner's `ApprovalForAll` of an operator, as kept in `store_operator_approvals`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Approval {
+    pub approved: bool,
+    /// Block of the `ApprovalForAll` setting it.
+    pub block_number: u64,
+    /// Unix time of that block.
+    pub time: i64,
+}
+
+impl Approval {
+    pub fn of(event: &contract::CtfApprovalForAll) -> Self {
+        Approval {
+            approved: event.approved,
+            block_number: event.evt_block_number,
+            time: event.evt_block_time.as_ref().map(|t| t.seconds).unwrap_or_default(),
+        }
+    }
+
+    /// The `<0|1>:<block number>:<unix time>` form kept in the store.
+    pub fn to_value(&self) -> String {
+        format!("{}:{}:{}", self.approved as u8, self.block_number, self.time)
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.splitn(3, ':');
+        Some(Approval {
+            approved: parts.next()? == "1",
+            block_number: parts.next()?.parse().ok()?,
+            time: parts.next()?.parse().ok()?,
+        })
+    }
+}
+
+/// Key of an owner's approval of an operator in `store_operator_approvals`.
+pub fn approval_key(owner: &[u8], operator: &[u8]) -> String {
+    format!("approval:{}:{}", Hex(owner), Hex(operator))
+}
+
+/// Every `ApprovalForAll` of the block with the approval it replaced, and every fill selling
+/// outcome tokens with the seller's approval of the exchange. `approval_at` gives the approval
+/// of an operator by an owner as of an ordinal, the changes at that ordinal included.
+pub fn operator_approvals<A>(events: &contract::Events, exchange: &[u8], approval_at: A) -> contract::OperatorApprovals
+where
+    A: Fn(&[u8], &[u8], u64) -> Option<Approval>,
+{
+    let mut approvals = contract::OperatorApprovals::default();
+
+    for event in &events.ctf_approval_for_alls {
+        let previous = approval_at(&event.owner, &event.operator, event.evt_ordinal.saturating_sub(1));
+        approvals.changes.push(contract::OperatorApprovalChange {
+            tx_hash: event.evt_tx_hash.clone(),
+            block_time: event.evt_block_time,
+            block_number: event.evt_block_number,
+            ordinal: event.evt_ordinal,
+            owner: event.owner.clone(),
+            operator: event.operator.clone(),
+            approved: event.approved,
+            previously_approved: previous.is_some_and(|p| p.approved),
+        });
+    }
+
+    let taker_fills = price::taker_order_fills(&events.polymarketctf_order_filleds, exchange);
+    for event in &events.polymarketctf_order_filleds {
+        let Some(fill) = price::fill(event) else {
+            continue;
+        };
+        // The taker of a `matchOrders` sells through the fill of its own order, against the
+        // exchange, and may be buying in a MINT match; only an operator filling orders itself
+        // takes their other side.
+        let matched = event.taker == exchange
+            || taker_fills.contains_key(&(event.evt_tx_hash.as_str(), event.taker.as_slice()));
+        let seller = match fill.side {
+            Side::Sell => &event.maker,
+            Side::Buy if !matched => &event.taker,
+            Side::Buy => continue,
+        };
+
+        let approval = approval_at(seller, exchange, event.evt_ordinal).filter(|a| a.approved);
+        approvals.sell_fills.push(contract::SellFillApproval {
+            tx_hash: event.evt_tx_hash.clone(),
+            block_time: event.evt_block_time,
+            block_number: event.evt_block_number,
+            ordinal: event.evt_ordinal,
+            order_hash: event.order_hash.clone(),
+            seller: seller.clone(),
+            token_id: fill.token_id,
+            size: fill.size.to_string(),
+            approved: approval.is_some(),
+            approved_block_number: approval.as_ref().map(|a| a.block_number).unwrap_or_default(),
+            approved_time: approval.map(|a| Timestamp { seconds: a.time, nanos: 0 }),
+        });
+    }
+
+    approvals.changes.sort_by_key(|c| c.ordinal);
+    approvals.sell_fills.sort_by_key(|f| f.ordinal);
+    approvals
+}
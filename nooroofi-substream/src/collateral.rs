@@ -0,0 +1,194 @@
+use crate::positions::ZERO_ADDRESS;
+use nooroo_core::ctf::EXCHANGE_COLLATERAL;
+use nooroo_core::mappers::usdc;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::parse_amount;
+use nooroo_core::{CONDITIONAL_TOKENS, EXCHANGE};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+// Failed fills are found by decoding the exchange's fill calls and the transfers below them.
+#[cfg(all(feature = "calls", feature = "trading"))]
+use {
+    contract::failed_fill::Reason,
+    nooroo_core::mappers::ctf,
+    nooroo_core::ExchangeCall,
+    std::collections::HashMap,
+};
+
+/// Selector of `Error(string)`, the revert data of `require` and `revert` with a message.
+#[cfg(all(feature = "calls", feature = "trading"))]
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Key of a trader's total `inflow` or `outflow` in `store_collateral_flows`.
+pub fn flow_key(trader: &[u8], direction: &str) -> String {
+    format!("trader:{}:{}", Hex(trader), direction)
+}
+
+/// Key of the allowance an owner gave a spender in `store_collateral_allowances`.
+pub fn allowance_key(owner: &[u8], spender: &[u8]) -> String {
+    format!("allowance:{}:{}", Hex(owner), Hex(spender))
+}
+
+/// What `map_collateral_flows` outputs for a block: the flows and approvals of the exchange
+/// collateral around the exchange and the conditional tokens, and the failed fills with the
+/// `calls` and `trading` features.
+pub fn block_flows(blk: &eth::Block) -> contract::CollateralFlows {
+    let mut usdc_events = contract::Events::default();
+    usdc::map_events(blk, &mut usdc_events, &EXCHANGE_COLLATERAL);
+
+    let contracts: [&[u8]; 2] = [&EXCHANGE, &CONDITIONAL_TOKENS];
+    let flows = collateral_flows(&usdc_events, &contracts);
+    #[cfg(all(feature = "calls", feature = "trading"))]
+    let flows = contract::CollateralFlows {
+        failed_fills: failed_fills(blk, &EXCHANGE_COLLATERAL),
+        ..flows
+    };
+    flows
+}
+
+/// The USDC transfers between a trader and one of `contracts`, and the approvals given to them.
+/// Transfers between two of the contracts, such as the exchange splitting collateral on the
+/// conditional tokens, move no trader's funds and are left out.
+pub fn collateral_flows(usdc_events: &contract::Events, contracts: &[&[u8]]) -> contract::CollateralFlows {
+    let tracked = |address: &[u8]| contracts.contains(&address);
+    let mut flows = contract::CollateralFlows::default();
+
+    for transfer in &usdc_events.usdc_transfers {
+        let (trader, contract, amount) = match (tracked(&transfer.from), tracked(&transfer.to)) {
+            (true, false) => (&transfer.to, &transfer.from, parse_amount(&transfer.value)),
+            (false, true) => (&transfer.from, &transfer.to, parse_amount(&transfer.value).neg()),
+            _ => continue,
+        };
+        if *trader == ZERO_ADDRESS || amount.is_zero() {
+            continue;
+        }
+        flows.flows.push(contract::CollateralFlow {
+            tx_hash: transfer.evt_tx_hash.clone(),
+            block_time: transfer.evt_block_time,
+            block_number: transfer.evt_block_number,
+            ordinal: transfer.evt_ordinal,
+            trader: trader.clone(),
+            contract: contract.clone(),
+            amount: amount.to_string(),
+        });
+    }
+    for approval in usdc_events.usdc_approvals.iter().filter(|a| tracked(&a.spender)) {
+        flows.allowances.push(contract::CollateralAllowance {
+            tx_hash: approval.evt_tx_hash.clone(),
+            block_time: approval.evt_block_time,
+            block_number: approval.evt_block_number,
+            ordinal: approval.evt_ordinal,
+            owner: approval.owner.clone(),
+            spender: approval.spender.clone(),
+            allowance: approval.value.clone(),
+        });
+    }
+
+    flows.flows.sort_by_key(|f| f.ordinal);
+    flows.allowances.sort_by_key(|a| a.ordinal);
+    flows
+}
+
+/// Fill calls to the exchange reverted by a USDC or outcome token transfer they made, failed
+/// transactions included. The transfer is the deepest failed call to `collateral` or the
+/// conditional tokens below the fill, and its revert message tells allowance from balance;
+/// fills reverted for any other reason aren't reported.
+#[cfg(all(feature = "calls", feature = "trading"))]
+pub fn failed_fills(blk: &eth::Block, collateral: &[u8]) -> Vec<contract::FailedFill> {
+    let mut failed = Vec::new();
+
+    for tx in &blk.transaction_traces {
+        let by_index: HashMap<u32, &eth::Call> = tx.calls.iter().map(|c| (c.index, c)).collect();
+
+        for call in tx.calls.iter().filter(|c| c.state_reverted) {
+            let name = match nooroo_core::decode_call(call) {
+                Some(ExchangeCall::FillOrder(_)) => "fillOrder",
+                Some(ExchangeCall::FillOrders(_)) => "fillOrders",
+                Some(ExchangeCall::MatchOrders(_)) => "matchOrders",
+                _ => continue,
+            };
+
+            let transfer = tx
+                .calls
+                .iter()
+                .filter(|c| c.status_failed && (c.address == collateral || c.address == ctf::TRACKED_CONTRACT))
+                .filter(|c| descends_from(c, call.index, &by_index))
+                .max_by_key(|c| c.depth);
+            let Some(transfer) = transfer else {
+                continue;
+            };
+            let revert_reason = revert_reason(transfer);
+            let Some(reason) = classify(&revert_reason) else {
+                continue;
+            };
+
+            let mut fill = contract::FailedFill {
+                tx_hash: Hex(&tx.hash).to_string(),
+                block_time: Some(blk.timestamp().to_owned()),
+                block_number: blk.number,
+                ordinal: call.begin_ordinal,
+                call: name.to_string(),
+                reason: reason as i32,
+                token: transfer.address.clone(),
+                revert_reason,
+                ..Default::default()
+            };
+            if let Some(usdc::Call::TransferFrom(decoded)) = usdc::decode_call(transfer, collateral) {
+                fill.owner = decoded.sender;
+                fill.amount = decoded.amount;
+            } else if let Some(ctf::Call::SafeTransferFrom(decoded)) = ctf::decode_call(transfer) {
+                fill.owner = decoded.from;
+                fill.token_id = decoded.id;
+                fill.amount = decoded.value;
+            }
+            failed.push(fill);
+        }
+    }
+
+    failed
+}
+
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn descends_from(call: &eth::Call, ancestor: u32, by_index: &HashMap<u32, &eth::Call>) -> bool {
+    let mut current = call;
+    while current.depth > 0 {
+        if current.parent_index == ancestor {
+            return true;
+        }
+        match by_index.get(&current.parent_index) {
+            Some(parent) => current = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+/// The `Error(string)` message of a failed call, or the tracer's failure reason without one.
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn revert_reason(call: &eth::Call) -> String {
+    let data = &call.return_data;
+    if data.len() >= 68 && data[..4] == ERROR_SELECTOR {
+        let length = u64::from_be_bytes(data[60..68].try_into().unwrap()) as usize;
+        if let Some(message) = data.get(68..68usize.saturating_add(length)) {
+            return String::from_utf8_lossy(message).into_owned();
+        }
+    }
+    call.failure_reason.clone()
+}
+
+/// Messages of the USDC.e ERC20 (`transfer amount exceeds allowance / balance`) and of the
+/// conditional tokens' ERC1155 (`need operator approval`, `SafeMath: subtraction overflow`).
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn classify(revert_reason: &str) -> Option<Reason> {
+    let reason = revert_reason.to_lowercase();
+    if reason.contains("approval") {
+        Some(Reason::MissingOperatorApproval)
+    } else if reason.contains("allowance") {
+        Some(Reason::InsufficientAllowance)
+    } else if reason.contains("balance") || reason.contains("subtraction overflow") {
+        Some(Reason::InsufficientBalance)
+    } else {
+        None
+    }
+}
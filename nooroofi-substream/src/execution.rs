@@ -0,0 +1,107 @@
+use nooroo_core::order::exchange_order_hash;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, parse_amount, Side};
+use std::collections::HashMap;
+use substreams::scalar::{BigDecimal, BigInt};
+
+use contract::fill_execution::Role;
+
+/// An order passed to a successful trading call.
+struct CalledOrder<'a> {
+    call: &'static str,
+    role: Role,
+    order: &'a contract::PolymarketctfOrder,
+}
+
+/// The execution of every fill of an order passed to a successful `fillOrder`, `fillOrders` or
+/// `matchOrders` call, found by the order hash of its `OrderFilled` within the transaction.
+/// Fills of orders the calls don't carry, such as those of failed calls, are left out.
+pub fn executions(events: &contract::Events, calls: &contract::Calls) -> contract::Executions {
+    let mut orders: HashMap<(&str, [u8; 32]), CalledOrder> = HashMap::new();
+    for call in calls.polymarketctf_call_fill_orders.iter().filter(|c| c.call_success) {
+        let Some(order) = &call.order else {
+            continue;
+        };
+        if let Some(hash) = exchange_order_hash(order) {
+            let called = CalledOrder { call: "fillOrder", role: Role::Maker, order };
+            orders.insert((call.call_tx_hash.as_str(), hash), called);
+        }
+    }
+    for call in calls.polymarketctf_call_fill_orders_batch.iter().filter(|c| c.call_success) {
+        for order in &call.orders {
+            let Some(hash) = exchange_order_hash(order) else {
+                continue;
+            };
+            let called = CalledOrder { call: "fillOrders", role: Role::Maker, order };
+            orders.insert((call.call_tx_hash.as_str(), hash), called);
+        }
+    }
+    for call in calls.polymarketctf_call_match_orders.iter().filter(|c| c.call_success) {
+        let taker = call.taker_order.iter().map(|order| (Role::Taker, order));
+        for (role, order) in taker.chain(call.maker_orders.iter().map(|order| (Role::Maker, order))) {
+            let Some(hash) = exchange_order_hash(order) else {
+                continue;
+            };
+            let called = CalledOrder { call: "matchOrders", role, order };
+            orders.insert((call.call_tx_hash.as_str(), hash), called);
+        }
+    }
+
+    let mut fills = Vec::new();
+    for event in &events.polymarketctf_order_filleds {
+        let Ok(order_hash) = <[u8; 32]>::try_from(event.order_hash.as_slice()) else {
+            continue;
+        };
+        let Some(called) = orders.get(&(event.evt_tx_hash.as_str(), order_hash)) else {
+            continue;
+        };
+        let (Some(fill), Some(limit_price)) = (price::fill(event), price::limit_price(called.order)) else {
+            continue;
+        };
+
+        fills.push(contract::FillExecution {
+            tx_hash: event.evt_tx_hash.clone(),
+            block_time: event.evt_block_time,
+            block_number: event.evt_block_number,
+            ordinal: event.evt_ordinal,
+            call: called.call.to_string(),
+            role: called.role as i32,
+            order_hash: event.order_hash.clone(),
+            maker: event.maker.clone(),
+            token_id: fill.token_id.clone(),
+            side: fill.side as u32,
+            size: fill.size.to_string(),
+            notional: fill.notional.to_string(),
+            fee: event.fee.clone(),
+            limit_price: limit_price.to_string(),
+            execution_price: fill.price.to_string(),
+            improvement_bps: improvement_bps(called.order, &fill).map(|bps| bps.to_string()).unwrap_or_default(),
+        });
+    }
+
+    fills.sort_by_key(|f| f.ordinal);
+    contract::Executions { fills }
+}
+
+/// How much better than the limit price of `order` its maker got in `fill`, in basis points
+/// rounded toward zero to 2 decimals: a lower price when buying, a higher one when selling.
+pub fn improvement_bps(order: &contract::PolymarketctfOrder, fill: &price::Fill) -> Option<BigDecimal> {
+    // Both prices as collateral over tokens.
+    let (limit_notional, limit_size) = match fill.side {
+        Side::Buy => (parse_amount(&order.maker_amount), parse_amount(&order.taker_amount)),
+        Side::Sell => (parse_amount(&order.taker_amount), parse_amount(&order.maker_amount)),
+    };
+    if limit_notional.is_zero() || limit_size.is_zero() || fill.size.is_zero() {
+        return None;
+    }
+
+    // execution / limit - 1, over the common denominator.
+    let limit = limit_notional * fill.size.clone();
+    let execution = fill.notional.clone() * limit_size;
+    let difference = match fill.side {
+        Side::Buy => limit.clone() - execution,
+        Side::Sell => execution - limit.clone(),
+    };
+    let hundredths = difference * BigInt::from(1_000_000) / limit;
+    Some(BigDecimal::new(hundredths, -2))
+}
@@ -0,0 +1,376 @@
+use anyhow::{anyhow, Context};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, parse_amount, Side};
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::ops::RangeInclusive;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+
+/// Metrics ranked by `map_leaderboard`, as named in the stores and boards.
+pub const METRICS: [&str; 6] = ["volume", "trades", "maker_volume", "taker_volume", "fees", "realized_pnl"];
+/// Largest board `map_leaderboard` can rank: `store_leaderboard_candidates` stops listing the
+/// wallets moving into a bucket once this many are above it.
+pub const MAX_TOP: usize = 100;
+/// Totals of `2^MAX_BUCKET` and more all land in the top bucket.
+pub const MAX_BUCKET: u32 = 128;
+/// Size of the boards when the params leave it out.
+pub const DEFAULT_TOP: usize = 10;
+
+/// A window totals are kept and ranked over.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    Day,
+    Week,
+    All,
+}
+
+impl Window {
+    pub const ALL: [Window; 3] = [Window::Day, Window::Week, Window::All];
+
+    /// The window's key in the stores and boards for a block of `stats`: `day:<n>`, `week:<n>`
+    /// or `all`.
+    pub fn key(&self, stats: &contract::TraderStats) -> String {
+        match self {
+            Window::Day => format!("day:{}", stats.day),
+            Window::Week => format!("week:{}", stats.week),
+            Window::All => "all".to_string(),
+        }
+    }
+}
+
+/// Days or weeks dropped from the stores when `store_leaderboard_days` moves from `previous` to
+/// `current`: the windows before the last one, those kept at `previous` included.
+pub fn expired_windows(previous: i64, current: i64) -> RangeInclusive<i64> {
+    // No windows are kept before the first one.
+    let last = if previous == 0 { previous - 2 } else { current - 2 };
+    previous - 1..=last
+}
+
+/// Params of `map_leaderboard`.
+#[derive(Debug, PartialEq)]
+pub struct Params {
+    pub top: usize,
+    pub windows: Vec<Window>,
+}
+
+impl Params {
+    /// Parses `top=<n>&window=<day|week|all>,...`. Both are optional: ten entries per board,
+    /// over every window.
+    pub fn parse(params: &str) -> Result<Self, substreams::errors::Error> {
+        let mut parsed = Params { top: DEFAULT_TOP, windows: Window::ALL.to_vec() };
+
+        for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+            match key.trim() {
+                "top" => {
+                    let top: usize = value.trim().parse().with_context(|| format!("invalid top {:?}", value))?;
+                    if top == 0 || top > MAX_TOP {
+                        return Err(anyhow!("invalid top {}, expected 1 to {}", top, MAX_TOP));
+                    }
+                    parsed.top = top;
+                }
+                "window" => {
+                    parsed.windows = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|w| !w.is_empty())
+                        .map(|w| match w {
+                            "day" => Ok(Window::Day),
+                            "week" => Ok(Window::Week),
+                            "all" => Ok(Window::All),
+                            other => Err(anyhow!("unknown window {:?}, expected day, week or all", other)),
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                other => return Err(anyhow!("unknown param {:?}", other)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// A wallet's side of a fill.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trade {
+    pub ordinal: u64,
+    pub wallet: Vec<u8>,
+    pub maker: bool,
+    pub token_id: String,
+    pub side: Side,
+    pub size: BigInt,
+    pub notional: BigInt,
+}
+
+/// Both sides of every fill of the block. The maker trades the side of its order; the taker of a
+/// `matchOrders` trades through the fill of its own order against the exchange, with its side,
+/// token and notional, as it only takes the other side of the maker orders in a COMPLEMENTARY
+/// match. Only an operator filling orders itself is given the other side of the maker's fill.
+pub fn trades(events: &contract::Events, exchange: &[u8]) -> Vec<Trade> {
+    let taker_fills = price::taker_order_fills(&events.polymarketctf_order_filleds, exchange);
+    let mut trades = Vec::new();
+    for event in &events.polymarketctf_order_filleds {
+        let Some(fill) = price::fill(event) else {
+            continue;
+        };
+        let trade = |wallet: &[u8], maker: bool, side: Side| Trade {
+            ordinal: event.evt_ordinal,
+            wallet: wallet.to_vec(),
+            maker,
+            token_id: fill.token_id.clone(),
+            side,
+            size: fill.size.clone(),
+            notional: fill.notional.clone(),
+        };
+        if event.taker == exchange {
+            trades.push(trade(&event.maker, false, fill.side));
+            continue;
+        }
+        trades.push(trade(&event.maker, true, fill.side));
+        if !taker_fills.contains_key(&(event.evt_tx_hash.as_str(), event.taker.as_slice())) {
+            let taker_side = match fill.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+            trades.push(trade(&event.taker, false, taker_side));
+        }
+    }
+    trades.sort_by_key(|t| t.ordinal);
+    trades
+}
+
+/// `(ordinal, wallet, fee)` of every fill charging a fee, taker orders of `matchOrders`
+/// included. The fee is paid by the maker of the filled order in the asset it receives;
+/// outcome tokens are valued at the fill price.
+pub fn fees(events: &contract::Events) -> Vec<(u64, Vec<u8>, BigInt)> {
+    let mut fees = Vec::new();
+    for event in &events.polymarketctf_order_filleds {
+        let fee = parse_amount(&event.fee);
+        let Some(fill) = price::fill(event).filter(|_| !fee.is_zero()) else {
+            continue;
+        };
+        let fee = match fill.side {
+            Side::Sell => fee,
+            Side::Buy => fee * fill.notional / fill.size,
+        };
+        fees.push((event.evt_ordinal, event.maker.clone(), fee));
+    }
+    fees.sort_by_key(|(ordinal, _, _)| *ordinal);
+    fees
+}
+
+/// Key of a wallet's running total of a position field in `store_trader_positions`:
+/// `buy_size`, `buy_cost`, `sell_size` or `sell_proceeds`.
+pub fn position_key(wallet: &[u8], token_id: &str, field: &str) -> String {
+    format!("position:{}:{}:{}", Hex(wallet), token_id, field)
+}
+
+/// What a wallet bought and sold of an outcome token through the exchange.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Position {
+    pub buy_size: BigInt,
+    pub buy_cost: BigInt,
+    pub sell_size: BigInt,
+    pub sell_proceeds: BigInt,
+}
+
+impl Position {
+    /// Profit on the tokens both bought and sold, at the average price of each side. Tokens
+    /// sold beyond those bought came from splits or transfers, at no known cost, and tokens
+    /// still held are unrealized.
+    pub fn realized_pnl(&self) -> BigInt {
+        let matched = if self.buy_size < self.sell_size { &self.buy_size } else { &self.sell_size };
+        if matched.is_zero() {
+            return BigInt::zero();
+        }
+        self.sell_proceeds.clone() * matched.clone() / self.sell_size.clone()
+            - self.buy_cost.clone() * matched.clone() / self.buy_size.clone()
+    }
+}
+
+/// What the fills of the block add to the totals of each wallet trading in it, by wallet.
+/// `position` gives a wallet's position in a token as of an ordinal, `None` for after the
+/// block, so realized PnL is what the block's fills moved it by.
+pub fn trader_stats<P>(events: &contract::Events, exchange: &[u8], position: P) -> contract::TraderStats
+where
+    P: Fn(&[u8], &str, Option<u64>) -> Position,
+{
+    let mut stats = contract::TraderStats::default();
+    let Some(first) = events.polymarketctf_order_filleds.iter().min_by_key(|e| e.evt_ordinal) else {
+        return stats;
+    };
+    let day = first.evt_block_time.as_ref().map(|t| t.seconds / 86400).unwrap_or_default();
+    stats.block_time = first.evt_block_time;
+    stats.block_number = first.evt_block_number;
+    stats.day = day;
+    // Day 0 was a Thursday.
+    stats.week = (day + 3) / 7;
+
+    let mut tallies: BTreeMap<Vec<u8>, Tally> = BTreeMap::new();
+    let mut positions: BTreeMap<(Vec<u8>, String), u64> = BTreeMap::new();
+    for trade in trades(events, exchange) {
+        let tally = tallies.entry(trade.wallet.clone()).or_default();
+        tally.volume = tally.volume.clone() + trade.notional.clone();
+        tally.trades += 1;
+        if trade.maker {
+            tally.maker_volume = tally.maker_volume.clone() + trade.notional;
+        } else {
+            tally.taker_volume = tally.taker_volume.clone() + trade.notional;
+        }
+        positions.entry((trade.wallet, trade.token_id)).or_insert(trade.ordinal);
+    }
+    for (_, wallet, fee) in fees(events) {
+        let tally = tallies.entry(wallet).or_default();
+        tally.fees = tally.fees.clone() + fee;
+    }
+    for ((wallet, token_id), first_ordinal) in positions {
+        let before = position(&wallet, &token_id, Some(first_ordinal.saturating_sub(1))).realized_pnl();
+        let after = position(&wallet, &token_id, None).realized_pnl();
+        let tally = tallies.entry(wallet).or_default();
+        tally.realized_pnl = tally.realized_pnl.clone() + after - before;
+    }
+
+    stats.stats = tallies
+        .into_iter()
+        .map(|(wallet, tally)| contract::TraderStat {
+            wallet,
+            volume: tally.volume.to_string(),
+            trades: tally.trades,
+            maker_volume: tally.maker_volume.to_string(),
+            taker_volume: tally.taker_volume.to_string(),
+            fees: tally.fees.to_string(),
+            realized_pnl: tally.realized_pnl.to_string(),
+        })
+        .collect();
+    stats
+}
+
+#[derive(Default)]
+struct Tally {
+    volume: BigInt,
+    trades: u64,
+    maker_volume: BigInt,
+    taker_volume: BigInt,
+    fees: BigInt,
+    realized_pnl: BigInt,
+}
+
+/// The stat's value for each of `METRICS`.
+pub fn metrics(stat: &contract::TraderStat) -> [(&'static str, BigInt); 6] {
+    [
+        ("volume", parse_amount(&stat.volume)),
+        ("trades", BigInt::from(stat.trades)),
+        ("maker_volume", parse_amount(&stat.maker_volume)),
+        ("taker_volume", parse_amount(&stat.taker_volume)),
+        ("fees", parse_amount(&stat.fees)),
+        ("realized_pnl", parse_amount(&stat.realized_pnl)),
+    ]
+}
+
+/// Key of a wallet's total of a metric over a window in `store_trader_totals`.
+pub fn total_key(window: &str, wallet: &[u8], metric: &str) -> String {
+    format!("{}:{}:{}", window, Hex(wallet), metric)
+}
+
+/// Key of a bucket of a metric's board over a window, in `store_leaderboard_buckets` (the
+/// wallets in it) and `store_leaderboard_slots` (the candidates listed for it).
+pub fn bucket_key(window: &str, metric: &str, bucket: u32) -> String {
+    format!("{}:{}:{}", window, metric, bucket)
+}
+
+/// Key of the `slot`-th candidate listed for a bucket in `store_leaderboard_candidates`, from 1.
+pub fn candidate_key(window: &str, metric: &str, bucket: u32, slot: i64) -> String {
+    format!("{}:{}:{}:{}", window, metric, bucket, slot)
+}
+
+/// Bucket of a total: its bit length, so bucket `b` holds totals from `2^(b-1)` to `2^b - 1`.
+/// Totals of zero or less are in no bucket, 0.
+pub fn bucket(total: &BigInt) -> u32 {
+    if *total <= BigInt::zero() {
+        return 0;
+    }
+    (total.bits() as u32).min(MAX_BUCKET)
+}
+
+/// A wallet's total moving from one bucket to another in the block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BucketMove {
+    pub window: String,
+    pub metric: &'static str,
+    pub wallet: Vec<u8>,
+    pub from: u32,
+    pub to: u32,
+}
+
+/// Bucket moves of the block's stats over every window. `total` gives a total from
+/// `store_trader_totals` after the block, by key.
+pub fn bucket_moves<T>(stats: &contract::TraderStats, total: T) -> Vec<BucketMove>
+where
+    T: Fn(&str) -> BigInt,
+{
+    let mut moves = Vec::new();
+    for window in Window::ALL.iter().map(|w| w.key(stats)) {
+        for stat in &stats.stats {
+            for (metric, delta) in metrics(stat) {
+                if delta.is_zero() {
+                    continue;
+                }
+                let after = total(&total_key(&window, &stat.wallet, metric));
+                let before = after.clone() - delta;
+                let (from, to) = (bucket(&before), bucket(&after));
+                if from != to {
+                    moves.push(BucketMove { window: window.clone(), metric, wallet: stat.wallet.clone(), from, to });
+                }
+            }
+        }
+    }
+    moves
+}
+
+/// Bucket moves of the block listing their wallet as a candidate of the bucket it moves into,
+/// up or down, while fewer than `MAX_TOP` wallets are above it. `above` gives the number of
+/// wallets in the buckets above one from `store_leaderboard_buckets`, by window, metric and
+/// bucket.
+pub fn admissions<T, A>(stats: &contract::TraderStats, total: T, above: A) -> Vec<BucketMove>
+where
+    T: Fn(&str) -> BigInt,
+    A: Fn(&str, &str, u32) -> i64,
+{
+    bucket_moves(stats, total)
+        .into_iter()
+        .filter(|m| m.to > 0 && above(&m.window, m.metric, m.to) < MAX_TOP as i64)
+        .collect()
+}
+
+/// The `top` wallets by total, highest first and ties by address. Buckets are scanned from
+/// the top down, taking the wallets `candidates` lists as having entered each, until `top` of
+/// them are known to be at least in the current bucket; `total` gives a wallet's exact total.
+pub fn top<C, T>(top: usize, candidates: C, total: T) -> Vec<contract::LeaderboardEntry>
+where
+    C: Fn(u32) -> Vec<Vec<u8>>,
+    T: Fn(&[u8]) -> BigInt,
+{
+    let mut seen: BTreeMap<Vec<u8>, BigInt> = BTreeMap::new();
+    for b in (1..=MAX_BUCKET).rev() {
+        for wallet in candidates(b) {
+            if let Entry::Vacant(entry) = seen.entry(wallet) {
+                let value = total(entry.key());
+                entry.insert(value);
+            }
+        }
+        if seen.values().filter(|v| bucket(v) >= b).count() >= top {
+            break;
+        }
+    }
+
+    let mut ranked: Vec<(Vec<u8>, BigInt)> = seen.into_iter().filter(|(_, v)| *v > BigInt::zero()).collect();
+    ranked.sort_by(|(wa, va), (wb, vb)| vb.cmp(va).then_with(|| wa.cmp(wb)));
+    ranked
+        .into_iter()
+        .take(top)
+        .enumerate()
+        .map(|(i, (wallet, value))| contract::LeaderboardEntry { rank: i as u32 + 1, wallet, value: value.to_string() })
+        .collect()
+}
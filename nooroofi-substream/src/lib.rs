@@ -1,741 +1,39 @@
-mod abi;
-mod pb;
-use hex_literal::hex;
-use pb::contract::v1 as contract;
+// The handler macros expose params as raw pointers on the generated wasm exports.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+pub mod activity;
+pub mod approvals;
+pub mod collateral;
+pub mod execution;
+pub mod leaderboard;
+pub mod market_stats;
+pub mod open_interest;
+pub mod operator;
+pub mod positions;
+pub mod probability;
+pub mod reconciliation;
+pub mod records;
+pub mod settlement;
+pub mod token_analytics;
+pub mod vault;
+pub mod wash_trading;
+pub mod watch_list;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::pb::contract::v2 as contract_v2;
+use nooroo_core::price::{self, parse_amount};
+use nooroo_core::{mappers, v2};
 use substreams::Hex;
+use substreams::scalar::{BigDecimal, BigInt};
 use substreams_ethereum::pb::eth::v2 as eth;
-use substreams_ethereum::Event;
-
-#[allow(unused_imports)]
-use num_traits::cast::ToPrimitive;
-use std::str::FromStr;
-use substreams::scalar::BigDecimal;
+use substreams::store::{
+    Appender, DeltaBigInt, DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreAddInt64, StoreAppend, StoreDelete, StoreGet,
+    StoreGetArray, StoreGetBigInt, StoreGetInt64, StoreGetString, StoreMax, StoreMaxInt64, StoreNew, StoreSet, StoreSetBigInt,
+    StoreSetIfNotExists, StoreSetIfNotExistsString, StoreSetInt64, StoreSetString,
+};
+use watch_list::WatchList;
 
 substreams_ethereum::init!();
 
-const POLYMARKETCTF_TRACKED_CONTRACT: [u8; 20] = hex!("4bfb41d5b3570defd03c39a9a4d8de6bd8b8982e");
-
-fn map_polymarketctf_events(blk: &eth::Block, events: &mut contract::Events) {
-    events.polymarketctf_fee_chargeds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::FeeCharged::match_and_decode(log) {
-                        return Some(contract::PolymarketctfFeeCharged {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            amount: event.amount.to_string(),
-                            receiver: event.receiver,
-                            token_id: event.token_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_new_admins.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::NewAdmin::match_and_decode(log) {
-                        return Some(contract::PolymarketctfNewAdmin {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            admin: event.admin,
-                            new_admin_address: event.new_admin_address,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_new_operators.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::NewOperator::match_and_decode(log) {
-                        return Some(contract::PolymarketctfNewOperator {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            admin: event.admin,
-                            new_operator_address: event.new_operator_address,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_order_cancelleds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::OrderCancelled::match_and_decode(log) {
-                        return Some(contract::PolymarketctfOrderCancelled {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            order_hash: Vec::from(event.order_hash),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_order_filleds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::OrderFilled::match_and_decode(log) {
-                        return Some(contract::PolymarketctfOrderFilled {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            fee: event.fee.to_string(),
-                            maker: event.maker,
-                            maker_amount_filled: event.maker_amount_filled.to_string(),
-                            maker_asset_id: event.maker_asset_id.to_string(),
-                            order_hash: Vec::from(event.order_hash),
-                            taker: event.taker,
-                            taker_amount_filled: event.taker_amount_filled.to_string(),
-                            taker_asset_id: event.taker_asset_id.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_orders_matcheds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::OrdersMatched::match_and_decode(log) {
-                        return Some(contract::PolymarketctfOrdersMatched {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            maker_amount_filled: event.maker_amount_filled.to_string(),
-                            maker_asset_id: event.maker_asset_id.to_string(),
-                            taker_amount_filled: event.taker_amount_filled.to_string(),
-                            taker_asset_id: event.taker_asset_id.to_string(),
-                            taker_order_hash: Vec::from(event.taker_order_hash),
-                            taker_order_maker: event.taker_order_maker,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_proxy_factory_updateds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::ProxyFactoryUpdated::match_and_decode(log) {
-                        return Some(contract::PolymarketctfProxyFactoryUpdated {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            new_proxy_factory: event.new_proxy_factory,
-                            old_proxy_factory: event.old_proxy_factory,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_removed_admins.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::RemovedAdmin::match_and_decode(log) {
-                        return Some(contract::PolymarketctfRemovedAdmin {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            admin: event.admin,
-                            removed_admin: event.removed_admin,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_removed_operators.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::RemovedOperator::match_and_decode(log) {
-                        return Some(contract::PolymarketctfRemovedOperator {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            admin: event.admin,
-                            removed_operator: event.removed_operator,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_safe_factory_updateds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::SafeFactoryUpdated::match_and_decode(log) {
-                        return Some(contract::PolymarketctfSafeFactoryUpdated {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            new_safe_factory: event.new_safe_factory,
-                            old_safe_factory: event.old_safe_factory,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_token_registereds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::TokenRegistered::match_and_decode(log) {
-                        return Some(contract::PolymarketctfTokenRegistered {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            condition_id: Vec::from(event.condition_id),
-                            token0: event.token0.to_string(),
-                            token1: event.token1.to_string(),
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_trading_pauseds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::TradingPaused::match_and_decode(log) {
-                        return Some(contract::PolymarketctfTradingPaused {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            pauser: event.pauser,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-    events.polymarketctf_trading_unpauseds.append(&mut blk
-        .receipts()
-        .flat_map(|view| {
-            view.receipt.logs.iter()
-                .filter(|log| log.address == POLYMARKETCTF_TRACKED_CONTRACT)
-                .filter_map(|log| {
-                    if let Some(event) = abi::polymarketctf_contract::events::TradingUnpaused::match_and_decode(log) {
-                        return Some(contract::PolymarketctfTradingUnpaused {
-                            evt_tx_hash: Hex(&view.transaction.hash).to_string(),
-                            evt_index: log.block_index,
-                            evt_block_time: Some(blk.timestamp().to_owned()),
-                            evt_block_number: blk.number,
-                            pauser: event.pauser,
-                        });
-                    }
-
-                    None
-                })
-        })
-        .collect());
-}
-fn map_polymarketctf_calls(blk: &eth::Block, calls: &mut contract::Calls) {
-    calls.polymarketctf_call_add_admins.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::AddAdmin::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::AddAdmin::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfAddAdminCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                admin: decoded_call.admin,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_add_operators.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::AddOperator::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::AddOperator::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfAddOperatorCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                operator: decoded_call.operator,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_cancel_orders.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::CancelOrder::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::CancelOrder::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfCancelOrderCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_cancel_orders_batch.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::CancelOrders::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::CancelOrders::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfCancelOrdersCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_fill_orders.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::FillOrder::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::FillOrder::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfFillOrderCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                fill_amount: decoded_call.fill_amount.to_string(),
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_fill_orders_batch.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::FillOrders::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::FillOrders::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfFillOrdersCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                fill_amounts: decoded_call.fill_amounts.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_increment_nonces.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::IncrementNonce::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::IncrementNonce::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfIncrementNonceCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_match_orders.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::MatchOrders::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::MatchOrders::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfMatchOrdersCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                maker_fill_amounts: decoded_call.maker_fill_amounts.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
-                                taker_fill_amount: decoded_call.taker_fill_amount.to_string(),
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_on_erc1155_batch_receiveds.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::OnErc1155BatchReceived::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::OnErc1155BatchReceived::decode(call) {
-                        Ok(decoded_call) => {
-                            let output_param0 = match abi::polymarketctf_contract::functions::OnErc1155BatchReceived::output(&call.return_data) {
-                                Ok(output_param0) => {output_param0}
-                                Err(_) => Default::default(),
-                            };
-                            
-                            Some(contract::PolymarketctfOnErc1155BatchReceivedCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                output_param0: Vec::from(output_param0),
-                                param0: decoded_call.param0,
-                                param1: decoded_call.param1,
-                                param2: decoded_call.param2.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
-                                param3: decoded_call.param3.into_iter().map(|x| x.to_string()).collect::<Vec<_>>(),
-                                param4: decoded_call.param4,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_on_erc1155_receiveds.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::OnErc1155Received::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::OnErc1155Received::decode(call) {
-                        Ok(decoded_call) => {
-                            let output_param0 = match abi::polymarketctf_contract::functions::OnErc1155Received::output(&call.return_data) {
-                                Ok(output_param0) => {output_param0}
-                                Err(_) => Default::default(),
-                            };
-                            
-                            Some(contract::PolymarketctfOnErc1155ReceivedCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                output_param0: Vec::from(output_param0),
-                                param0: decoded_call.param0,
-                                param1: decoded_call.param1,
-                                param2: decoded_call.param2.to_string(),
-                                param3: decoded_call.param3.to_string(),
-                                param4: decoded_call.param4,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_pause_tradings.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::PauseTrading::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::PauseTrading::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfPauseTradingCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_register_tokens.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::RegisterToken::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::RegisterToken::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfRegisterTokenCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                complement: decoded_call.complement.to_string(),
-                                condition_id: Vec::from(decoded_call.condition_id),
-                                token: decoded_call.token.to_string(),
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_remove_admins.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::RemoveAdmin::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::RemoveAdmin::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfRemoveAdminCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                admin: decoded_call.admin,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_remove_operators.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::RemoveOperator::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::RemoveOperator::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfRemoveOperatorCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                operator: decoded_call.operator,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_renounce_admin_roles.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::RenounceAdminRole::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::RenounceAdminRole::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfRenounceAdminRoleCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_renounce_operator_roles.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::RenounceOperatorRole::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::RenounceOperatorRole::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfRenounceOperatorRoleCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_set_proxy_factories.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::SetProxyFactory::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::SetProxyFactory::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfSetProxyFactoryCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                u_new_proxy_factory: decoded_call.u_new_proxy_factory,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_set_safe_factories.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::SetSafeFactory::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::SetSafeFactory::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfSetSafeFactoryCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                                u_new_safe_factory: decoded_call.u_new_safe_factory,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-    calls.polymarketctf_call_unpause_tradings.append(&mut blk
-        .transactions()
-        .flat_map(|tx| {
-            tx.calls.iter()
-                .filter(|call| call.address == POLYMARKETCTF_TRACKED_CONTRACT && abi::polymarketctf_contract::functions::UnpauseTrading::match_call(call))
-                .filter_map(|call| {
-                    match abi::polymarketctf_contract::functions::UnpauseTrading::decode(call) {
-                        Ok(decoded_call) => {
-                            Some(contract::PolymarketctfUnpauseTradingCall {
-                                call_tx_hash: Hex(&tx.hash).to_string(),
-                                call_block_time: Some(blk.timestamp().to_owned()),
-                                call_block_number: blk.number,
-                                call_ordinal: call.begin_ordinal,
-                                call_success: !call.state_reverted,
-                            })
-                        },
-                        Err(_) => None,
-                    }
-                })
-        })
-        .collect());
-}
-
 #[substreams::handlers::map]
 fn map_events_calls(
     events: contract::Events,
@@ -749,13 +47,741 @@ fn map_events_calls(
 #[substreams::handlers::map]
 fn map_events(blk: eth::Block) -> Result<contract::Events, substreams::errors::Error> {
     let mut events = contract::Events::default();
-    map_polymarketctf_events(&blk, &mut events);
+    mappers::map_events(&blk, &mut events);
     Ok(events)
 }
 #[substreams::handlers::map]
 fn map_calls(blk: eth::Block) -> Result<contract::Calls, substreams::errors::Error> {
 let mut calls = contract::Calls::default();
-    map_polymarketctf_calls(&blk, &mut calls);
+    mappers::map_calls(&blk, &mut calls);
     Ok(calls)
 }
+#[substreams::handlers::map]
+fn map_events_v2(events: contract::Events) -> Result<contract_v2::Events, substreams::errors::Error> {
+    Ok(v2::events(events))
+}
+#[substreams::handlers::map]
+fn map_calls_v2(calls: contract::Calls) -> Result<contract_v2::Calls, substreams::errors::Error> {
+    Ok(v2::calls(calls))
+}
+#[substreams::handlers::map]
+fn map_events_calls_v2(
+    events_calls: contract::EventsCalls,
+) -> Result<contract_v2::EventsCalls, substreams::errors::Error> {
+    Ok(v2::events_calls(events_calls))
+}
+#[substreams::handlers::map]
+fn map_exchange_records(
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::ExchangeRecords, substreams::errors::Error> {
+    Ok(records::exchange_records(events, calls))
+}
+#[substreams::handlers::map]
+fn map_reconciliation(
+    events: contract::Events,
+    calls: contract::Calls,
+) -> Result<contract::Reconciliation, substreams::errors::Error> {
+    Ok(reconciliation::reconcile(&events, &calls))
+}
+#[substreams::handlers::map]
+fn map_operator_batches(
+    blk: eth::Block,
+    events: contract::Events,
+) -> Result<contract::OperatorBatches, substreams::errors::Error> {
+    Ok(operator::operator_batches(&blk, &events, &mappers::polymarketctf::TRACKED_CONTRACT))
+}
+#[substreams::handlers::store]
+fn store_operator_daily(batches: contract::OperatorBatches, store: StoreAddBigInt) {
+    for batch in batches.batches {
+        let day = batch.block_time.as_ref().map(|t| t.seconds / 86400).unwrap_or_default();
+        let prefix = format!("operator:{}:day:{}", Hex(&batch.operator), day);
+        let ord = batch.tx_index as u64;
+
+        store.add(ord, format!("{}:txs", prefix), BigInt::one());
+        if batch.reverted {
+            store.add(ord, format!("{}:reverted_txs", prefix), BigInt::one());
+        }
+        store.add(ord, format!("{}:makers_matched", prefix), BigInt::from(batch.makers_matched));
+        store.add(ord, format!("{}:notional", prefix), parse_amount(&batch.notional));
+        store.add(ord, format!("{}:gas_used", prefix), BigInt::from(batch.gas_used));
+        store.add(ord, format!("{}:gas_cost_wei", prefix), operator::gas_cost_wei(&batch));
+        store.add(ord, format!("{}:collateral_fees", prefix), parse_amount(&batch.collateral_fees));
+    }
+}
+#[substreams::handlers::map]
+fn map_position_changes(events: contract::Events) -> Result<contract::PositionChanges, substreams::errors::Error> {
+    Ok(positions::position_changes(&events))
+}
+#[substreams::handlers::store]
+fn store_position_balances(changes: contract::PositionChanges, store: StoreAddBigInt) {
+    for change in changes.changes {
+        let delta = parse_amount(&change.delta);
+        if change.counterparty == positions::ZERO_ADDRESS {
+            store.add(change.ordinal, positions::supply_key(&change.position_id), delta.clone());
+        }
+        store.add(change.ordinal, positions::balance_key(&change.holder, &change.position_id), delta);
+    }
+}
+#[substreams::handlers::store]
+fn store_position_holders(changes: contract::PositionChanges, balances: StoreGetBigInt, store: StoreAppend<String>) {
+    for change in changes.changes {
+        let delta = parse_amount(&change.delta);
+        if delta <= BigInt::zero() {
+            continue;
+        }
+        // The balance after the change is all it brought in: the holder had none before.
+        let balance = balances.get_at(change.ordinal, positions::balance_key(&change.holder, &change.position_id));
+        if balance == Some(delta) {
+            store.append(change.ordinal, positions::holders_key(&change.position_id), Hex(&change.holder).to_string());
+        }
+    }
+}
+#[substreams::handlers::map]
+fn map_position_balances(
+    deltas: Deltas<DeltaBigInt>,
+) -> Result<contract::PositionBalances, substreams::errors::Error> {
+    Ok(positions::position_balances(&deltas))
+}
+#[substreams::handlers::store]
+fn store_registered_tokens(events: contract::Events, store: StoreSetString) {
+    for registered in events.polymarketctf_token_registereds {
+        let condition_id = Hex(&registered.condition_id).to_string();
+        store.set(registered.evt_ordinal, format!("token:{}", registered.token0), &condition_id);
+        store.set(registered.evt_ordinal, format!("token:{}", registered.token1), &condition_id);
+
+        let mut tokens = [registered.token0.as_str(), registered.token1.as_str()];
+        tokens.sort();
+        store.set(registered.evt_ordinal, format!("condition:{}", condition_id), &tokens.join(","));
+    }
+}
+#[substreams::handlers::store]
+fn store_resolutions(events: contract::Events, registered_tokens: StoreGetString, store: StoreSetString) {
+    for resolution in events.ctf_condition_resolutions {
+        let tokens = market_tokens(&registered_tokens, &resolution.condition_id);
+        for outcome in settlement::outcomes(&resolution, &tokens).0 {
+            let payout = format!("{}/{}", outcome.payout_numerator, outcome.payout_denominator);
+            store.set(resolution.evt_ordinal, format!("token:{}", outcome.token_id), &payout);
+        }
+    }
+}
+#[substreams::handlers::map]
+fn map_settlements(
+    events: contract::Events,
+    changes: contract::PositionChanges,
+    registered_tokens: StoreGetString,
+    resolutions: StoreGetString,
+    positions: StoreGetBigInt,
+    holders: StoreGetArray<String>,
+) -> Result<contract::Settlements, substreams::errors::Error> {
+    let state = Stores {
+        registered_tokens: &registered_tokens,
+        resolutions: &resolutions,
+        positions: &positions,
+        holders: &holders,
+    };
+    Ok(settlement::settlements(&events, &changes, &state))
+}
+#[substreams::handlers::map]
+fn map_open_interest_changes(
+    events: contract::Events,
+    registered_tokens: StoreGetString,
+) -> Result<contract::OpenInterestChanges, substreams::errors::Error> {
+    Ok(open_interest::open_interest_changes(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |condition_id| {
+        market_tokens(&registered_tokens, condition_id).len()
+    }))
+}
+#[substreams::handlers::store]
+fn store_open_interest(changes: contract::OpenInterestChanges, store: StoreAddBigInt) {
+    for (index, change) in changes.changes.iter().enumerate() {
+        store.add(index as u64, open_interest::open_interest_key(&change.condition_id), parse_amount(&change.delta));
+    }
+}
+#[substreams::handlers::map]
+fn map_open_interest(
+    changes: contract::OpenInterestChanges,
+    open_interest: StoreGetBigInt,
+) -> Result<contract::OpenInterestChanges, substreams::errors::Error> {
+    let mut changes = changes;
+    for change in &mut changes.changes {
+        change.open_interest = open_interest
+            .get_last(open_interest::open_interest_key(&change.condition_id))
+            .unwrap_or_else(BigInt::zero)
+            .to_string();
+    }
+    Ok(changes)
+}
+#[substreams::handlers::store]
+fn store_last_prices(events: contract::Events, store: StoreSetString) {
+    for event in events.polymarketctf_order_filleds {
+        if let Some(fill) = price::fill(&event) {
+            store.set(event.evt_ordinal, format!("price:{}", fill.token_id), &fill.price.to_string());
+        }
+    }
+}
+#[substreams::handlers::map]
+fn map_implied_probabilities(
+    params: String,
+    events: contract::Events,
+    registered_tokens: StoreGetString,
+    last_prices: StoreGetString,
+) -> Result<contract::ImpliedProbabilities, substreams::errors::Error> {
+    let tolerance = probability::parse_tolerance(&params)?;
+    Ok(probability::implied_probabilities(
+        &events,
+        &tolerance,
+        |token_id| {
+            let condition_id = condition_of(&registered_tokens, token_id)?;
+            let [token0, token1] = market_tokens(&registered_tokens, &condition_id).try_into().ok()?;
+            Some((condition_id, [token0, token1]))
+        },
+        |token_id| {
+            last_prices
+                .get_last(format!("price:{}", token_id))
+                .and_then(|price| BigDecimal::try_from(price).ok())
+        },
+    ))
+}
+#[substreams::handlers::map]
+fn map_vault_events(params: String, blk: eth::Block) -> Result<contract::Events, substreams::errors::Error> {
+    vault::vault_events(&blk, &params)
+}
+#[substreams::handlers::map]
+fn map_vault_calls(params: String, blk: eth::Block) -> Result<contract::Calls, substreams::errors::Error> {
+    vault::vault_calls(&blk, &params)
+}
+#[substreams::handlers::store]
+fn store_vault_tvl(events: contract::Events, calls: contract::Calls, store: StoreAddBigInt) {
+    for (ordinal, change) in vault::tvl_changes(&events, &calls) {
+        store.add(ordinal, vault::TVL_KEY, change);
+    }
+}
+#[substreams::handlers::store]
+fn store_vault_deposits(events: contract::Events, store: StoreAddBigInt) {
+    for deposit in events.vault_deposits {
+        let ord = deposit.evt_ordinal;
+        store.add(ord, vault::user_key(&deposit.user, "deposits"), BigInt::one());
+        store.add(ord, vault::user_key(&deposit.user, "deposited"), parse_amount(&deposit.usdc_amount));
+        store.add(ord, vault::user_key(&deposit.user, "net_deposited"), vault::net_deposit(&deposit));
+        store.add(ord, vault::user_key(&deposit.user, "fees"), parse_amount(&deposit.fee_amount));
+        store.add(ord, vault::user_key(&deposit.user, "minted"), parse_amount(&deposit.steth_amount));
+    }
+}
+#[substreams::handlers::store]
+fn store_vault_fees(events: contract::Events, store: StoreAddBigInt) {
+    for deposit in events.vault_deposits {
+        let day = deposit.evt_block_time.as_ref().map(|t| t.seconds / 86400).unwrap_or_default();
+        let fee = parse_amount(&deposit.fee_amount);
+        store.add(deposit.evt_ordinal, vault::FEES_KEY, fee.clone());
+        store.add(deposit.evt_ordinal, vault::daily_fees_key(day), fee);
+    }
+}
+#[substreams::handlers::store]
+fn store_wallet_owners(calls: contract::Calls, store: StoreSetString) {
+    for (ordinal, proxy, signer) in activity::proxy_owners(&calls) {
+        store.set(ordinal, activity::owner_key(&proxy), &Hex(&signer).to_string());
+    }
+}
+#[substreams::handlers::map]
+fn map_user_activity(
+    vault_events: contract::Events,
+    events: contract::Events,
+    wallet_owners: StoreGetString,
+    vault_deposits: StoreGetBigInt,
+) -> Result<contract::UserActivities, substreams::errors::Error> {
+    Ok(activity::user_activities(
+        &vault_events,
+        &events,
+        &mappers::polymarketctf::TRACKED_CONTRACT,
+        |actor| {
+            wallet_owners
+                .get_last(activity::owner_key(actor))
+                .and_then(|signer| Hex::decode(signer).ok())
+        },
+        |wallet, ordinal| vault_deposits.get_at(ordinal, vault::user_key(wallet, "deposits")).is_some(),
+    ))
+}
+#[substreams::handlers::map]
+fn map_collateral_flows(blk: eth::Block) -> Result<contract::CollateralFlows, substreams::errors::Error> {
+    Ok(collateral::block_flows(&blk))
+}
+#[substreams::handlers::store]
+fn store_collateral_flows(flows: contract::CollateralFlows, store: StoreAddBigInt) {
+    for flow in flows.flows {
+        let amount = parse_amount(&flow.amount);
+        if amount < BigInt::zero() {
+            store.add(flow.ordinal, collateral::flow_key(&flow.trader, "outflow"), amount.neg());
+        } else {
+            store.add(flow.ordinal, collateral::flow_key(&flow.trader, "inflow"), amount);
+        }
+    }
+}
+#[substreams::handlers::store]
+fn store_collateral_allowances(flows: contract::CollateralFlows, store: StoreSetBigInt) {
+    for allowance in flows.allowances {
+        store.set(
+            allowance.ordinal,
+            collateral::allowance_key(&allowance.owner, &allowance.spender),
+            &parse_amount(&allowance.allowance),
+        );
+    }
+}
+#[substreams::handlers::store]
+fn store_operator_approvals(events: contract::Events, store: StoreSetString) {
+    for event in events.ctf_approval_for_alls {
+        let approval = approvals::Approval::of(&event);
+        store.set(event.evt_ordinal, approvals::approval_key(&event.owner, &event.operator), &approval.to_value());
+    }
+}
+#[substreams::handlers::map]
+fn map_operator_approvals(
+    events: contract::Events,
+    operator_approvals: StoreGetString,
+) -> Result<contract::OperatorApprovals, substreams::errors::Error> {
+    Ok(approvals::operator_approvals(
+        &events,
+        &mappers::polymarketctf::TRACKED_CONTRACT,
+        |owner, operator, ordinal| {
+            operator_approvals
+                .get_at(ordinal, approvals::approval_key(owner, operator))
+                .and_then(|value| approvals::Approval::parse(&value))
+        },
+    ))
+}
+#[substreams::handlers::store]
+fn store_trader_positions(events: contract::Events, store: StoreAddBigInt) {
+    for trade in leaderboard::trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT) {
+        let (size, amount) = match trade.side {
+            price::Side::Buy => ("buy_size", "buy_cost"),
+            price::Side::Sell => ("sell_size", "sell_proceeds"),
+        };
+        store.add(trade.ordinal, leaderboard::position_key(&trade.wallet, &trade.token_id, size), trade.size);
+        store.add(trade.ordinal, leaderboard::position_key(&trade.wallet, &trade.token_id, amount), trade.notional);
+    }
+}
+#[substreams::handlers::map]
+fn map_trader_stats(
+    events: contract::Events,
+    trader_positions: StoreGetBigInt,
+) -> Result<contract::TraderStats, substreams::errors::Error> {
+    Ok(leaderboard::trader_stats(
+        &events,
+        &mappers::polymarketctf::TRACKED_CONTRACT,
+        |wallet, token_id, ordinal| {
+            let field = |name: &str| {
+                let key = leaderboard::position_key(wallet, token_id, name);
+                match ordinal {
+                    Some(ordinal) => trader_positions.get_at(ordinal, key),
+                    None => trader_positions.get_last(key),
+                }
+                .unwrap_or_else(BigInt::zero)
+            };
+            leaderboard::Position {
+                buy_size: field("buy_size"),
+                buy_cost: field("buy_cost"),
+                sell_size: field("sell_size"),
+                sell_proceeds: field("sell_proceeds"),
+            }
+        },
+    ))
+}
+#[substreams::handlers::store]
+fn store_leaderboard_days(stats: contract::TraderStats, store: StoreMaxInt64) {
+    if stats.block_time.is_some() {
+        store.max(0, "day", stats.day);
+        store.max(0, "week", stats.week);
+    }
+}
+#[substreams::handlers::store]
+fn store_trader_totals(stats: contract::TraderStats, days: Deltas<DeltaInt64>, store: StoreAddBigInt) {
+    delete_expired_windows(&days, &store);
+    for window in leaderboard::Window::ALL.iter().map(|w| w.key(&stats)) {
+        for (index, stat) in stats.stats.iter().enumerate() {
+            for (metric, value) in leaderboard::metrics(stat) {
+                if !value.is_zero() {
+                    store.add(index as u64, leaderboard::total_key(&window, &stat.wallet, metric), value);
+                }
+            }
+        }
+    }
+}
+#[substreams::handlers::store]
+fn store_leaderboard_buckets(
+    stats: contract::TraderStats,
+    trader_totals: StoreGetBigInt,
+    days: Deltas<DeltaInt64>,
+    store: StoreAddInt64,
+) {
+    delete_expired_windows(&days, &store);
+    let total = |key: &str| trader_totals.get_last(key).unwrap_or_else(BigInt::zero);
+    for (index, moved) in leaderboard::bucket_moves(&stats, total).into_iter().enumerate() {
+        if moved.from > 0 {
+            store.add(index as u64, leaderboard::bucket_key(&moved.window, moved.metric, moved.from), -1);
+        }
+        if moved.to > 0 {
+            store.add(index as u64, leaderboard::bucket_key(&moved.window, moved.metric, moved.to), 1);
+        }
+    }
+}
+#[substreams::handlers::store]
+fn store_leaderboard_slots(
+    stats: contract::TraderStats,
+    trader_totals: StoreGetBigInt,
+    leaderboard_buckets: StoreGetInt64,
+    days: Deltas<DeltaInt64>,
+    store: StoreAddInt64,
+) {
+    delete_expired_windows(&days, &store);
+    for (index, moved) in leaderboard_admissions(&stats, &trader_totals, &leaderboard_buckets).into_iter().enumerate() {
+        store.add(index as u64, leaderboard::bucket_key(&moved.window, moved.metric, moved.to), 1);
+    }
+}
+#[substreams::handlers::store]
+fn store_leaderboard_candidates(
+    stats: contract::TraderStats,
+    trader_totals: StoreGetBigInt,
+    leaderboard_buckets: StoreGetInt64,
+    leaderboard_slots: StoreGetInt64,
+    days: Deltas<DeltaInt64>,
+    store: StoreSetString,
+) {
+    delete_expired_windows(&days, &store);
+    for (index, moved) in leaderboard_admissions(&stats, &trader_totals, &leaderboard_buckets).into_iter().enumerate() {
+        let slot = leaderboard_slots
+            .get_at(index as u64, leaderboard::bucket_key(&moved.window, moved.metric, moved.to))
+            .unwrap_or_default();
+        let key = leaderboard::candidate_key(&moved.window, moved.metric, moved.to, slot);
+        store.set(index as u64, key, &Hex(&moved.wallet).to_string());
+    }
+}
+#[substreams::handlers::map]
+fn map_leaderboard(
+    params: String,
+    stats: contract::TraderStats,
+    trader_totals: StoreGetBigInt,
+    leaderboard_slots: StoreGetInt64,
+    leaderboard_candidates: StoreGetString,
+) -> Result<contract::Leaderboards, substreams::errors::Error> {
+    let params = leaderboard::Params::parse(&params)?;
+    let mut leaderboards = contract::Leaderboards::default();
+    if stats.stats.is_empty() {
+        return Ok(leaderboards);
+    }
+    leaderboards.block_time = stats.block_time;
+    leaderboards.block_number = stats.block_number;
 
+    for window in params.windows.iter().map(|w| w.key(&stats)) {
+        for metric in leaderboard::METRICS {
+            let entries = leaderboard::top(
+                params.top,
+                |bucket| {
+                    let slots = leaderboard_slots.get_last(leaderboard::bucket_key(&window, metric, bucket));
+                    (1..=slots.unwrap_or_default())
+                        .filter_map(|slot| leaderboard_candidates.get_last(leaderboard::candidate_key(&window, metric, bucket, slot)))
+                        .filter_map(|wallet| Hex::decode(wallet).ok())
+                        .collect()
+                },
+                |wallet| {
+                    trader_totals
+                        .get_last(leaderboard::total_key(&window, wallet, metric))
+                        .unwrap_or_else(BigInt::zero)
+                },
+            );
+            leaderboards.boards.push(contract::Leaderboard {
+                metric: metric.to_string(),
+                window: window.clone(),
+                entries,
+            });
+        }
+    }
+    Ok(leaderboards)
+}
+#[substreams::handlers::store]
+fn store_market_hours(events: contract::Events, store: StoreMaxInt64) {
+    if let Some(fill) = events.polymarketctf_order_filleds.first() {
+        store.max(0, "hour", market_stats::hour(fill.evt_block_time.as_ref()));
+    }
+}
+#[substreams::handlers::store]
+fn store_market_traders(events: contract::Events, registered_tokens: StoreGetString, store: StoreSetInt64) {
+    let trades = market_stats::market_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |token_id| {
+        condition_of(&registered_tokens, token_id)
+    });
+    for trade in trades {
+        for wallet in [&trade.maker, &trade.taker] {
+            store.set(trade.ordinal, market_stats::trader_key(&trade.condition_id, wallet), &trade.hour);
+        }
+    }
+}
+#[substreams::handlers::store]
+fn store_market_totals(
+    events: contract::Events,
+    registered_tokens: StoreGetString,
+    market_traders: StoreGetInt64,
+    hours: Deltas<DeltaInt64>,
+    store: StoreAddBigInt,
+) {
+    delete_expired_hours(&hours, &store);
+    let trades = market_stats::market_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |token_id| {
+        condition_of(&registered_tokens, token_id)
+    });
+
+    for trade in &trades {
+        for (metric, value) in [("trades", BigInt::one()), ("volume", trade.notional.clone())] {
+            store.add(trade.ordinal, market_stats::total_key(&trade.condition_id, metric), value.clone());
+            store.add(trade.ordinal, market_stats::hourly_key(trade.hour, &trade.condition_id, metric), value);
+        }
+    }
+    let moves = market_stats::trader_moves(&trades, |condition_id, wallet, ordinal| {
+        market_traders.get_at(ordinal, market_stats::trader_key(condition_id, wallet))
+    });
+    for moved in moves {
+        if moved.new {
+            store.add(moved.ordinal, market_stats::total_key(&moved.condition_id, "traders"), BigInt::one());
+        }
+        if let Some(from) = moved.from {
+            store.add(moved.ordinal, market_stats::hourly_key(from, &moved.condition_id, "traders"), BigInt::from(-1));
+        }
+        store.add(moved.ordinal, market_stats::hourly_key(moved.to, &moved.condition_id, "traders"), BigInt::one());
+    }
+}
+#[substreams::handlers::store]
+fn store_market_opens(
+    events: contract::Events,
+    last_prices: StoreGetString,
+    hours: Deltas<DeltaInt64>,
+    store: StoreSetIfNotExistsString,
+) {
+    delete_expired_hours(&hours, &store);
+    let mut fills: Vec<_> = events.polymarketctf_order_filleds.iter().collect();
+    fills.sort_by_key(|f| f.evt_ordinal);
+    for event in fills {
+        let Some(fill) = price::fill(event) else {
+            continue;
+        };
+        let open = last_prices
+            .get_at(event.evt_ordinal.saturating_sub(1), format!("price:{}", fill.token_id))
+            .unwrap_or_else(|| fill.price.to_string());
+        let hour = market_stats::hour(event.evt_block_time.as_ref());
+        store.set_if_not_exists(event.evt_ordinal, market_stats::open_key(hour, &fill.token_id), &open);
+    }
+}
+#[substreams::handlers::map]
+fn map_market_stats(
+    events: contract::Events,
+    registered_tokens: StoreGetString,
+    market_totals: StoreGetBigInt,
+    last_prices: StoreGetString,
+    market_opens: StoreGetString,
+) -> Result<contract::MarketStatsUpdates, substreams::errors::Error> {
+    let trades = market_stats::market_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |token_id| {
+        condition_of(&registered_tokens, token_id)
+    });
+    let decimal = |value: String| BigDecimal::try_from(value).ok();
+    Ok(market_stats::market_stats(
+        &trades,
+        |condition_id| market_tokens(&registered_tokens, condition_id),
+        |key| market_totals.get_last(key).unwrap_or_else(BigInt::zero),
+        |token_id| last_prices.get_last(format!("price:{}", token_id)).and_then(decimal),
+        |hour, token_id| market_opens.get_last(market_stats::open_key(hour, token_id)).and_then(decimal),
+    ))
+}
+#[substreams::handlers::store]
+fn store_token_days(events: contract::Events, store: StoreMaxInt64) {
+    if let Some(fill) = events.polymarketctf_order_filleds.first() {
+        let day = fill.evt_block_time.as_ref().map(|t| t.seconds / 86400).unwrap_or_default();
+        store.max(0, "day", day);
+    }
+}
+#[substreams::handlers::store]
+fn store_token_volumes(
+    events: contract::Events,
+    hours: Deltas<DeltaInt64>,
+    days: Deltas<DeltaInt64>,
+    store: StoreAddBigInt,
+) {
+    delete_expired_hours(&hours, &store);
+    for delta in &days.deltas {
+        for day in token_analytics::expired_days(delta.old_value, delta.new_value) {
+            store.delete_prefix(0, &format!("day:{}:", day));
+        }
+    }
+    for trade in token_analytics::token_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT) {
+        for (key, value) in trade.volume_changes() {
+            store.add(trade.ordinal, key, value);
+        }
+    }
+}
+#[substreams::handlers::map]
+fn map_token_analytics(
+    params: String,
+    events: contract::Events,
+    token_volumes: StoreGetBigInt,
+) -> Result<contract::TokenAnalyticsUpdates, substreams::errors::Error> {
+    let windows = token_analytics::parse_windows(&params)?;
+    let trades = token_analytics::token_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT);
+    Ok(token_analytics::token_analytics(&trades, &windows, |key| {
+        token_volumes.get_last(key).unwrap_or_else(BigInt::zero)
+    }))
+}
+#[substreams::handlers::map]
+fn map_executions(events: contract::Events, calls: contract::Calls) -> Result<contract::Executions, substreams::errors::Error> {
+    Ok(execution::executions(&events, &calls))
+}
+#[substreams::handlers::store]
+fn store_last_buyers(events: contract::Events, wallet_owners: StoreGetString, store: StoreSetString) {
+    let trades = wash_trading::owned_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |wallet| {
+        wallet_owner(&wallet_owners, wallet)
+    });
+    // A self trade passes the tokens nowhere.
+    for trade in trades.iter().filter(|t| t.seller != t.buyer) {
+        store.set(
+            trade.ordinal,
+            wash_trading::buyer_key(&trade.token_id, &trade.seller),
+            &wash_trading::buyer_value(&trade.buyer, trade.seconds()),
+        );
+    }
+}
+#[substreams::handlers::map]
+fn map_suspicious_trades(
+    params: String,
+    events: contract::Events,
+    wallet_owners: StoreGetString,
+    last_buyers: StoreGetString,
+) -> Result<contract::SuspiciousTrades, substreams::errors::Error> {
+    let params = wash_trading::Params::parse(&params)?;
+    let trades = wash_trading::owned_trades(&events, &mappers::polymarketctf::TRACKED_CONTRACT, |wallet| {
+        wallet_owner(&wallet_owners, wallet)
+    });
+    Ok(wash_trading::suspicious_trades(&trades, &params, |token_id, seller, ordinal| {
+        last_buyers
+            .get_at(ordinal, wash_trading::buyer_key(token_id, seller))
+            .and_then(|value| wash_trading::parse_buyer(&value))
+    }))
+}
+#[substreams::handlers::map]
+fn map_filtered_events(
+    params: String,
+    events: contract::Events,
+    registered_tokens: StoreGetString,
+) -> Result<contract::Events, substreams::errors::Error> {
+    let watch_list = WatchList::parse(&params)?;
+    Ok(watch_list.filter_events(events, |token_id| condition_of(&registered_tokens, token_id)))
+}
+#[substreams::handlers::map]
+fn map_filtered_calls(
+    params: String,
+    calls: contract::Calls,
+    registered_tokens: StoreGetString,
+) -> Result<contract::Calls, substreams::errors::Error> {
+    let watch_list = WatchList::parse(&params)?;
+    Ok(watch_list.filter_calls(calls, |token_id| condition_of(&registered_tokens, token_id)))
+}
+
+/// Drops the day and week windows before the last one from a store once `store_leaderboard_days`
+/// moves on to a new one.
+fn delete_expired_windows(days: &Deltas<DeltaInt64>, store: &impl StoreDelete) {
+    for delta in &days.deltas {
+        for window in leaderboard::expired_windows(delta.old_value, delta.new_value) {
+            store.delete_prefix(0, &format!("{}:{}:", delta.key, window));
+        }
+    }
+}
+
+/// `leaderboard::admissions` of the block, counting the wallets above a bucket in
+/// `store_leaderboard_buckets`.
+fn leaderboard_admissions(
+    stats: &contract::TraderStats,
+    trader_totals: &StoreGetBigInt,
+    leaderboard_buckets: &StoreGetInt64,
+) -> Vec<leaderboard::BucketMove> {
+    let total = |key: &str| trader_totals.get_last(key).unwrap_or_else(BigInt::zero);
+    let above = |window: &str, metric: &str, bucket: u32| {
+        (bucket + 1..=leaderboard::MAX_BUCKET)
+            .filter_map(|b| leaderboard_buckets.get_last(leaderboard::bucket_key(window, metric, b)))
+            .sum()
+    };
+    leaderboard::admissions(stats, total, above)
+}
+
+/// Drops the hours falling out of the rolling window of `map_market_stats` from a store once
+/// `store_market_hours` moves on to a new hour.
+fn delete_expired_hours(hours: &Deltas<DeltaInt64>, store: &impl StoreDelete) {
+    for delta in &hours.deltas {
+        for hour in market_stats::expired_hours(delta.old_value, delta.new_value) {
+            store.delete_prefix(0, &format!("hour:{}:", hour));
+        }
+    }
+}
+
+fn condition_of(registered_tokens: &StoreGetString, token_id: &str) -> Option<Vec<u8>> {
+    registered_tokens
+        .get_last(format!("token:{}", token_id))
+        .and_then(|condition_id| Hex::decode(condition_id).ok())
+}
+
+/// The signer owning a proxy or safe wallet in `store_wallet_owners`, or the wallet itself.
+fn wallet_owner(wallet_owners: &StoreGetString, wallet: &[u8]) -> Vec<u8> {
+    wallet_owners
+        .get_last(activity::owner_key(wallet))
+        .and_then(|signer| Hex::decode(signer).ok())
+        .unwrap_or_else(|| wallet.to_vec())
+}
+
+fn market_tokens(registered_tokens: &StoreGetString, condition_id: &[u8]) -> Vec<String> {
+    registered_tokens
+        .get_last(format!("condition:{}", Hex(condition_id)))
+        .map(|tokens| tokens.split(',').map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+struct Stores<'a> {
+    registered_tokens: &'a StoreGetString,
+    resolutions: &'a StoreGetString,
+    positions: &'a StoreGetBigInt,
+    holders: &'a StoreGetArray<String>,
+}
+
+impl settlement::SettlementState for Stores<'_> {
+    fn market_tokens(&self, condition_id: &[u8]) -> Vec<String> {
+        market_tokens(self.registered_tokens, condition_id)
+    }
+
+    fn market_of(&self, token_id: &str) -> Option<Vec<u8>> {
+        condition_of(self.registered_tokens, token_id)
+    }
+
+    fn payout(&self, token_id: &str) -> Option<settlement::Payout> {
+        self.resolutions
+            .get_last(format!("token:{}", token_id))
+            .and_then(|payout| settlement::Payout::parse(&payout))
+    }
+
+    fn supply(&self, ordinal: Option<u64>, token_id: &str) -> BigInt {
+        let key = positions::supply_key(token_id);
+        match ordinal {
+            Some(ordinal) => self.positions.get_at(ordinal, key),
+            None => self.positions.get_last(key),
+        }
+        .unwrap_or_else(BigInt::zero)
+    }
+
+    fn balance(&self, holder: &[u8], token_id: &str) -> BigInt {
+        self.positions
+            .get_last(positions::balance_key(holder, token_id))
+            .unwrap_or_else(BigInt::zero)
+    }
+
+    fn holders(&self, token_id: &str) -> Vec<Vec<u8>> {
+        let mut holders: Vec<Vec<u8>> = self
+            .holders
+            .get_last(positions::holders_key(token_id))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|holder| Hex::decode(holder).ok())
+            .collect();
+        holders.sort();
+        holders.dedup();
+        holders
+    }
+}
@@ -0,0 +1,205 @@
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price;
+use prost_types::Timestamp;
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::Hex;
+
+/// Hours the rolling window spans, the current one included.
+pub const WINDOW_HOURS: i64 = 24;
+
+/// Hours since epoch.
+pub fn hour(time: Option<&Timestamp>) -> i64 {
+    time.map(|t| t.seconds / 3600).unwrap_or_default()
+}
+
+/// Hours of the rolling window ending in `hour`.
+pub fn window(hour: i64) -> RangeInclusive<i64> {
+    hour - WINDOW_HOURS + 1..=hour
+}
+
+/// Hours falling out of the window when the last hour with fills moves from `previous` to
+/// `current`: those of the window ending in `previous` that aren't in the new one.
+pub fn expired_hours(previous: i64, current: i64) -> RangeInclusive<i64> {
+    // No hours are kept before the first one.
+    let last = if previous == 0 { previous - WINDOW_HOURS } else { previous.min(current - WINDOW_HOURS) };
+    previous - WINDOW_HOURS + 1..=last
+}
+
+/// Key of a market's all-time total of `trades`, `traders` or `volume` in `store_market_totals`.
+pub fn total_key(condition_id: &[u8], metric: &str) -> String {
+    format!("market:{}:{}", Hex(condition_id), metric)
+}
+
+/// Key of what an hour added to a market's `trades`, `traders` or `volume` in
+/// `store_market_totals`.
+pub fn hourly_key(hour: i64, condition_id: &[u8], metric: &str) -> String {
+    format!("hour:{}:market:{}:{}", hour, Hex(condition_id), metric)
+}
+
+/// Key of the last hour a wallet traded a market in `store_market_traders`.
+pub fn trader_key(condition_id: &[u8], wallet: &[u8]) -> String {
+    format!("market:{}:trader:{}", Hex(condition_id), Hex(wallet))
+}
+
+/// Key of a token's last trade price before its first fill of an hour in `store_market_opens`.
+pub fn open_key(hour: i64, token_id: &str) -> String {
+    format!("hour:{}:open:{}", hour, token_id)
+}
+
+/// A fill of a market's outcome token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketTrade {
+    pub ordinal: u64,
+    pub hour: i64,
+    pub block_time: Option<Timestamp>,
+    pub block_number: u64,
+    pub condition_id: Vec<u8>,
+    pub token_id: String,
+    pub maker: Vec<u8>,
+    pub taker: Vec<u8>,
+    pub notional: BigInt,
+}
+
+/// Every fill of the block trading a market's token, in execution order. `condition_of` gives
+/// the condition an outcome token is registered under. A matched trade counts once: the fill of
+/// a `matchOrders` taker order, whose taker is the exchange, is left out.
+pub fn market_trades<C>(events: &contract::Events, exchange: &[u8], condition_of: C) -> Vec<MarketTrade>
+where
+    C: Fn(&str) -> Option<Vec<u8>>,
+{
+    let mut trades = Vec::new();
+    for event in &events.polymarketctf_order_filleds {
+        if event.taker == exchange {
+            continue;
+        }
+        let Some(fill) = price::fill(event) else {
+            continue;
+        };
+        let Some(condition_id) = condition_of(&fill.token_id) else {
+            continue;
+        };
+        trades.push(MarketTrade {
+            ordinal: event.evt_ordinal,
+            hour: hour(event.evt_block_time.as_ref()),
+            block_time: event.evt_block_time,
+            block_number: event.evt_block_number,
+            condition_id,
+            token_id: fill.token_id,
+            maker: event.maker.clone(),
+            taker: event.taker.clone(),
+            notional: fill.notional,
+        });
+    }
+    trades.sort_by_key(|t| t.ordinal);
+    trades
+}
+
+/// A wallet trading a market for the first time in the block, moving its last hour of trading
+/// to this one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraderMove {
+    pub ordinal: u64,
+    pub condition_id: Vec<u8>,
+    /// First trade of the wallet in the market.
+    pub new: bool,
+    /// Hour of the wallet's previous trade, when still in the window.
+    pub from: Option<i64>,
+    pub to: i64,
+}
+
+/// How the block's trades move wallets between the hours of their last trade of each market.
+/// `previous_hour` gives the last hour a wallet traded a market, as of an ordinal. Wallets
+/// that already traded the market in the current hour don't move.
+pub fn trader_moves<P>(trades: &[MarketTrade], previous_hour: P) -> Vec<TraderMove>
+where
+    P: Fn(&[u8], &[u8], u64) -> Option<i64>,
+{
+    let mut seen = BTreeSet::new();
+    let mut moves = Vec::new();
+    for trade in trades {
+        for wallet in [&trade.maker, &trade.taker] {
+            if !seen.insert((trade.condition_id.clone(), wallet.clone())) {
+                continue;
+            }
+            let previous = previous_hour(&trade.condition_id, wallet, trade.ordinal.saturating_sub(1));
+            if previous == Some(trade.hour) {
+                continue;
+            }
+            moves.push(TraderMove {
+                ordinal: trade.ordinal,
+                condition_id: trade.condition_id.clone(),
+                new: previous.is_none(),
+                from: previous.filter(|h| window(trade.hour).contains(h)),
+                to: trade.hour,
+            });
+        }
+    }
+    moves
+}
+
+/// One `MarketStats` per market traded in the block, in the order of their first trade.
+/// `market_tokens` gives the tokens registered for a condition, `total` a value of
+/// `store_market_totals` by key after the block, `last_price` the last trade price of a token
+/// and `open` its last price before its first fill of an hour, for the hours it traded in.
+pub fn market_stats<M, T, P, O>(
+    trades: &[MarketTrade],
+    market_tokens: M,
+    total: T,
+    last_price: P,
+    open: O,
+) -> contract::MarketStatsUpdates
+where
+    M: Fn(&[u8]) -> Vec<String>,
+    T: Fn(&str) -> BigInt,
+    P: Fn(&str) -> Option<BigDecimal>,
+    O: Fn(i64, &str) -> Option<BigDecimal>,
+{
+    let mut updates = contract::MarketStatsUpdates::default();
+    let mut seen = BTreeSet::new();
+
+    for trade in trades {
+        if !seen.insert(trade.condition_id.clone()) {
+            continue;
+        }
+        let condition_id = &trade.condition_id;
+        let rolling = |metric: &str| {
+            window(trade.hour).fold(BigInt::zero(), |sum, hour| sum + total(&hourly_key(hour, condition_id, metric)))
+        };
+        let mut tokens = market_tokens(condition_id).into_iter();
+        let token0 = tokens.next().unwrap_or_default();
+        let token1 = tokens.next().unwrap_or_default();
+        let [(price0, ago0, change0), (price1, ago1, change1)] = [&token0, &token1].map(|token_id| {
+            let Some(price) = last_price(token_id) else {
+                return Default::default();
+            };
+            // No trade in the window leaves the price where it was.
+            let ago = window(trade.hour).find_map(|hour| open(hour, token_id)).unwrap_or_else(|| price.clone());
+            let change = price.clone() - ago.clone();
+            (price.to_string(), ago.to_string(), change.to_string())
+        });
+
+        updates.markets.push(contract::MarketStats {
+            condition_id: condition_id.clone(),
+            block_time: trade.block_time,
+            block_number: trade.block_number,
+            trades: total(&total_key(condition_id, "trades")).to_u64(),
+            traders: total(&total_key(condition_id, "traders")).to_u64(),
+            volume: total(&total_key(condition_id, "volume")).to_string(),
+            trades_24h: rolling("trades").to_u64(),
+            traders_24h: rolling("traders").to_u64(),
+            volume_24h: rolling("volume").to_string(),
+            token0,
+            token1,
+            price0,
+            price1,
+            price0_24h_ago: ago0,
+            price1_24h_ago: ago1,
+            change0_24h: change0,
+            change1_24h: change1,
+        });
+    }
+
+    updates
+}
@@ -0,0 +1,129 @@
+use nooroo_core::ctf;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::parse_amount;
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+
+#[derive(Default)]
+struct Tally {
+    condition_id: Vec<u8>,
+    split: BigInt,
+    merged: BigInt,
+    exchange_minted: BigInt,
+    exchange_merged: BigInt,
+    redeemed: BigInt,
+}
+
+/// Collateral moved in and out of complete sets of the exchange's markets, one change per
+/// market touched by the block. `outcome_count` gives the number of tokens registered for a
+/// condition, zero for conditions that aren't markets of the exchange.
+///
+/// Only top-level splits and merges of the whole condition move collateral; those between
+/// positions, or on another collateral, leave open interest as it is. The exchange splits and
+/// merges for MINT and MERGE matches itself, which is how they are told apart.
+pub fn open_interest_changes<F>(
+    events: &contract::Events,
+    exchange: &[u8],
+    outcome_count: F,
+) -> contract::OpenInterestChanges
+where
+    F: Fn(&[u8]) -> usize,
+{
+    let mut tallies: Vec<Tally> = Vec::new();
+    let mut block = None;
+
+    for split in &events.ctf_position_splits {
+        if !moves_collateral(&split.collateral_token, &split.parent_collection_id, &split.partition, outcome_count(&split.condition_id)) {
+            continue;
+        }
+        block.get_or_insert((split.evt_block_time, split.evt_block_number));
+        let tally = tally_for(&mut tallies, &split.condition_id);
+        if split.stakeholder == exchange {
+            tally.exchange_minted = tally.exchange_minted.clone() + parse_amount(&split.amount);
+        } else {
+            tally.split = tally.split.clone() + parse_amount(&split.amount);
+        }
+    }
+    for merge in &events.ctf_positions_merges {
+        if !moves_collateral(&merge.collateral_token, &merge.parent_collection_id, &merge.partition, outcome_count(&merge.condition_id)) {
+            continue;
+        }
+        block.get_or_insert((merge.evt_block_time, merge.evt_block_number));
+        let tally = tally_for(&mut tallies, &merge.condition_id);
+        if merge.stakeholder == exchange {
+            tally.exchange_merged = tally.exchange_merged.clone() + parse_amount(&merge.amount);
+        } else {
+            tally.merged = tally.merged.clone() + parse_amount(&merge.amount);
+        }
+    }
+    for redemption in &events.ctf_payout_redemptions {
+        if redemption.collateral_token != ctf::EXCHANGE_COLLATERAL
+            || !is_zero(&redemption.parent_collection_id)
+            || outcome_count(&redemption.condition_id) == 0
+        {
+            continue;
+        }
+        block.get_or_insert((redemption.evt_block_time, redemption.evt_block_number));
+        let tally = tally_for(&mut tallies, &redemption.condition_id);
+        tally.redeemed = tally.redeemed.clone() + parse_amount(&redemption.payout);
+    }
+
+    let (block_time, block_number): (Option<Timestamp>, u64) = block.unwrap_or_default();
+    let changes = tallies
+        .into_iter()
+        .map(|tally| {
+            let delta = tally.split.clone() + tally.exchange_minted.clone()
+                - tally.merged.clone()
+                - tally.exchange_merged.clone()
+                - tally.redeemed.clone();
+            contract::OpenInterestChange {
+                condition_id: tally.condition_id,
+                block_time,
+                block_number,
+                split: tally.split.to_string(),
+                merged: tally.merged.to_string(),
+                exchange_minted: tally.exchange_minted.to_string(),
+                exchange_merged: tally.exchange_merged.to_string(),
+                redeemed: tally.redeemed.to_string(),
+                delta: delta.to_string(),
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    contract::OpenInterestChanges { changes }
+}
+
+/// Key of a market's open interest in `store_open_interest`.
+pub fn open_interest_key(condition_id: &[u8]) -> String {
+    format!("market:{}:open_interest", substreams::Hex(condition_id))
+}
+
+/// Whether a split or merge turns collateral into complete sets or back: top-level, on the
+/// exchange collateral, and over a partition covering every outcome.
+fn moves_collateral(collateral: &[u8], parent_collection_id: &[u8], partition: &[String], outcome_count: usize) -> bool {
+    if collateral != ctf::EXCHANGE_COLLATERAL || !is_zero(parent_collection_id) || outcome_count == 0 {
+        return false;
+    }
+
+    let full = BigInt::from((1u64 << outcome_count.min(63)) - 1);
+    partition.iter().fold(BigInt::zero(), |union, index_set| union + parse_amount(index_set)) == full
+}
+
+fn tally_for<'a>(tallies: &'a mut Vec<Tally>, condition_id: &[u8]) -> &'a mut Tally {
+    let index = match tallies.iter().position(|t| t.condition_id == condition_id) {
+        Some(index) => index,
+        None => {
+            tallies.push(Tally {
+                condition_id: condition_id.to_vec(),
+                ..Default::default()
+            });
+            tallies.len() - 1
+        }
+    };
+    &mut tallies[index]
+}
+
+fn is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().all(|b| *b == 0)
+}
@@ -0,0 +1,119 @@
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{parse_amount, COLLATERAL_ASSET_ID};
+use std::collections::HashMap;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+#[derive(Default)]
+struct BatchTally {
+    batch: contract::OperatorBatch,
+    succeeded: bool,
+    notional: BigInt,
+    collateral_fees: BigInt,
+    outcome_token_fees: BigInt,
+}
+
+/// Summarizes every transaction submitting trading calls to the exchange, in execution
+/// order. The batches come from the block's transaction traces, so failed transactions are
+/// kept with their gas; a batch is reverted when its transaction failed or none of its calls
+/// succeeded. A matched trade is counted once: the `OrderFilled` of a `matchOrders` taker
+/// order (whose taker is the exchange itself) is left out of the notional.
+pub fn operator_batches(blk: &eth::Block, events: &contract::Events, exchange: &[u8]) -> contract::OperatorBatches {
+    let mut txs: HashMap<String, BatchTally> = HashMap::new();
+
+    for tx in &blk.transaction_traces {
+        for call in &tx.calls {
+            let Some((name, makers)) = trading_call(call) else {
+                continue;
+            };
+            let tally = txs.entry(Hex(&tx.hash).to_string()).or_insert_with(|| BatchTally {
+                batch: contract::OperatorBatch {
+                    tx_hash: Hex(&tx.hash).to_string(),
+                    tx_index: tx.index,
+                    block_time: Some(blk.timestamp().to_owned()),
+                    block_number: blk.number,
+                    operator: call.caller.clone(),
+                    gas_used: tx.gas_used,
+                    gas_price: tx.gas_price.as_ref().map(|p| BigInt::from_unsigned_bytes_be(&p.bytes).to_string()).unwrap_or_default(),
+                    reverted: tx.status != eth::TransactionTraceStatus::Succeeded as i32,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+            if name == "matchOrders" {
+                tally.batch.match_orders_calls += 1;
+            } else {
+                tally.batch.fill_orders_calls += 1;
+            }
+            if !call.state_reverted {
+                tally.succeeded = true;
+                tally.batch.makers_matched += makers;
+            }
+        }
+    }
+
+    for fill in &events.polymarketctf_order_filleds {
+        let Some(tally) = txs.get_mut(fill.evt_tx_hash.as_str()) else {
+            continue;
+        };
+        if fill.taker == exchange {
+            continue;
+        }
+        if fill.maker_asset_id == COLLATERAL_ASSET_ID {
+            tally.notional = tally.notional.clone() + parse_amount(&fill.maker_amount_filled);
+        } else if fill.taker_asset_id == COLLATERAL_ASSET_ID {
+            tally.notional = tally.notional.clone() + parse_amount(&fill.taker_amount_filled);
+        }
+    }
+
+    for fee in &events.polymarketctf_fee_chargeds {
+        let Some(tally) = txs.get_mut(fee.evt_tx_hash.as_str()) else {
+            continue;
+        };
+        if fee.token_id == COLLATERAL_ASSET_ID {
+            tally.collateral_fees = tally.collateral_fees.clone() + parse_amount(&fee.amount);
+        } else {
+            tally.outcome_token_fees = tally.outcome_token_fees.clone() + parse_amount(&fee.amount);
+        }
+    }
+
+    let mut batches: Vec<contract::OperatorBatch> = txs
+        .into_values()
+        .map(|tally| {
+            let mut batch = tally.batch;
+            batch.reverted |= !tally.succeeded;
+            batch.notional = tally.notional.to_string();
+            batch.gas_cost = gas_cost_wei(&batch).to_decimal(18).to_string();
+            batch.collateral_fees = tally.collateral_fees.to_string();
+            batch.outcome_token_fees = tally.outcome_token_fees.to_string();
+            batch
+        })
+        .collect();
+    batches.sort_by_key(|b| b.tx_index);
+
+    contract::OperatorBatches { batches }
+}
+
+/// The name of a `fillOrder`, `fillOrders` or `matchOrders` call to the exchange, reverted
+/// ones included, and the number of maker orders it fills.
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn trading_call(call: &eth::Call) -> Option<(&'static str, u32)> {
+    match nooroo_core::decode_call(call)? {
+        nooroo_core::ExchangeCall::FillOrder(_) => Some(("fillOrder", 1)),
+        nooroo_core::ExchangeCall::FillOrders(decoded) => Some(("fillOrders", decoded.orders.len() as u32)),
+        nooroo_core::ExchangeCall::MatchOrders(decoded) => Some(("matchOrders", decoded.maker_orders.len() as u32)),
+        _ => None,
+    }
+}
+
+/// Without the trading calls decoded, no transaction is a batch.
+#[cfg(not(all(feature = "calls", feature = "trading")))]
+fn trading_call(_call: &eth::Call) -> Option<(&'static str, u32)> {
+    None
+}
+
+/// Gas paid by the batch transaction, in wei.
+pub fn gas_cost_wei(batch: &contract::OperatorBatch) -> BigInt {
+    BigInt::from(batch.gas_used) * parse_amount(&batch.gas_price)
+}
@@ -1,629 +0,0 @@
-// @generated
-// This file is @generated by prost-build.
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Events {
-    #[prost(message, repeated, tag="1")]
-    pub polymarketctf_fee_chargeds: ::prost::alloc::vec::Vec<PolymarketctfFeeCharged>,
-    #[prost(message, repeated, tag="2")]
-    pub polymarketctf_new_admins: ::prost::alloc::vec::Vec<PolymarketctfNewAdmin>,
-    #[prost(message, repeated, tag="3")]
-    pub polymarketctf_new_operators: ::prost::alloc::vec::Vec<PolymarketctfNewOperator>,
-    #[prost(message, repeated, tag="4")]
-    pub polymarketctf_order_cancelleds: ::prost::alloc::vec::Vec<PolymarketctfOrderCancelled>,
-    #[prost(message, repeated, tag="5")]
-    pub polymarketctf_order_filleds: ::prost::alloc::vec::Vec<PolymarketctfOrderFilled>,
-    #[prost(message, repeated, tag="6")]
-    pub polymarketctf_orders_matcheds: ::prost::alloc::vec::Vec<PolymarketctfOrdersMatched>,
-    #[prost(message, repeated, tag="7")]
-    pub polymarketctf_proxy_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfProxyFactoryUpdated>,
-    #[prost(message, repeated, tag="8")]
-    pub polymarketctf_removed_admins: ::prost::alloc::vec::Vec<PolymarketctfRemovedAdmin>,
-    #[prost(message, repeated, tag="9")]
-    pub polymarketctf_removed_operators: ::prost::alloc::vec::Vec<PolymarketctfRemovedOperator>,
-    #[prost(message, repeated, tag="10")]
-    pub polymarketctf_safe_factory_updateds: ::prost::alloc::vec::Vec<PolymarketctfSafeFactoryUpdated>,
-    #[prost(message, repeated, tag="11")]
-    pub polymarketctf_token_registereds: ::prost::alloc::vec::Vec<PolymarketctfTokenRegistered>,
-    #[prost(message, repeated, tag="12")]
-    pub polymarketctf_trading_pauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingPaused>,
-    #[prost(message, repeated, tag="13")]
-    pub polymarketctf_trading_unpauseds: ::prost::alloc::vec::Vec<PolymarketctfTradingUnpaused>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Calls {
-    #[prost(message, repeated, tag="1")]
-    pub polymarketctf_call_add_admins: ::prost::alloc::vec::Vec<PolymarketctfAddAdminCall>,
-    #[prost(message, repeated, tag="2")]
-    pub polymarketctf_call_add_operators: ::prost::alloc::vec::Vec<PolymarketctfAddOperatorCall>,
-    #[prost(message, repeated, tag="3")]
-    pub polymarketctf_call_cancel_orders: ::prost::alloc::vec::Vec<PolymarketctfCancelOrderCall>,
-    /// FIXED: Different field name
-    #[prost(message, repeated, tag="4")]
-    pub polymarketctf_call_cancel_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfCancelOrdersCall>,
-    #[prost(message, repeated, tag="5")]
-    pub polymarketctf_call_fill_orders: ::prost::alloc::vec::Vec<PolymarketctfFillOrderCall>,
-    /// FIXED: Different field name
-    #[prost(message, repeated, tag="6")]
-    pub polymarketctf_call_fill_orders_batch: ::prost::alloc::vec::Vec<PolymarketctfFillOrdersCall>,
-    #[prost(message, repeated, tag="7")]
-    pub polymarketctf_call_increment_nonces: ::prost::alloc::vec::Vec<PolymarketctfIncrementNonceCall>,
-    #[prost(message, repeated, tag="8")]
-    pub polymarketctf_call_match_orders: ::prost::alloc::vec::Vec<PolymarketctfMatchOrdersCall>,
-    #[prost(message, repeated, tag="9")]
-    pub polymarketctf_call_on_erc1155_batch_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155BatchReceivedCall>,
-    #[prost(message, repeated, tag="10")]
-    pub polymarketctf_call_on_erc1155_receiveds: ::prost::alloc::vec::Vec<PolymarketctfOnErc1155ReceivedCall>,
-    #[prost(message, repeated, tag="11")]
-    pub polymarketctf_call_pause_tradings: ::prost::alloc::vec::Vec<PolymarketctfPauseTradingCall>,
-    #[prost(message, repeated, tag="12")]
-    pub polymarketctf_call_register_tokens: ::prost::alloc::vec::Vec<PolymarketctfRegisterTokenCall>,
-    #[prost(message, repeated, tag="13")]
-    pub polymarketctf_call_remove_admins: ::prost::alloc::vec::Vec<PolymarketctfRemoveAdminCall>,
-    #[prost(message, repeated, tag="14")]
-    pub polymarketctf_call_remove_operators: ::prost::alloc::vec::Vec<PolymarketctfRemoveOperatorCall>,
-    #[prost(message, repeated, tag="15")]
-    pub polymarketctf_call_renounce_admin_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceAdminRoleCall>,
-    #[prost(message, repeated, tag="16")]
-    pub polymarketctf_call_renounce_operator_roles: ::prost::alloc::vec::Vec<PolymarketctfRenounceOperatorRoleCall>,
-    #[prost(message, repeated, tag="17")]
-    pub polymarketctf_call_set_proxy_factories: ::prost::alloc::vec::Vec<PolymarketctfSetProxyFactoryCall>,
-    #[prost(message, repeated, tag="18")]
-    pub polymarketctf_call_set_safe_factories: ::prost::alloc::vec::Vec<PolymarketctfSetSafeFactoryCall>,
-    #[prost(message, repeated, tag="19")]
-    pub polymarketctf_call_unpause_tradings: ::prost::alloc::vec::Vec<PolymarketctfUnpauseTradingCall>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EventsCalls {
-    #[prost(message, optional, tag="1")]
-    pub events: ::core::option::Option<Events>,
-    #[prost(message, optional, tag="2")]
-    pub calls: ::core::option::Option<Calls>,
-}
-/// EVENT MESSAGES
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfFeeCharged {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub receiver: ::prost::alloc::vec::Vec<u8>,
-    #[prost(string, tag="6")]
-    pub token_id: ::prost::alloc::string::String,
-    #[prost(string, tag="7")]
-    pub amount: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfNewAdmin {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub new_admin_address: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfNewOperator {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub new_operator_address: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfOrderCancelled {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub order_hash: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfOrderFilled {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub order_hash: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub maker: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="7")]
-    pub taker: ::prost::alloc::vec::Vec<u8>,
-    #[prost(string, tag="8")]
-    pub maker_asset_id: ::prost::alloc::string::String,
-    #[prost(string, tag="9")]
-    pub taker_asset_id: ::prost::alloc::string::String,
-    #[prost(string, tag="10")]
-    pub maker_amount_filled: ::prost::alloc::string::String,
-    #[prost(string, tag="11")]
-    pub taker_amount_filled: ::prost::alloc::string::String,
-    #[prost(string, tag="12")]
-    pub fee: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfOrdersMatched {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub taker_order_hash: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub taker_order_maker: ::prost::alloc::vec::Vec<u8>,
-    #[prost(string, tag="7")]
-    pub maker_asset_id: ::prost::alloc::string::String,
-    #[prost(string, tag="8")]
-    pub taker_asset_id: ::prost::alloc::string::String,
-    #[prost(string, tag="9")]
-    pub maker_amount_filled: ::prost::alloc::string::String,
-    #[prost(string, tag="10")]
-    pub taker_amount_filled: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfProxyFactoryUpdated {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub old_proxy_factory: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub new_proxy_factory: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRemovedAdmin {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub removed_admin: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRemovedOperator {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub removed_operator: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfSafeFactoryUpdated {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub old_safe_factory: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="6")]
-    pub new_safe_factory: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfTokenRegistered {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(string, tag="5")]
-    pub token0: ::prost::alloc::string::String,
-    #[prost(string, tag="6")]
-    pub token1: ::prost::alloc::string::String,
-    #[prost(bytes="vec", tag="7")]
-    pub condition_id: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfTradingPaused {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub pauser: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfTradingUnpaused {
-    #[prost(string, tag="1")]
-    pub evt_tx_hash: ::prost::alloc::string::String,
-    #[prost(uint32, tag="2")]
-    pub evt_index: u32,
-    #[prost(message, optional, tag="3")]
-    pub evt_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="4")]
-    pub evt_block_number: u64,
-    #[prost(bytes="vec", tag="5")]
-    pub pauser: ::prost::alloc::vec::Vec<u8>,
-}
-/// CALL MESSAGES
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfAddAdminCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfAddOperatorCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub operator: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfCancelOrderCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfCancelOrdersCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfFillOrderCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(string, tag="6")]
-    pub fill_amount: ::prost::alloc::string::String,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfFillOrdersCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(string, repeated, tag="6")]
-    pub fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfIncrementNonceCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfMatchOrdersCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(string, tag="6")]
-    pub taker_fill_amount: ::prost::alloc::string::String,
-    #[prost(string, repeated, tag="7")]
-    pub maker_fill_amounts: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfOnErc1155BatchReceivedCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub param0: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="7")]
-    pub param1: ::prost::alloc::vec::Vec<u8>,
-    #[prost(string, repeated, tag="8")]
-    pub param2: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-    #[prost(string, repeated, tag="9")]
-    pub param3: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
-    #[prost(bytes="vec", tag="10")]
-    pub param4: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="11")]
-    pub output_param0: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfOnErc1155ReceivedCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub param0: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="7")]
-    pub param1: ::prost::alloc::vec::Vec<u8>,
-    #[prost(string, tag="8")]
-    pub param2: ::prost::alloc::string::String,
-    #[prost(string, tag="9")]
-    pub param3: ::prost::alloc::string::String,
-    #[prost(bytes="vec", tag="10")]
-    pub param4: ::prost::alloc::vec::Vec<u8>,
-    #[prost(bytes="vec", tag="11")]
-    pub output_param0: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfPauseTradingCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRegisterTokenCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(string, tag="6")]
-    pub token: ::prost::alloc::string::String,
-    #[prost(string, tag="7")]
-    pub complement: ::prost::alloc::string::String,
-    #[prost(bytes="vec", tag="8")]
-    pub condition_id: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRemoveAdminCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub admin: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRemoveOperatorCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub operator: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRenounceAdminRoleCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfRenounceOperatorRoleCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfSetProxyFactoryCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub u_new_proxy_factory: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfSetSafeFactoryCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-    #[prost(bytes="vec", tag="6")]
-    pub u_new_safe_factory: ::prost::alloc::vec::Vec<u8>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct PolymarketctfUnpauseTradingCall {
-    #[prost(string, tag="1")]
-    pub call_tx_hash: ::prost::alloc::string::String,
-    #[prost(message, optional, tag="2")]
-    pub call_block_time: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(uint64, tag="3")]
-    pub call_block_number: u64,
-    #[prost(uint64, tag="4")]
-    pub call_ordinal: u64,
-    #[prost(bool, tag="5")]
-    pub call_success: bool,
-}
-// @@protoc_insertion_point(module)
@@ -0,0 +1,132 @@
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::parse_amount;
+use std::collections::HashMap;
+use substreams::store::{DeltaBigInt, Deltas};
+use substreams::Hex;
+
+/// Stands for the other side of mints and burns, it holds no position itself.
+pub const ZERO_ADDRESS: [u8; 20] = [0; 20];
+
+/// Splits every CTF `TransferSingle` / `TransferBatch` of the block into signed per-holder
+/// changes, in execution order. Splits, merges, redemptions, exchange fills and wallet to
+/// wallet transfers all move positions through these two events.
+pub fn position_changes(events: &contract::Events) -> contract::PositionChanges {
+    let mut changes = Vec::new();
+
+    for transfer in &events.ctf_transfer_singles {
+        let base = contract::PositionChange {
+            tx_hash: transfer.evt_tx_hash.clone(),
+            block_time: transfer.evt_block_time,
+            block_number: transfer.evt_block_number,
+            ordinal: transfer.evt_ordinal,
+            operator: transfer.operator.clone(),
+            ..Default::default()
+        };
+        push_transfer(&mut changes, &base, &transfer.from, &transfer.to, &transfer.id, &transfer.value);
+    }
+    for transfer in &events.ctf_transfer_batches {
+        let base = contract::PositionChange {
+            tx_hash: transfer.evt_tx_hash.clone(),
+            block_time: transfer.evt_block_time,
+            block_number: transfer.evt_block_number,
+            ordinal: transfer.evt_ordinal,
+            operator: transfer.operator.clone(),
+            ..Default::default()
+        };
+        for (id, value) in transfer.ids.iter().zip(&transfer.values) {
+            push_transfer(&mut changes, &base, &transfer.from, &transfer.to, id, value);
+        }
+    }
+
+    // Stable, so the positions of a batch keep their order.
+    changes.sort_by_key(|c| c.ordinal);
+
+    contract::PositionChanges { changes }
+}
+
+fn push_transfer(
+    changes: &mut Vec<contract::PositionChange>,
+    base: &contract::PositionChange,
+    from: &[u8],
+    to: &[u8],
+    position_id: &str,
+    value: &str,
+) {
+    let amount = parse_amount(value);
+    if amount.is_zero() {
+        return;
+    }
+
+    if from != ZERO_ADDRESS {
+        changes.push(contract::PositionChange {
+            holder: from.to_vec(),
+            position_id: position_id.to_string(),
+            delta: amount.neg().to_string(),
+            counterparty: to.to_vec(),
+            ..base.clone()
+        });
+    }
+    if to != ZERO_ADDRESS {
+        changes.push(contract::PositionChange {
+            holder: to.to_vec(),
+            position_id: position_id.to_string(),
+            delta: amount.to_string(),
+            counterparty: from.to_vec(),
+            ..base.clone()
+        });
+    }
+}
+
+/// Key of a holder's balance of a position in `store_position_balances`.
+pub fn balance_key(holder: &[u8], position_id: &str) -> String {
+    format!("position:{}:{}", Hex(holder), position_id)
+}
+
+/// Key of the outstanding supply of a position in `store_position_balances`, moved by mints and
+/// burns only.
+pub fn supply_key(position_id: &str) -> String {
+    format!("supply:{}", position_id)
+}
+
+/// Key of the holders of a position in `store_position_holders`, each listed when their balance
+/// goes up from zero, so a holder that left and came back is listed again.
+pub fn holders_key(position_id: &str) -> String {
+    format!("holders:{}", position_id)
+}
+
+/// One balance per holder and position changed in the block, from the balance before the
+/// block to the one after its last change.
+pub fn position_balances(deltas: &Deltas<DeltaBigInt>) -> contract::PositionBalances {
+    let mut balances: Vec<contract::PositionBalance> = Vec::new();
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    for delta in deltas.iter() {
+        if let Some(&index) = seen.get(delta.key.as_str()) {
+            let balance = &mut balances[index];
+            balance.new_balance = delta.new_value.to_string();
+            balance.ordinal = delta.ordinal;
+            continue;
+        }
+
+        let Some((holder, position_id)) = parse_key(&delta.key) else {
+            continue;
+        };
+        seen.insert(&delta.key, balances.len());
+        balances.push(contract::PositionBalance {
+            holder,
+            position_id,
+            old_balance: delta.old_value.to_string(),
+            new_balance: delta.new_value.to_string(),
+            ordinal: delta.ordinal,
+        });
+    }
+
+    contract::PositionBalances { balances }
+}
+
+fn parse_key(key: &str) -> Option<(Vec<u8>, String)> {
+    let mut parts = key.strip_prefix("position:")?.splitn(2, ':');
+    let holder = Hex::decode(parts.next()?).ok()?;
+    let position_id = parts.next()?.to_string();
+    Some((holder, position_id))
+}
@@ -0,0 +1,108 @@
+use anyhow::{anyhow, Context};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price;
+use std::str::FromStr;
+use substreams::scalar::BigDecimal;
+
+/// Tolerance used when the params leave it out.
+pub const DEFAULT_TOLERANCE: &str = "0.02";
+
+/// Parses the `tolerance=<decimal>` params of `map_implied_probabilities`: how far from 1
+/// the last prices of a market's two tokens may sum before it is reported.
+pub fn parse_tolerance(params: &str) -> Result<BigDecimal, substreams::errors::Error> {
+    let mut tolerance = BigDecimal::from_str(DEFAULT_TOLERANCE).unwrap();
+
+    for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+        match key.trim() {
+            "tolerance" => {
+                tolerance = BigDecimal::from_str(value.trim())
+                    .with_context(|| format!("invalid tolerance {:?}", value))?;
+            }
+            other => return Err(anyhow!("unknown param {:?}", other)),
+        }
+    }
+
+    Ok(tolerance)
+}
+
+/// Last prices of the markets traded in the block. `market` gives the condition and registered
+/// tokens of an outcome token, `last_price` the price of the last fill of a token, this block's
+/// fills included.
+pub fn implied_probabilities<M, P>(
+    events: &contract::Events,
+    tolerance: &BigDecimal,
+    market: M,
+    last_price: P,
+) -> contract::ImpliedProbabilities
+where
+    M: Fn(&str) -> Option<(Vec<u8>, [String; 2])>,
+    P: Fn(&str) -> Option<BigDecimal>,
+{
+    let mut fills: Vec<&contract::PolymarketctfOrderFilled> = events.polymarketctf_order_filleds.iter().collect();
+    fills.sort_by_key(|f| f.evt_ordinal);
+
+    let mut markets: Vec<(Vec<u8>, [String; 2])> = Vec::new();
+    let mut block = None;
+    for fill in fills {
+        let Some(token_id) = price::fill(fill).map(|f| f.token_id) else {
+            continue;
+        };
+        let Some(market) = market(&token_id) else {
+            continue;
+        };
+        block.get_or_insert((fill.evt_block_time, fill.evt_block_number));
+        if !markets.iter().any(|(condition_id, _)| *condition_id == market.0) {
+            markets.push(market);
+        }
+    }
+
+    let (block_time, block_number) = block.unwrap_or_default();
+    let mut probabilities = contract::ImpliedProbabilities::default();
+    for (condition_id, [token0, token1]) in markets {
+        let price0 = last_price(&token0);
+        let price1 = last_price(&token1);
+        let (probability0, probability1) = match (&price0, &price1) {
+            (Some(p0), Some(p1)) if !(p0.clone() + p1.clone()).is_zero() => {
+                let sum = p0.clone() + p1.clone();
+                (p0.clone() / &sum, p1.clone() / &sum)
+            }
+            (Some(p0), None) => (p0.clone(), BigDecimal::one() - p0.clone()),
+            (None, Some(p1)) => (BigDecimal::one() - p1.clone(), p1.clone()),
+            _ => continue,
+        };
+
+        if let (Some(p0), Some(p1)) = (&price0, &price1) {
+            let sum = p0.clone() + p1.clone();
+            if (sum.clone() - BigDecimal::one()).absolute() > *tolerance {
+                probabilities.inconsistencies.push(contract::PriceInconsistency {
+                    condition_id: condition_id.clone(),
+                    block_time,
+                    block_number,
+                    token0: token0.clone(),
+                    token1: token1.clone(),
+                    price0: p0.to_string(),
+                    price1: p1.to_string(),
+                    sum: sum.to_string(),
+                    tolerance: tolerance.to_string(),
+                });
+            }
+        }
+
+        probabilities.probabilities.push(contract::ImpliedProbability {
+            condition_id,
+            block_time,
+            block_number,
+            token0,
+            token1,
+            price0: price0.map(|p| p.to_string()).unwrap_or_default(),
+            price1: price1.map(|p| p.to_string()).unwrap_or_default(),
+            probability0: probability0.to_string(),
+            probability1: probability1.to_string(),
+        });
+    }
+
+    probabilities
+}
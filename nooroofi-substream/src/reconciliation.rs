@@ -0,0 +1,152 @@
+use nooroo_core::pb::contract::v1 as contract;
+use contract::reconciliation_anomaly::Kind;
+use std::collections::BTreeMap;
+use substreams::Hex;
+
+/// What the successful exchange calls of one transaction should have emitted, next to
+/// what `map_events` actually decoded for it.
+#[derive(Default)]
+struct TxTally<'a> {
+    expected_fills: u32,
+    expected_matches: u32,
+    expected_cancels: u32,
+    filled_makers: Vec<&'a [u8]>,
+    matched_takers: Vec<&'a [u8]>,
+    fills: Vec<&'a contract::PolymarketctfOrderFilled>,
+    matches: Vec<&'a contract::PolymarketctfOrdersMatched>,
+    cancels: u32,
+    fees: Vec<&'a contract::PolymarketctfFeeCharged>,
+}
+
+/// Checks every transaction touching the exchange against the invariants of its calls:
+/// one `OrderFilled` per order filled by `fillOrder`/`fillOrders`, one `OrdersMatched`
+/// plus N+1 `OrderFilled` per `matchOrders`, one `OrderCancelled` per cancelled order,
+/// and an `OrderFilled` carrying the fee of every `FeeCharged`.
+pub fn reconcile(events: &contract::Events, calls: &contract::Calls) -> contract::Reconciliation {
+    let mut block_number = 0;
+    let mut txs: BTreeMap<&str, TxTally> = BTreeMap::new();
+
+    for call in calls.polymarketctf_call_fill_orders.iter().filter(|c| c.call_success) {
+        block_number = call.call_block_number;
+        let tally = txs.entry(&call.call_tx_hash).or_default();
+        tally.expected_fills += 1;
+        tally.filled_makers.extend(call.order.iter().map(|o| o.maker.as_slice()));
+    }
+    for call in calls.polymarketctf_call_fill_orders_batch.iter().filter(|c| c.call_success) {
+        block_number = call.call_block_number;
+        let tally = txs.entry(&call.call_tx_hash).or_default();
+        tally.expected_fills += call.orders.len() as u32;
+        tally.filled_makers.extend(call.orders.iter().map(|o| o.maker.as_slice()));
+    }
+    for call in calls.polymarketctf_call_match_orders.iter().filter(|c| c.call_success) {
+        block_number = call.call_block_number;
+        let tally = txs.entry(&call.call_tx_hash).or_default();
+        tally.expected_fills += call.maker_orders.len() as u32 + 1;
+        tally.expected_matches += 1;
+        tally.filled_makers.extend(call.maker_orders.iter().map(|o| o.maker.as_slice()));
+        if let Some(taker_order) = &call.taker_order {
+            tally.filled_makers.push(&taker_order.maker);
+            tally.matched_takers.push(&taker_order.maker);
+        }
+    }
+    for call in calls.polymarketctf_call_cancel_orders.iter().filter(|c| c.call_success) {
+        block_number = call.call_block_number;
+        txs.entry(&call.call_tx_hash).or_default().expected_cancels += 1;
+    }
+    for call in calls.polymarketctf_call_cancel_orders_batch.iter().filter(|c| c.call_success) {
+        block_number = call.call_block_number;
+        txs.entry(&call.call_tx_hash).or_default().expected_cancels += call.orders.len() as u32;
+    }
+
+    for event in &events.polymarketctf_order_filleds {
+        block_number = event.evt_block_number;
+        txs.entry(&event.evt_tx_hash).or_default().fills.push(event);
+    }
+    for event in &events.polymarketctf_orders_matcheds {
+        block_number = event.evt_block_number;
+        txs.entry(&event.evt_tx_hash).or_default().matches.push(event);
+    }
+    for event in &events.polymarketctf_order_cancelleds {
+        block_number = event.evt_block_number;
+        txs.entry(&event.evt_tx_hash).or_default().cancels += 1;
+    }
+    for event in &events.polymarketctf_fee_chargeds {
+        block_number = event.evt_block_number;
+        txs.entry(&event.evt_tx_hash).or_default().fees.push(event);
+    }
+
+    let mut anomalies = Vec::new();
+    for (tx_hash, tally) in &txs {
+        check_tx(tx_hash, tally, &mut anomalies);
+    }
+
+    contract::Reconciliation {
+        block_number,
+        transactions_checked: txs.len() as u32,
+        anomalies,
+    }
+}
+
+fn check_tx(tx_hash: &str, tally: &TxTally, anomalies: &mut Vec<contract::ReconciliationAnomaly>) {
+    let mut anomaly = |kind: Kind, expected: u32, actual: u32, detail: String| {
+        anomalies.push(contract::ReconciliationAnomaly {
+            tx_hash: tx_hash.to_string(),
+            kind: kind as i32,
+            expected,
+            actual,
+            detail,
+        })
+    };
+
+    let fills = tally.fills.len() as u32;
+    if fills != tally.expected_fills {
+        anomaly(Kind::OrderFilledCountMismatch, tally.expected_fills, fills, String::new());
+    }
+    let matches = tally.matches.len() as u32;
+    if matches != tally.expected_matches {
+        anomaly(Kind::OrdersMatchedCountMismatch, tally.expected_matches, matches, String::new());
+    }
+    if tally.cancels != tally.expected_cancels {
+        anomaly(Kind::OrderCancelledCountMismatch, tally.expected_cancels, tally.cancels, String::new());
+    }
+
+    let mut fill_makers: Vec<&[u8]> = tally.fills.iter().map(|e| e.maker.as_slice()).collect();
+    for maker in &tally.filled_makers {
+        if !take(&mut fill_makers, |m| m == maker) {
+            anomaly(Kind::OrderFilledMakerMismatch, 1, 0, format!("maker 0x{}", Hex(maker)));
+        }
+    }
+
+    let mut match_takers: Vec<&[u8]> = tally.matches.iter().map(|e| e.taker_order_maker.as_slice()).collect();
+    for taker in &tally.matched_takers {
+        if !take(&mut match_takers, |t| t == taker) {
+            anomaly(Kind::OrdersMatchedTakerMismatch, 1, 0, format!("taker order maker 0x{}", Hex(taker)));
+        }
+    }
+
+    let mut charged_fills = tally.fills.clone();
+    for fee in &tally.fees {
+        let paid_by_fill = take(&mut charged_fills, |fill| {
+            fill.fee == fee.amount && (fill.maker_asset_id == fee.token_id || fill.taker_asset_id == fee.token_id)
+        });
+        if !paid_by_fill {
+            anomaly(
+                Kind::UnmatchedFeeCharged,
+                1,
+                0,
+                format!("fee {} on token {} to 0x{}", fee.amount, fee.token_id, Hex(&fee.receiver)),
+            );
+        }
+    }
+}
+
+/// Removes the first item matching `predicate`, returning whether one was found.
+fn take<T>(items: &mut Vec<T>, predicate: impl Fn(&T) -> bool) -> bool {
+    match items.iter().position(predicate) {
+        Some(index) => {
+            items.swap_remove(index);
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,68 @@
+use nooroo_core::pb::contract::v1 as contract;
+use contract::exchange_record::Body;
+
+macro_rules! push_events {
+    ($records:ident, $events:expr, $body:path) => {
+        $records.extend($events.into_iter().map(|e| contract::ExchangeRecord {
+            ordinal: e.evt_ordinal,
+            block_number: e.evt_block_number,
+            block_hash: e.evt_block_hash.clone(),
+            tx_hash: e.evt_tx_hash.clone(),
+            body: Some($body(e)),
+        }))
+    };
+}
+
+macro_rules! push_calls {
+    ($records:ident, $calls:expr, $body:path) => {
+        $records.extend($calls.into_iter().map(|c| contract::ExchangeRecord {
+            ordinal: c.call_ordinal,
+            block_number: c.call_block_number,
+            block_hash: c.call_block_hash.clone(),
+            tx_hash: c.call_tx_hash.clone(),
+            body: Some($body(c)),
+        }))
+    };
+}
+
+/// Merges the events and calls of a block into a single list in execution order.
+pub fn exchange_records(events: contract::Events, calls: contract::Calls) -> contract::ExchangeRecords {
+    let mut records = Vec::new();
+
+    push_events!(records, events.polymarketctf_fee_chargeds, Body::FeeCharged);
+    push_events!(records, events.polymarketctf_new_admins, Body::NewAdmin);
+    push_events!(records, events.polymarketctf_new_operators, Body::NewOperator);
+    push_events!(records, events.polymarketctf_order_cancelleds, Body::OrderCancelled);
+    push_events!(records, events.polymarketctf_order_filleds, Body::OrderFilled);
+    push_events!(records, events.polymarketctf_orders_matcheds, Body::OrdersMatched);
+    push_events!(records, events.polymarketctf_proxy_factory_updateds, Body::ProxyFactoryUpdated);
+    push_events!(records, events.polymarketctf_removed_admins, Body::RemovedAdmin);
+    push_events!(records, events.polymarketctf_removed_operators, Body::RemovedOperator);
+    push_events!(records, events.polymarketctf_safe_factory_updateds, Body::SafeFactoryUpdated);
+    push_events!(records, events.polymarketctf_token_registereds, Body::TokenRegistered);
+    push_events!(records, events.polymarketctf_trading_pauseds, Body::TradingPaused);
+    push_events!(records, events.polymarketctf_trading_unpauseds, Body::TradingUnpaused);
+
+    push_calls!(records, calls.polymarketctf_call_add_admins, Body::AddAdminCall);
+    push_calls!(records, calls.polymarketctf_call_add_operators, Body::AddOperatorCall);
+    push_calls!(records, calls.polymarketctf_call_cancel_orders, Body::CancelOrderCall);
+    push_calls!(records, calls.polymarketctf_call_cancel_orders_batch, Body::CancelOrdersCall);
+    push_calls!(records, calls.polymarketctf_call_fill_orders, Body::FillOrderCall);
+    push_calls!(records, calls.polymarketctf_call_fill_orders_batch, Body::FillOrdersCall);
+    push_calls!(records, calls.polymarketctf_call_increment_nonces, Body::IncrementNonceCall);
+    push_calls!(records, calls.polymarketctf_call_match_orders, Body::MatchOrdersCall);
+    push_calls!(records, calls.polymarketctf_call_on_erc1155_batch_receiveds, Body::OnErc1155BatchReceivedCall);
+    push_calls!(records, calls.polymarketctf_call_on_erc1155_receiveds, Body::OnErc1155ReceivedCall);
+    push_calls!(records, calls.polymarketctf_call_pause_tradings, Body::PauseTradingCall);
+    push_calls!(records, calls.polymarketctf_call_register_tokens, Body::RegisterTokenCall);
+    push_calls!(records, calls.polymarketctf_call_remove_admins, Body::RemoveAdminCall);
+    push_calls!(records, calls.polymarketctf_call_remove_operators, Body::RemoveOperatorCall);
+    push_calls!(records, calls.polymarketctf_call_renounce_admin_roles, Body::RenounceAdminRoleCall);
+    push_calls!(records, calls.polymarketctf_call_renounce_operator_roles, Body::RenounceOperatorRoleCall);
+    push_calls!(records, calls.polymarketctf_call_set_proxy_factories, Body::SetProxyFactoryCall);
+    push_calls!(records, calls.polymarketctf_call_set_safe_factories, Body::SetSafeFactoryCall);
+    push_calls!(records, calls.polymarketctf_call_unpause_tradings, Body::UnpauseTradingCall);
+
+    records.sort_by_key(|r| r.ordinal);
+    contract::ExchangeRecords { records }
+}
@@ -0,0 +1,258 @@
+use nooroo_core::ctf;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::parse_amount;
+use std::collections::{BTreeMap, HashMap};
+use substreams::scalar::BigInt;
+
+/// Share of the collateral an outcome token pays out once its condition is resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payout {
+    pub numerator: BigInt,
+    pub denominator: BigInt,
+}
+
+impl Payout {
+    /// Collateral owed for `amount` outcome tokens, rounded down like `redeemPositions`.
+    pub fn of(&self, amount: &BigInt) -> BigInt {
+        if self.denominator.is_zero() {
+            return BigInt::zero();
+        }
+        amount.clone() * self.numerator.clone() / self.denominator.clone()
+    }
+
+    /// The `<numerator>/<denominator>` form kept in `store_resolutions`.
+    pub fn parse(value: &str) -> Option<Payout> {
+        let (numerator, denominator) = value.split_once('/')?;
+        Some(Payout {
+            numerator: parse_amount(numerator),
+            denominator: parse_amount(denominator),
+        })
+    }
+}
+
+/// What settlement reads from the stores, as of the end of the block unless an ordinal is given.
+pub trait SettlementState {
+    /// Outcome tokens registered on the exchange for a condition, empty for other conditions.
+    fn market_tokens(&self, condition_id: &[u8]) -> Vec<String>;
+    /// Condition an outcome token was registered under.
+    fn market_of(&self, token_id: &str) -> Option<Vec<u8>>;
+    /// Payout of an outcome token, once its condition is resolved.
+    fn payout(&self, token_id: &str) -> Option<Payout>;
+    fn supply(&self, ordinal: Option<u64>, token_id: &str) -> BigInt;
+    fn balance(&self, holder: &[u8], token_id: &str) -> BigInt;
+    /// Every holder an outcome token ever had, including those who hold none of it anymore.
+    fn holders(&self, token_id: &str) -> Vec<Vec<u8>>;
+}
+
+/// Places the registered tokens of a market among the outcomes of its `ConditionResolution`.
+/// Tokens that aren't a single-outcome position of the condition on the exchange collateral
+/// come back in the second list.
+pub fn outcomes(
+    resolution: &contract::CtfConditionResolution,
+    tokens: &[String],
+) -> (Vec<contract::OutcomePayout>, Vec<String>) {
+    let numerators: Vec<BigInt> = resolution.payout_numerators.iter().map(|n| parse_amount(n)).collect();
+    let denominator = numerators.iter().fold(BigInt::zero(), |sum, n| sum + n.clone());
+
+    let mut outcomes = Vec::new();
+    let mut unmatched = Vec::new();
+    for token_id in tokens {
+        let index = ctf::outcome_index(&ctf::EXCHANGE_COLLATERAL, &resolution.condition_id, numerators.len(), token_id);
+        let Some(index) = index else {
+            unmatched.push(token_id.clone());
+            continue;
+        };
+        outcomes.push(contract::OutcomePayout {
+            token_id: token_id.clone(),
+            outcome_index: index as u32,
+            payout_numerator: numerators[index].to_string(),
+            payout_denominator: denominator.to_string(),
+            winning: !numerators[index].is_zero(),
+            ..Default::default()
+        });
+    }
+    outcomes.sort_by_key(|o| o.outcome_index);
+
+    (outcomes, unmatched)
+}
+
+/// Settles the markets of the exchange: resolutions with what each outcome owes, redemptions
+/// checked against the tokens they burned, the redeemable payout of every holder of a market
+/// resolved in the block and of every holder whose positions in a resolved market moved, and
+/// what is left unredeemed on every resolved market that changed.
+pub fn settlements<S: SettlementState>(
+    events: &contract::Events,
+    changes: &contract::PositionChanges,
+    state: &S,
+) -> contract::Settlements {
+    let mut settlements = contract::Settlements::default();
+    let mut touched: Vec<Vec<u8>> = Vec::new();
+    let mut redeemed: HashMap<Vec<u8>, BigInt> = HashMap::new();
+
+    for resolution in &events.ctf_condition_resolutions {
+        let tokens = state.market_tokens(&resolution.condition_id);
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let (mut outcomes, unmatched_token_ids) = outcomes(resolution, &tokens);
+        let mut total_payout = BigInt::zero();
+        for outcome in &mut outcomes {
+            let supply = state.supply(Some(resolution.evt_ordinal), &outcome.token_id);
+            let payout = Payout {
+                numerator: parse_amount(&outcome.payout_numerator),
+                denominator: parse_amount(&outcome.payout_denominator),
+            }
+            .of(&supply);
+            total_payout = total_payout + payout.clone();
+            outcome.supply = supply.to_string();
+            outcome.payout = payout.to_string();
+        }
+
+        settlements.resolutions.push(contract::MarketResolution {
+            condition_id: resolution.condition_id.clone(),
+            tx_hash: resolution.evt_tx_hash.clone(),
+            block_time: resolution.evt_block_time,
+            block_number: resolution.evt_block_number,
+            ordinal: resolution.evt_ordinal,
+            outcomes,
+            unmatched_token_ids,
+            total_payout: total_payout.to_string(),
+        });
+        touch(&mut touched, &resolution.condition_id);
+    }
+
+    let mut redemptions: Vec<&contract::CtfPayoutRedemption> = events.ctf_payout_redemptions.iter().collect();
+    redemptions.sort_by_key(|r| r.evt_ordinal);
+    let mut consumed = vec![false; changes.changes.len()];
+    for redemption in redemptions {
+        let tokens = state.market_tokens(&redemption.condition_id);
+        if tokens.is_empty() {
+            continue;
+        }
+
+        // `redeemPositions` burns the redeemer's tokens right before emitting the event.
+        let mut burned = BigInt::zero();
+        let mut expected_payout = BigInt::zero();
+        for (change, consumed) in changes.changes.iter().zip(consumed.iter_mut()) {
+            if *consumed
+                || change.tx_hash != redemption.evt_tx_hash
+                || change.ordinal > redemption.evt_ordinal
+                || change.holder != redemption.redeemer
+                || !is_zero_address(&change.counterparty)
+                || !tokens.contains(&change.position_id)
+            {
+                continue;
+            }
+            let amount = parse_amount(&change.delta).neg();
+            if amount <= BigInt::zero() {
+                continue;
+            }
+
+            *consumed = true;
+            if let Some(payout) = state.payout(&change.position_id) {
+                expected_payout = expected_payout + payout.of(&amount);
+            }
+            burned = burned + amount;
+        }
+
+        let payout = parse_amount(&redemption.payout);
+        let total = redeemed.entry(redemption.condition_id.clone()).or_insert_with(BigInt::zero);
+        *total = total.clone() + payout.clone();
+
+        settlements.redemptions.push(contract::RedemptionCheck {
+            condition_id: redemption.condition_id.clone(),
+            redeemer: redemption.redeemer.clone(),
+            tx_hash: redemption.evt_tx_hash.clone(),
+            ordinal: redemption.evt_ordinal,
+            burned: burned.to_string(),
+            expected_payout: expected_payout.to_string(),
+            matches: expected_payout == payout,
+            payout: payout.to_string(),
+        });
+        touch(&mut touched, &redemption.condition_id);
+    }
+
+    // By market and holder, the order they are output in.
+    let mut holders: BTreeMap<(Vec<u8>, Vec<u8>), contract::HolderPayout> = BTreeMap::new();
+    for resolution in &settlements.resolutions {
+        for outcome in &resolution.outcomes {
+            // Holders that have sold or redeemed all of it have nothing left to redeem.
+            for holder in state.holders(&outcome.token_id) {
+                if state.balance(&holder, &outcome.token_id) <= BigInt::zero() {
+                    continue;
+                }
+                holders
+                    .entry((resolution.condition_id.clone(), holder.clone()))
+                    .or_insert_with(|| contract::HolderPayout {
+                        condition_id: resolution.condition_id.clone(),
+                        holder,
+                        ordinal: resolution.ordinal,
+                        ..Default::default()
+                    });
+            }
+        }
+    }
+    for change in &changes.changes {
+        if state.payout(&change.position_id).is_none() {
+            continue;
+        }
+        let Some(condition_id) = state.market_of(&change.position_id) else {
+            continue;
+        };
+        if is_zero_address(&change.counterparty) {
+            touch(&mut touched, &condition_id);
+        }
+
+        let holder = holders
+            .entry((condition_id.clone(), change.holder.clone()))
+            .or_insert_with(|| contract::HolderPayout {
+                condition_id,
+                holder: change.holder.clone(),
+                ordinal: change.ordinal,
+                ..Default::default()
+            });
+        holder.ordinal = holder.ordinal.max(change.ordinal);
+    }
+    for holder in holders.values_mut() {
+        let redeemable = state
+            .market_tokens(&holder.condition_id)
+            .iter()
+            .filter_map(|token_id| Some(state.payout(token_id)?.of(&state.balance(&holder.holder, token_id))))
+            .fold(BigInt::zero(), |sum, payout| sum + payout);
+        holder.redeemable = redeemable.to_string();
+    }
+    settlements.holder_payouts = holders.into_values().collect();
+
+    for condition_id in touched {
+        let mut resolved = false;
+        let mut outstanding = BigInt::zero();
+        for token_id in state.market_tokens(&condition_id) {
+            if let Some(payout) = state.payout(&token_id) {
+                resolved = true;
+                outstanding = outstanding + payout.of(&state.supply(None, &token_id));
+            }
+        }
+        if !resolved {
+            continue;
+        }
+
+        settlements.markets.push(contract::MarketSettlement {
+            redeemed: redeemed.remove(&condition_id).unwrap_or_default().to_string(),
+            outstanding: outstanding.to_string(),
+            condition_id,
+        });
+    }
+
+    settlements
+}
+
+fn touch(touched: &mut Vec<Vec<u8>>, condition_id: &[u8]) {
+    if !touched.iter().any(|c| c == condition_id) {
+        touched.push(condition_id.to_vec());
+    }
+}
+
+fn is_zero_address(address: &[u8]) -> bool {
+    address.iter().all(|b| *b == 0)
+}
@@ -0,0 +1,210 @@
+use crate::market_stats::{self, WINDOW_HOURS};
+use anyhow::anyhow;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price;
+use prost_types::Timestamp;
+use std::collections::BTreeSet;
+use std::ops::RangeInclusive;
+use substreams::scalar::BigInt;
+
+/// Windows of `map_token_analytics` when the params leave them out.
+pub const DEFAULT_WINDOWS: &str = "1h,24h,7d";
+/// Days of daily totals kept, the longest `<n>d` window. Hourly totals are kept for
+/// `market_stats::WINDOW_HOURS`, the longest `<n>h` one.
+pub const WINDOW_DAYS: i64 = 30;
+/// Lower bounds of the trade size buckets, in USDC. Trades under the first one fall in a
+/// bucket of their own.
+pub const SIZE_BUCKETS_USDC: [u64; 6] = [1, 10, 100, 1_000, 10_000, 100_000];
+/// Collateral base units per USDC.
+const USDC: u64 = 1_000_000;
+
+/// A window of `map_token_analytics`: the current hour or day and the ones before it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    Hours(i64),
+    Days(i64),
+}
+
+impl Window {
+    /// Parses `<n>h`, up to `market_stats::WINDOW_HOURS`, or `<n>d`, up to `WINDOW_DAYS`.
+    pub fn parse(window: &str) -> Result<Self, substreams::errors::Error> {
+        let invalid = || anyhow!("invalid window {:?}, expected 1h to {}h or 1d to {}d", window, WINDOW_HOURS, WINDOW_DAYS);
+        let count = |count: &str| count.parse::<i64>().map_err(|_| invalid());
+        if let Some(hours) = window.strip_suffix('h') {
+            Some(count(hours)?).filter(|h| (1..=WINDOW_HOURS).contains(h)).map(Window::Hours).ok_or_else(invalid)
+        } else if let Some(days) = window.strip_suffix('d') {
+            Some(count(days)?).filter(|d| (1..=WINDOW_DAYS).contains(d)).map(Window::Days).ok_or_else(invalid)
+        } else {
+            Err(invalid())
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Window::Hours(count) => format!("{}h", count),
+            Window::Days(count) => format!("{}d", count),
+        }
+    }
+
+    /// Key prefixes of the periods of the window ending in `hour` / `day`.
+    fn periods(&self, hour: i64, day: i64) -> Vec<String> {
+        match *self {
+            Window::Hours(count) => (hour - count + 1..=hour).map(|h| format!("hour:{}", h)).collect(),
+            Window::Days(count) => (day - count + 1..=day).map(|d| format!("day:{}", d)).collect(),
+        }
+    }
+}
+
+/// Parses the `windows=<window>,...` params of `map_token_analytics`.
+pub fn parse_windows(params: &str) -> Result<Vec<Window>, substreams::errors::Error> {
+    let mut windows = DEFAULT_WINDOWS;
+
+    for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+        match key.trim() {
+            "windows" => windows = value,
+            other => return Err(anyhow!("unknown param {:?}", other)),
+        }
+    }
+
+    windows.split(',').map(str::trim).filter(|w| !w.is_empty()).map(Window::parse).collect()
+}
+
+/// Days falling out of the daily totals when the last day with fills moves from `previous` to
+/// `current`, as `market_stats::expired_hours` does for hours.
+pub fn expired_days(previous: i64, current: i64) -> RangeInclusive<i64> {
+    // No days are kept before the first one.
+    let last = if previous == 0 { previous - WINDOW_DAYS } else { previous.min(current - WINDOW_DAYS) };
+    previous - WINDOW_DAYS + 1..=last
+}
+
+/// Key of what a period, `hour:<hour>` or `day:<day>`, added to a token's `trades`, `volume`,
+/// `size` or to a size bucket's `bucket:<i>:trades` / `bucket:<i>:volume` in
+/// `store_token_volumes`.
+pub fn period_key(period: &str, token_id: &str, metric: &str) -> String {
+    format!("{}:token:{}:{}", period, token_id, metric)
+}
+
+/// Index of the size bucket of a trade of `notional` collateral.
+pub fn size_bucket(notional: &BigInt) -> usize {
+    SIZE_BUCKETS_USDC
+        .iter()
+        .take_while(|min| *notional >= BigInt::from(**min) * BigInt::from(USDC))
+        .count()
+}
+
+/// A fill of an outcome token.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokenTrade {
+    pub ordinal: u64,
+    pub block_time: Option<Timestamp>,
+    pub block_number: u64,
+    pub token_id: String,
+    pub size: BigInt,
+    pub notional: BigInt,
+}
+
+impl TokenTrade {
+    pub fn hour(&self) -> i64 {
+        market_stats::hour(self.block_time.as_ref())
+    }
+
+    pub fn day(&self) -> i64 {
+        self.block_time.as_ref().map(|t| t.seconds / 86400).unwrap_or_default()
+    }
+
+    /// What the trade adds to the totals of its hour and day in `store_token_volumes`.
+    pub fn volume_changes(&self) -> Vec<(String, BigInt)> {
+        let bucket = size_bucket(&self.notional);
+        let mut changes = Vec::new();
+        for period in [format!("hour:{}", self.hour()), format!("day:{}", self.day())] {
+            let key = |metric: &str| period_key(&period, &self.token_id, metric);
+            changes.push((key("trades"), BigInt::one()));
+            changes.push((key("volume"), self.notional.clone()));
+            changes.push((key("size"), self.size.clone()));
+            changes.push((key(&format!("bucket:{}:trades", bucket)), BigInt::one()));
+            changes.push((key(&format!("bucket:{}:volume", bucket)), self.notional.clone()));
+        }
+        changes
+    }
+}
+
+/// Every fill of the block, in execution order. A matched trade counts once: the fill of a
+/// `matchOrders` taker order, whose taker is the exchange, is left out.
+pub fn token_trades(events: &contract::Events, exchange: &[u8]) -> Vec<TokenTrade> {
+    let mut trades: Vec<TokenTrade> = events
+        .polymarketctf_order_filleds
+        .iter()
+        .filter(|event| event.taker != exchange)
+        .filter_map(|event| {
+            let fill = price::fill(event)?;
+            Some(TokenTrade {
+                ordinal: event.evt_ordinal,
+                block_time: event.evt_block_time,
+                block_number: event.evt_block_number,
+                token_id: fill.token_id,
+                size: fill.size,
+                notional: fill.notional,
+            })
+        })
+        .collect();
+    trades.sort_by_key(|t| t.ordinal);
+    trades
+}
+
+/// One `TokenAnalytics` per token traded in the block, in the order of their first trade.
+/// `total` gives a value of `store_token_volumes` by key after the block.
+pub fn token_analytics<T>(trades: &[TokenTrade], windows: &[Window], total: T) -> contract::TokenAnalyticsUpdates
+where
+    T: Fn(&str) -> BigInt,
+{
+    let mut updates = contract::TokenAnalyticsUpdates::default();
+    let mut seen = BTreeSet::new();
+
+    for trade in trades {
+        if !seen.insert(trade.token_id.as_str()) {
+            continue;
+        }
+        let sum = |periods: &[String], metric: &str| {
+            periods
+                .iter()
+                .fold(BigInt::zero(), |sum, period| sum + total(&period_key(period, &trade.token_id, metric)))
+        };
+
+        let windows = windows
+            .iter()
+            .map(|window| {
+                let periods = &window.periods(trade.hour(), trade.day());
+                let volume = sum(periods, "volume");
+                let size = sum(periods, "size");
+                let size_buckets = (0..=SIZE_BUCKETS_USDC.len())
+                    .map(|i| contract::SizeBucket {
+                        min_usdc: if i == 0 { 0 } else { SIZE_BUCKETS_USDC[i - 1] },
+                        max_usdc: SIZE_BUCKETS_USDC.get(i).copied().unwrap_or_default(),
+                        trades: sum(periods, &format!("bucket:{}:trades", i)).to_u64(),
+                        volume: sum(periods, &format!("bucket:{}:volume", i)).to_string(),
+                    })
+                    .collect();
+                contract::TokenWindow {
+                    window: window.name(),
+                    trades: sum(periods, "trades").to_u64(),
+                    vwap: price::price(&volume, &size).map(|p| p.to_string()).unwrap_or_default(),
+                    volume: volume.to_string(),
+                    size: size.to_string(),
+                    size_buckets,
+                }
+            })
+            .collect();
+
+        updates.tokens.push(contract::TokenAnalytics {
+            token_id: trade.token_id.clone(),
+            block_time: trade.block_time,
+            block_number: trade.block_number,
+            windows,
+        });
+    }
+
+    updates
+}
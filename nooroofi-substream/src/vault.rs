@@ -0,0 +1,115 @@
+use anyhow::{anyhow, Context};
+use nooroo_core::mappers;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::parse_amount;
+use nooroo_core::vault::steth_address;
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+/// Key of the USDC held by the vault in `store_vault_tvl`.
+pub const TVL_KEY: &str = "tvl";
+/// Key of all the fees charged on deposits in `store_vault_fees`.
+pub const FEES_KEY: &str = "fees";
+
+/// Addresses of the vault deployment to decode, from the params of `map_vault_events` /
+/// `map_vault_calls`.
+#[derive(Debug, PartialEq)]
+pub struct Vault {
+    pub vault: Vec<u8>,
+    pub steth: Vec<u8>,
+}
+
+impl Vault {
+    /// Parses `vault=0x..&steth=0x..`. The stETH token defaults to the one the vault deploys;
+    /// without a vault there is nothing to decode.
+    pub fn parse(params: &str) -> Result<Option<Self>, substreams::errors::Error> {
+        let mut vault = None;
+        let mut steth = None;
+
+        for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match key.trim() {
+                "vault" => vault = Some(decode_address(value)?),
+                "steth" => steth = Some(decode_address(value)?),
+                other => return Err(anyhow!("unknown param {:?}", other)),
+            }
+        }
+
+        Ok(vault.map(|vault| Vault {
+            steth: steth.unwrap_or_else(|| steth_address(&vault).to_vec()),
+            vault,
+        }))
+    }
+}
+
+/// What `map_vault_events` outputs for a block: the events of the vault in `params` and of its
+/// stETH token, none without a vault.
+pub fn vault_events(blk: &eth::Block, params: &str) -> Result<contract::Events, substreams::errors::Error> {
+    let mut events = contract::Events::default();
+    if let Some(vault) = Vault::parse(params)? {
+        mappers::vault::map_events(blk, &mut events, &vault.vault);
+        mappers::steth::map_events(blk, &mut events, &vault.steth);
+    }
+    Ok(events)
+}
+
+/// What `map_vault_calls` outputs for a block: the calls of the vault in `params` and of its
+/// stETH token, none without a vault.
+pub fn vault_calls(blk: &eth::Block, params: &str) -> Result<contract::Calls, substreams::errors::Error> {
+    let mut calls = contract::Calls::default();
+    if let Some(vault) = Vault::parse(params)? {
+        mappers::vault::map_calls(blk, &mut calls, &vault.vault);
+        mappers::steth::map_calls(blk, &mut calls, &vault.steth);
+    }
+    Ok(calls)
+}
+
+fn decode_address(value: &str) -> Result<Vec<u8>, substreams::errors::Error> {
+    let address = Hex::decode(value.trim_start_matches("0x")).with_context(|| format!("invalid address {:?}", value))?;
+    if address.len() != 20 {
+        return Err(anyhow!("invalid address {:?}, expected 20 bytes", value));
+    }
+    Ok(address)
+}
+
+/// USDC a deposit leaves in the vault: `depositAndMint` forwards the fee to the fee recipient
+/// right away.
+pub fn net_deposit(deposit: &contract::VaultDeposit) -> BigInt {
+    parse_amount(&deposit.usdc_amount) - parse_amount(&deposit.fee_amount)
+}
+
+/// Signed changes of the USDC held by the vault, by ordinal: net deposits in, and successful
+/// `withdrawUSDC` calls out since withdrawals emit no event.
+pub fn tvl_changes(events: &contract::Events, calls: &contract::Calls) -> Vec<(u64, BigInt)> {
+    let mut changes: Vec<(u64, BigInt)> = events
+        .vault_deposits
+        .iter()
+        .map(|deposit| (deposit.evt_ordinal, net_deposit(deposit)))
+        .collect();
+    changes.extend(
+        calls
+            .vault_call_withdraw_usdcs
+            .iter()
+            .filter(|call| call.call_success)
+            .map(|call| (call.call_ordinal, parse_amount(&call.amount).neg())),
+    );
+    changes.sort_by_key(|(ordinal, _)| *ordinal);
+    changes
+}
+
+/// Key of a depositor's `metric` in `store_vault_deposits`.
+pub fn user_key(user: &[u8], metric: &str) -> String {
+    format!("user:{}:{}", Hex(user), metric)
+}
+
+/// Key of the fees charged on a day (since epoch) in `store_vault_fees`.
+pub fn daily_fees_key(day: i64) -> String {
+    format!("day:{}:fees", day)
+}
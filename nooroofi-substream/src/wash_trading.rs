@@ -0,0 +1,207 @@
+use anyhow::{anyhow, Context};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, Side};
+use prost_types::Timestamp;
+use substreams::Hex;
+
+use contract::suspicious_trade::Reason;
+
+/// Seconds a cycle must close within when the params leave the window out.
+pub const DEFAULT_WINDOW_SECONDS: i64 = 3600;
+/// Most owners a cycle can go through: past that, trades are too spread out to be told apart
+/// from a busy market.
+pub const MAX_CYCLE_WALLETS: usize = 4;
+/// Owners of a cycle when the params leave them out.
+pub const DEFAULT_CYCLE_WALLETS: usize = 3;
+
+/// Params of `map_suspicious_trades`.
+#[derive(Debug, PartialEq)]
+pub struct Params {
+    pub window: i64,
+    pub wallets: usize,
+}
+
+impl Params {
+    /// Parses `window=<seconds>&wallets=<n>`. Both are optional: cycles of up to three owners
+    /// within an hour.
+    pub fn parse(params: &str) -> Result<Self, substreams::errors::Error> {
+        let mut parsed = Params { window: DEFAULT_WINDOW_SECONDS, wallets: DEFAULT_CYCLE_WALLETS };
+
+        for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+            match key.trim() {
+                "window" => {
+                    let window: i64 = value.trim().parse().with_context(|| format!("invalid window {:?}", value))?;
+                    if window <= 0 {
+                        return Err(anyhow!("invalid window {}, expected seconds above 0", window));
+                    }
+                    parsed.window = window;
+                }
+                "wallets" => {
+                    let wallets: usize =
+                        value.trim().parse().with_context(|| format!("invalid wallets {:?}", value))?;
+                    if !(2..=MAX_CYCLE_WALLETS).contains(&wallets) {
+                        return Err(anyhow!("invalid wallets {}, expected 2 to {}", wallets, MAX_CYCLE_WALLETS));
+                    }
+                    parsed.wallets = wallets;
+                }
+                other => return Err(anyhow!("unknown param {:?}", other)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Key of the owner who last bought a token from `seller` in `store_last_buyers`.
+pub fn buyer_key(token_id: &str, seller: &[u8]) -> String {
+    format!("token:{}:buyer:{}", token_id, Hex(seller))
+}
+
+/// Value of a `buyer_key`: the buyer and the block time of the trade, `<buyer>:<seconds>`.
+pub fn buyer_value(buyer: &[u8], seconds: i64) -> String {
+    format!("{}:{}", Hex(buyer), seconds)
+}
+
+/// Reads back a `buyer_value`.
+pub fn parse_buyer(value: &str) -> Option<(Vec<u8>, i64)> {
+    let (buyer, seconds) = value.split_once(':')?;
+    Some((Hex::decode(buyer).ok()?, seconds.parse().ok()?))
+}
+
+/// A fill between the owners of its maker and taker.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedTrade {
+    pub tx_hash: String,
+    pub ordinal: u64,
+    pub block_time: Option<Timestamp>,
+    pub block_number: u64,
+    pub order_hash: Vec<u8>,
+    pub token_id: String,
+    pub maker: Vec<u8>,
+    pub taker: Vec<u8>,
+    /// Owner of the wallet giving the tokens.
+    pub seller: Vec<u8>,
+    /// Owner of the wallet receiving them.
+    pub buyer: Vec<u8>,
+    pub size: String,
+    pub notional: String,
+}
+
+impl OwnedTrade {
+    pub fn seconds(&self) -> i64 {
+        self.block_time.as_ref().map(|t| t.seconds).unwrap_or_default()
+    }
+}
+
+/// Every fill of the block passing tokens between its maker and taker, resolved by `owner_of`,
+/// in execution order. The maker orders of a `matchOrders` are filled against the maker of its
+/// taker order, the `OrdersMatched` taker, but only trade with it in a COMPLEMENTARY match,
+/// where its own fill against the exchange is in the same token: a MINT or MERGE match moves
+/// no tokens between them. The fill of the taker order itself is left out.
+pub fn owned_trades<O>(events: &contract::Events, exchange: &[u8], owner_of: O) -> Vec<OwnedTrade>
+where
+    O: Fn(&[u8]) -> Vec<u8>,
+{
+    let taker_fills = price::taker_order_fills(&events.polymarketctf_order_filleds, exchange);
+    let mut trades: Vec<OwnedTrade> = events
+        .polymarketctf_order_filleds
+        .iter()
+        .filter(|event| event.taker != exchange)
+        .filter_map(|event| {
+            let fill = price::fill(event)?;
+            let taker_fill = taker_fills.get(&(event.evt_tx_hash.as_str(), event.taker.as_slice()));
+            if taker_fill.is_some_and(|taker_fill| taker_fill.token_id != fill.token_id) {
+                return None;
+            }
+            let (maker, taker) = (owner_of(&event.maker), owner_of(&event.taker));
+            let (seller, buyer) = match fill.side {
+                Side::Buy => (taker, maker),
+                Side::Sell => (maker, taker),
+            };
+            Some(OwnedTrade {
+                tx_hash: event.evt_tx_hash.clone(),
+                ordinal: event.evt_ordinal,
+                block_time: event.evt_block_time,
+                block_number: event.evt_block_number,
+                order_hash: event.order_hash.clone(),
+                token_id: fill.token_id,
+                maker: event.maker.clone(),
+                taker: event.taker.clone(),
+                seller,
+                buyer,
+                size: fill.size.to_string(),
+                notional: fill.notional.to_string(),
+            })
+        })
+        .collect();
+    trades.sort_by_key(|t| t.ordinal);
+    trades
+}
+
+/// The block's trades between the same owner, and those closing a cycle: following the last
+/// buyer of the token from each owner, starting at the buyer, leads back to the seller through
+/// at most `params.wallets` owners, every hop within `params.window` of the trade.
+/// `last_buyer` gives the last `(buyer, seconds)` of a token from a seller, as of an ordinal.
+pub fn suspicious_trades<B>(trades: &[OwnedTrade], params: &Params, last_buyer: B) -> contract::SuspiciousTrades
+where
+    B: Fn(&str, &[u8], u64) -> Option<(Vec<u8>, i64)>,
+{
+    let mut suspicious = contract::SuspiciousTrades::default();
+
+    for trade in trades {
+        let (reason, wallets) = if trade.seller == trade.buyer {
+            (Reason::SelfTrade, Vec::new())
+        } else if let Some(wallets) = cycle(trade, params, &last_buyer) {
+            (Reason::CircularTrade, wallets)
+        } else {
+            continue;
+        };
+
+        suspicious.trades.push(contract::SuspiciousTrade {
+            tx_hash: trade.tx_hash.clone(),
+            block_time: trade.block_time,
+            block_number: trade.block_number,
+            ordinal: trade.ordinal,
+            order_hash: trade.order_hash.clone(),
+            reason: reason as i32,
+            token_id: trade.token_id.clone(),
+            maker: trade.maker.clone(),
+            taker: trade.taker.clone(),
+            seller: trade.seller.clone(),
+            buyer: trade.buyer.clone(),
+            size: trade.size.clone(),
+            notional: trade.notional.clone(),
+            wallets,
+        });
+    }
+
+    suspicious
+}
+
+/// Owners of the cycle `trade` closes, from its seller.
+fn cycle<B>(trade: &OwnedTrade, params: &Params, last_buyer: &B) -> Option<Vec<Vec<u8>>>
+where
+    B: Fn(&str, &[u8], u64) -> Option<(Vec<u8>, i64)>,
+{
+    let since = trade.seconds() - params.window;
+    let mut wallets = vec![trade.seller.clone(), trade.buyer.clone()];
+    while wallets.len() <= params.wallets {
+        let current = wallets.last()?;
+        let (next, seconds) = last_buyer(&trade.token_id, current, trade.ordinal.saturating_sub(1))?;
+        if seconds < since {
+            return None;
+        }
+        if next == trade.seller {
+            return Some(wallets);
+        }
+        // A loop that doesn't go through the seller.
+        if wallets.contains(&next) {
+            return None;
+        }
+        wallets.push(next);
+    }
+    None
+}
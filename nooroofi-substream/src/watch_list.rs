@@ -0,0 +1,221 @@
+use nooroo_core::pb::contract::v1 as contract;
+use anyhow::{anyhow, Context};
+use num_bigint::BigUint;
+use std::collections::HashSet;
+use std::str::FromStr;
+use substreams::Hex;
+
+/// Wallets, outcome token ids and condition ids parsed from a module's params.
+///
+/// Params use the query-string form `wallets=0x..,0x..&token_ids=..&condition_ids=0x..`;
+/// every key is optional. A record is kept when it involves at least one entry of any
+/// list, and an empty watch-list keeps everything.
+#[derive(Debug, Default)]
+pub struct WatchList {
+    wallets: HashSet<Vec<u8>>,
+    token_ids: HashSet<String>,
+    condition_ids: HashSet<Vec<u8>>,
+}
+
+impl WatchList {
+    pub fn parse(params: &str) -> Result<Self, substreams::errors::Error> {
+        let mut watch_list = WatchList::default();
+
+        for pair in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, values) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("invalid param {:?}, expected key=value", pair))?;
+            let values = values.split(',').map(str::trim).filter(|v| !v.is_empty());
+
+            match key.trim() {
+                "wallets" => {
+                    for value in values {
+                        watch_list.wallets.insert(decode_hex(value, 20)?);
+                    }
+                }
+                "token_ids" => {
+                    for value in values {
+                        let token_id = BigUint::from_str(value)
+                            .with_context(|| format!("invalid token id {:?}", value))?;
+                        watch_list.token_ids.insert(token_id.to_string());
+                    }
+                }
+                "condition_ids" => {
+                    for value in values {
+                        watch_list.condition_ids.insert(decode_hex(value, 32)?);
+                    }
+                }
+                other => return Err(anyhow!("unknown param {:?}", other)),
+            }
+        }
+
+        Ok(watch_list)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wallets.is_empty() && self.token_ids.is_empty() && self.condition_ids.is_empty()
+    }
+
+    fn wallet(&self, address: &[u8]) -> bool {
+        self.wallets.contains(address)
+    }
+
+    fn condition(&self, condition_id: &[u8]) -> bool {
+        self.condition_ids.contains(condition_id)
+    }
+
+    /// Matches a token id directly, or through the condition it was registered under.
+    fn token<F>(&self, token_id: &str, condition_of: &F) -> bool
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        if self.token_ids.contains(token_id) {
+            return true;
+        }
+
+        !self.condition_ids.is_empty() && condition_of(token_id).is_some_and(|c| self.condition(&c))
+    }
+
+    /// Matches an order through its maker, signer, restricted taker or outcome token.
+    fn order<F>(&self, order: &contract::PolymarketctfOrder, condition_of: &F) -> bool
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        self.wallet(&order.maker)
+            || self.wallet(&order.signer)
+            || self.wallet(&order.taker)
+            || self.token(&order.token_id, condition_of)
+    }
+
+    /// Keeps only the events involving the watch-list. `OrderCancelled` only carries the
+    /// order hash, so cancellations are dropped unless the watch-list is empty.
+    pub fn filter_events<F>(&self, mut events: contract::Events, condition_of: F) -> contract::Events
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        if self.is_empty() {
+            return events;
+        }
+
+        events.polymarketctf_fee_chargeds.retain(|e| {
+            self.wallet(&e.receiver) || self.token(&e.token_id, &condition_of)
+        });
+        events.polymarketctf_new_admins.retain(|e| {
+            self.wallet(&e.admin) || self.wallet(&e.new_admin_address)
+        });
+        events.polymarketctf_new_operators.retain(|e| {
+            self.wallet(&e.admin) || self.wallet(&e.new_operator_address)
+        });
+        events.polymarketctf_order_cancelleds.clear();
+        events.polymarketctf_order_filleds.retain(|e| {
+            self.wallet(&e.maker)
+                || self.wallet(&e.taker)
+                || self.token(&e.maker_asset_id, &condition_of)
+                || self.token(&e.taker_asset_id, &condition_of)
+        });
+        events.polymarketctf_orders_matcheds.retain(|e| {
+            self.wallet(&e.taker_order_maker)
+                || self.token(&e.maker_asset_id, &condition_of)
+                || self.token(&e.taker_asset_id, &condition_of)
+        });
+        events.polymarketctf_proxy_factory_updateds.retain(|e| {
+            self.wallet(&e.old_proxy_factory) || self.wallet(&e.new_proxy_factory)
+        });
+        events.polymarketctf_removed_admins.retain(|e| {
+            self.wallet(&e.admin) || self.wallet(&e.removed_admin)
+        });
+        events.polymarketctf_removed_operators.retain(|e| {
+            self.wallet(&e.admin) || self.wallet(&e.removed_operator)
+        });
+        events.polymarketctf_safe_factory_updateds.retain(|e| {
+            self.wallet(&e.old_safe_factory) || self.wallet(&e.new_safe_factory)
+        });
+        events.polymarketctf_token_registereds.retain(|e| {
+            self.condition(&e.condition_id) || self.token_ids.contains(&e.token0) || self.token_ids.contains(&e.token1)
+        });
+        events.polymarketctf_trading_pauseds.retain(|e| self.wallet(&e.pauser));
+        events.polymarketctf_trading_unpauseds.retain(|e| self.wallet(&e.pauser));
+        events.ctf_approval_for_alls.retain(|e| self.wallet(&e.owner) || self.wallet(&e.operator));
+        events.ctf_condition_preparations.retain(|e| self.condition(&e.condition_id) || self.wallet(&e.oracle));
+        events.ctf_condition_resolutions.retain(|e| self.condition(&e.condition_id) || self.wallet(&e.oracle));
+        events.ctf_payout_redemptions.retain(|e| self.wallet(&e.redeemer) || self.condition(&e.condition_id));
+        events.ctf_position_splits.retain(|e| self.wallet(&e.stakeholder) || self.condition(&e.condition_id));
+        events.ctf_positions_merges.retain(|e| self.wallet(&e.stakeholder) || self.condition(&e.condition_id));
+        events.ctf_transfer_batches.retain(|e| {
+            self.wallet(&e.operator)
+                || self.wallet(&e.from)
+                || self.wallet(&e.to)
+                || e.ids.iter().any(|id| self.token(id, &condition_of))
+        });
+        events.ctf_transfer_singles.retain(|e| {
+            self.wallet(&e.operator) || self.wallet(&e.from) || self.wallet(&e.to) || self.token(&e.id, &condition_of)
+        });
+        events.ctf_uris.retain(|e| self.token(&e.id, &condition_of));
+
+        events
+    }
+
+    /// Keeps only the calls whose decoded inputs involve the watch-list. Calls taking several
+    /// orders are kept whole when any of them does. Calls without any address, token or order
+    /// input are dropped unless the watch-list is empty.
+    pub fn filter_calls<F>(&self, mut calls: contract::Calls, condition_of: F) -> contract::Calls
+    where
+        F: Fn(&str) -> Option<Vec<u8>>,
+    {
+        if self.is_empty() {
+            return calls;
+        }
+
+        calls.polymarketctf_call_add_admins.retain(|c| self.wallet(&c.admin));
+        calls.polymarketctf_call_add_operators.retain(|c| self.wallet(&c.operator));
+        calls.polymarketctf_call_cancel_orders.retain(|c| c.order.as_ref().is_some_and(|o| self.order(o, &condition_of)));
+        calls.polymarketctf_call_cancel_orders_batch.retain(|c| c.orders.iter().any(|o| self.order(o, &condition_of)));
+        calls.polymarketctf_call_fill_orders.retain(|c| c.order.as_ref().is_some_and(|o| self.order(o, &condition_of)));
+        calls.polymarketctf_call_fill_orders_batch.retain(|c| c.orders.iter().any(|o| self.order(o, &condition_of)));
+        calls.polymarketctf_call_increment_nonces.clear();
+        calls.polymarketctf_call_match_orders.retain(|c| {
+            c.taker_order.iter().chain(&c.maker_orders).any(|o| self.order(o, &condition_of))
+        });
+        calls.polymarketctf_call_on_erc1155_batch_receiveds.retain(|c| {
+            self.wallet(&c.operator) || self.wallet(&c.from) || c.ids.iter().any(|id| self.token(id, &condition_of))
+        });
+        calls.polymarketctf_call_on_erc1155_receiveds.retain(|c| {
+            self.wallet(&c.operator) || self.wallet(&c.from) || self.token(&c.id, &condition_of)
+        });
+        calls.polymarketctf_call_pause_tradings.clear();
+        calls.polymarketctf_call_register_tokens.retain(|c| {
+            self.condition(&c.condition_id) || self.token_ids.contains(&c.token) || self.token_ids.contains(&c.complement)
+        });
+        calls.polymarketctf_call_remove_admins.retain(|c| self.wallet(&c.admin));
+        calls.polymarketctf_call_remove_operators.retain(|c| self.wallet(&c.operator));
+        calls.polymarketctf_call_renounce_admin_roles.clear();
+        calls.polymarketctf_call_renounce_operator_roles.clear();
+        calls.polymarketctf_call_set_proxy_factories.retain(|c| self.wallet(&c.new_proxy_factory));
+        calls.polymarketctf_call_set_safe_factories.retain(|c| self.wallet(&c.new_safe_factory));
+        calls.polymarketctf_call_unpause_tradings.clear();
+        calls.ctf_call_merge_positions.retain(|c| self.condition(&c.condition_id));
+        calls.ctf_call_prepare_conditions.retain(|c| self.wallet(&c.oracle));
+        calls.ctf_call_redeem_positions.retain(|c| self.condition(&c.condition_id));
+        calls.ctf_call_report_payouts.clear();
+        calls.ctf_call_safe_batch_transfer_froms.retain(|c| {
+            self.wallet(&c.from) || self.wallet(&c.to) || c.ids.iter().any(|id| self.token(id, &condition_of))
+        });
+        calls.ctf_call_safe_transfer_froms.retain(|c| {
+            self.wallet(&c.from) || self.wallet(&c.to) || self.token(&c.id, &condition_of)
+        });
+        calls.ctf_call_set_approval_for_alls.retain(|c| self.wallet(&c.operator));
+        calls.ctf_call_split_positions.retain(|c| self.condition(&c.condition_id));
+
+        calls
+    }
+}
+
+fn decode_hex(value: &str, len: usize) -> Result<Vec<u8>, substreams::errors::Error> {
+    let bytes = Hex::decode(value.trim_start_matches("0x"))
+        .with_context(|| format!("invalid hex value {:?}", value))?;
+    if bytes.len() != len {
+        return Err(anyhow!("expected {} bytes, got {} in {:?}", len, bytes.len(), value));
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use common::{address, fill, order, TOKEN};
+use nooroo::activity;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+
+use contract::user_activity::Kind;
+
+/// An order of the wallet `maker` signed by `signer`.
+fn signed(maker: u8, signer: u8) -> contract::PolymarketctfOrder {
+    contract::PolymarketctfOrder { signer: address(signer), ..order(maker, Side::Buy, "40", "100") }
+}
+
+#[test]
+fn proxies_are_linked_to_the_signers_of_successful_calls() {
+    let calls = contract::Calls {
+        polymarketctf_call_match_orders: vec![contract::PolymarketctfMatchOrdersCall {
+            call_ordinal: 4,
+            call_success: true,
+            taker_order: Some(signed(0x0a, 0xa0)),
+            maker_orders: vec![signed(0x0b, 0x0b), signed(0x0c, 0xc0)],
+            ..Default::default()
+        }],
+        polymarketctf_call_fill_orders: vec![contract::PolymarketctfFillOrderCall {
+            call_ordinal: 2,
+            call_success: false,
+            order: Some(signed(0x0d, 0xd0)),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        activity::proxy_owners(&calls),
+        [(4, address(0x0a), address(0xa0)), (4, address(0x0c), address(0xc0))]
+    );
+}
+
+#[test]
+fn fills_are_credited_to_depositors_through_their_proxies() {
+    let exchange = address(0xee);
+    let vault_events = contract::Events {
+        vault_deposits: vec![contract::VaultDeposit {
+            evt_ordinal: 5,
+            user: address(0xa0),
+            usdc_amount: "1000000".to_string(),
+            fee_amount: "5000".to_string(),
+            ..Default::default()
+        }],
+        steth_transfers: vec![
+            contract::StethTransfer {
+                evt_ordinal: 4,
+                from: address(0),
+                to: address(0xa0),
+                value: "995".to_string(),
+                ..Default::default()
+            },
+            contract::StethTransfer {
+                evt_ordinal: 6,
+                from: address(0xa0),
+                to: address(0xb0),
+                value: "10".to_string(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    let events = contract::Events {
+        // Before the deposit, after it through the proxy 0x0a, and with the exchange as taker,
+        // each in a transaction of its own.
+        polymarketctf_order_filleds: [(3, 0x0a, 0xb0), (9, 0xb0, 0x0a), (12, 0x0a, 0xee)]
+            .map(|(ordinal, maker, taker)| contract::PolymarketctfOrderFilled {
+                evt_tx_hash: format!("{:02x}", ordinal),
+                fee: "1".to_string(),
+                ..fill(ordinal, maker, taker, Side::Buy, "100", "40")
+            })
+            .to_vec(),
+        ..Default::default()
+    };
+
+    let activities = activity::user_activities(
+        &vault_events,
+        &events,
+        &exchange,
+        |actor| (actor == address(0x0a).as_slice()).then(|| address(0xa0)),
+        |wallet, ordinal| wallet == address(0xa0).as_slice() && ordinal > 5,
+    )
+    .activities;
+
+    // (ordinal, kind, wallet, actor, amount, side), addresses by seed.
+    let summary: Vec<_> = activities
+        .iter()
+        .map(|a| (a.ordinal, a.kind, a.wallet[0], a.actor[0], a.amount.as_str(), a.side))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (4, Kind::Mint as i32, 0xa0, 0xa0, "995", 0),
+            (5, Kind::Deposit as i32, 0xa0, 0xa0, "1000000", 0),
+            (9, Kind::Fill as i32, 0xa0, 0x0a, "40", Side::Sell as u32),
+            (12, Kind::Fill as i32, 0xa0, 0x0a, "40", Side::Buy as u32),
+        ]
+    );
+    assert_eq!((activities[2].fee.as_str(), activities[3].fee.as_str()), ("0", "1"));
+    assert_eq!((activities[3].token_id.as_str(), activities[3].size.as_str()), (TOKEN, "100"));
+}
+
+#[test]
+fn takers_of_mint_and_merge_matches_report_their_own_fill() {
+    let exchange = address(0xee);
+    let complement = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        maker_asset_id: fill.maker_asset_id.replace(TOKEN, "5678"),
+        taker_asset_id: fill.taker_asset_id.replace(TOKEN, "5678"),
+        ..fill
+    };
+    let in_tx = |tx_hash: &str, fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        ..fill
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            // MINT: 0x0b buys the complement with 60, 0x0a buys the token with 40.
+            in_tx("aa", complement(fill(2, 0x0b, 0x0a, Side::Buy, "100", "60"))),
+            in_tx("aa", fill(3, 0x0a, 0xee, Side::Buy, "100", "40")),
+            // MERGE: 0x0b sells the complement for 60, 0x0a sells the token for 40.
+            in_tx("bb", complement(fill(5, 0x0b, 0x0a, Side::Sell, "100", "60"))),
+            in_tx("bb", fill(6, 0x0a, 0xee, Side::Sell, "100", "40")),
+        ],
+        ..Default::default()
+    };
+
+    let activities = activity::user_activities(&Default::default(), &events, &exchange, |_| None, |_, _| true).activities;
+
+    // (ordinal, wallet, token, side, amount), addresses by seed.
+    let summary: Vec<_> = activities
+        .iter()
+        .map(|a| (a.ordinal, a.wallet[0], a.token_id.as_str(), a.side, a.amount.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (2, 0x0b, "5678", Side::Buy as u32, "60"),
+            (3, 0x0a, TOKEN, Side::Buy as u32, "40"),
+            (5, 0x0b, "5678", Side::Sell as u32, "60"),
+            (6, 0x0a, TOKEN, Side::Sell as u32, "40"),
+        ]
+    );
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use common::{address, fill, time, TOKEN};
+use nooroo::approvals::{self, Approval};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+
+fn approval_for_all(ordinal: u64, owner: u8, approved: bool) -> contract::CtfApprovalForAll {
+    contract::CtfApprovalForAll {
+        evt_ordinal: ordinal,
+        evt_block_number: 100,
+        evt_block_time: time(1_750_000_000),
+        owner: address(owner),
+        operator: address(0xee),
+        approved,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn approvals_round_trip_through_the_store() {
+    let approval = Approval::of(&approval_for_all(3, 0x0a, true));
+    assert_eq!(approval.to_value(), "1:100:1750000000");
+    assert_eq!(Approval::parse(&approval.to_value()), Some(approval));
+    assert_eq!(Approval::parse("1:100"), None);
+}
+
+#[test]
+fn sell_fills_carry_the_approval_of_the_seller() {
+    let exchange = address(0xee);
+    let events = contract::Events {
+        ctf_approval_for_alls: vec![approval_for_all(2, 0x0a, true), approval_for_all(8, 0x0b, false)],
+        polymarketctf_order_filleds: vec![
+            fill(5, 0x0a, 0x0c, Side::Sell, "100", "40"),
+            fill(6, 0x0d, 0x0b, Side::Buy, "100", "40"),
+            fill(7, 0x0c, 0xee, Side::Buy, "100", "40"),
+            fill(9, 0x0c, 0x0d, Side::Sell, "100", "40"),
+        ],
+        ..Default::default()
+    };
+    // 0x0a approves in the block, 0x0b approved at block 90 and revokes at ordinal 8.
+    let state = |owner: &[u8], _operator: &[u8], ordinal: u64| -> Option<Approval> {
+        match owner[0] {
+            0x0a if ordinal >= 2 => Some(Approval { approved: true, block_number: 100, time: 1_750_000_000 }),
+            0x0b if ordinal < 8 => Some(Approval { approved: true, block_number: 90, time: 1_749_999_000 }),
+            0x0b => Some(Approval { approved: false, block_number: 100, time: 1_750_000_000 }),
+            _ => None,
+        }
+    };
+
+    let approvals = approvals::operator_approvals(&events, &exchange, state);
+
+    let changes: Vec<_> = approvals.changes.iter().map(|c| (c.owner[0], c.approved, c.previously_approved)).collect();
+    assert_eq!(changes, [(0x0a, true, false), (0x0b, false, true)]);
+
+    // (seller, approved, approved block), addresses by seed; 0x0c buying through its own taker
+    // order sells nothing.
+    let fills: Vec<_> = approvals
+        .sell_fills
+        .iter()
+        .map(|f| (f.seller[0], f.approved, f.approved_block_number))
+        .collect();
+    assert_eq!(fills, [(0x0a, true, 100), (0x0b, true, 90), (0x0c, false, 0)]);
+    assert_eq!(approvals.sell_fills[1].approved_time, time(1_749_999_000));
+    assert_eq!((approvals.sell_fills[0].token_id.as_str(), approvals.sell_fills[0].size.as_str()), (TOKEN, "100"));
+}
+
+#[test]
+fn matched_sells_are_checked_on_the_fills_of_their_own_orders() {
+    let exchange = address(0xee);
+    let complement = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        maker_asset_id: fill.maker_asset_id.replace(TOKEN, "5678"),
+        taker_asset_id: fill.taker_asset_id.replace(TOKEN, "5678"),
+        ..fill
+    };
+    let in_tx = |tx_hash: &str, fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        ..fill
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            // COMPLEMENTARY: the taker order of 0x0a sells 200 to the buy orders of 0x0b and 0x0c.
+            in_tx("aa", fill(3, 0x0b, 0x0a, Side::Buy, "100", "40")),
+            in_tx("aa", fill(4, 0x0c, 0x0a, Side::Buy, "100", "40")),
+            in_tx("aa", fill(5, 0x0a, 0xee, Side::Sell, "200", "80")),
+            // MINT: 0x0b buys the complement and 0x0a the token, nobody sells.
+            in_tx("bb", complement(fill(7, 0x0b, 0x0a, Side::Buy, "100", "60"))),
+            in_tx("bb", fill(8, 0x0a, 0xee, Side::Buy, "100", "40")),
+            // MERGE: 0x0b sells the complement and 0x0a the token.
+            in_tx("cc", complement(fill(10, 0x0b, 0x0a, Side::Sell, "100", "60"))),
+            in_tx("cc", fill(11, 0x0a, 0xee, Side::Sell, "100", "40")),
+        ],
+        ..Default::default()
+    };
+    let state = |_owner: &[u8], _operator: &[u8], _ordinal: u64| Some(Approval { approved: true, block_number: 90, time: 0 });
+
+    let approvals = approvals::operator_approvals(&events, &exchange, state);
+    let fills: Vec<_> = approvals
+        .sell_fills
+        .iter()
+        .map(|f| (f.ordinal, f.seller[0], f.token_id.as_str(), f.size.as_str()))
+        .collect();
+    assert_eq!(fills, [(5, 0x0a, TOKEN, "200"), (10, 0x0b, "5678", "100"), (11, 0x0a, TOKEN, "100")]);
+}
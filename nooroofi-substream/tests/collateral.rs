@@ -0,0 +1,170 @@
+mod common;
+
+use common::address;
+use nooroo::collateral;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::{CONDITIONAL_TOKENS, EXCHANGE};
+
+#[cfg(all(feature = "calls", feature = "trading"))]
+use {
+    contract::failed_fill::Reason,
+    nooroo_core::abi::{polymarketctf_contract, usdc_contract},
+    nooroo_core::ctf::EXCHANGE_COLLATERAL,
+    substreams::scalar::BigInt,
+    substreams_ethereum::pb::eth::v2 as eth,
+};
+
+fn transfer(ordinal: u64, from: &[u8], to: &[u8], value: &str) -> contract::UsdcTransfer {
+    contract::UsdcTransfer {
+        evt_ordinal: ordinal,
+        from: from.to_vec(),
+        to: to.to_vec(),
+        value: value.to_string(),
+        ..Default::default()
+    }
+}
+
+/// `Error(string)` revert data carrying `message`.
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn revert_data(message: &str) -> Vec<u8> {
+    let mut data = vec![0x08, 0xc3, 0x79, 0xa0];
+    data.extend([[0u8; 31].as_slice(), &[0x20]].concat());
+    data.extend([[0u8; 24].as_slice(), &(message.len() as u64).to_be_bytes()].concat());
+    data.extend(message.as_bytes());
+    data.resize(4 + 64 + message.len().div_ceil(32) * 32, 0);
+    data
+}
+
+/// A failed transaction whose `fillOrder` reverts on the USDC `transferFrom` of the maker.
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn failed_fill_tx(message: &str) -> eth::TransactionTrace {
+    let order = (
+        BigInt::from(1),
+        address(0x0a),
+        address(0x0a),
+        address(0),
+        BigInt::from(1234),
+        BigInt::from(40),
+        BigInt::from(100),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        vec![0xab; 65],
+    );
+    let fill = polymarketctf_contract::functions::FillOrder { order, fill_amount: BigInt::from(40) };
+    let transfer_from = usdc_contract::functions::TransferFrom {
+        sender: address(0x0a),
+        recipient: EXCHANGE.to_vec(),
+        amount: BigInt::from(40),
+    };
+
+    eth::TransactionTrace {
+        hash: vec![0xf1; 32],
+        status: eth::TransactionTraceStatus::Reverted as i32,
+        calls: vec![
+            eth::Call {
+                index: 1,
+                depth: 0,
+                address: EXCHANGE.to_vec(),
+                input: fill.encode(),
+                begin_ordinal: 3,
+                state_reverted: true,
+                status_failed: true,
+                status_reverted: true,
+                failure_reason: "execution reverted".to_string(),
+                ..Default::default()
+            },
+            eth::Call {
+                index: 2,
+                parent_index: 1,
+                depth: 1,
+                address: EXCHANGE_COLLATERAL.to_vec(),
+                input: transfer_from.encode(),
+                begin_ordinal: 5,
+                state_reverted: true,
+                status_failed: true,
+                status_reverted: true,
+                failure_reason: "execution reverted".to_string(),
+                return_data: revert_data(message),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn flows_are_seen_from_the_trader() {
+    let events = contract::Events {
+        usdc_transfers: vec![
+            transfer(7, &EXCHANGE, &address(0x0a), "30"),
+            transfer(2, &address(0x0a), &EXCHANGE, "100"),
+            transfer(4, &EXCHANGE, &CONDITIONAL_TOKENS, "50"),
+            transfer(5, &address(0x0b), &address(0x0c), "10"),
+        ],
+        usdc_approvals: vec![
+            contract::UsdcApproval {
+                evt_ordinal: 1,
+                owner: address(0x0a),
+                spender: EXCHANGE.to_vec(),
+                value: "1000".to_string(),
+                ..Default::default()
+            },
+            contract::UsdcApproval {
+                evt_ordinal: 6,
+                owner: address(0x0a),
+                spender: address(0x0d),
+                value: "5".to_string(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+
+    let flows = collateral::collateral_flows(&events, &[&EXCHANGE, &CONDITIONAL_TOKENS]);
+    let summary: Vec<_> = flows.flows.iter().map(|f| (f.ordinal, f.trader[0], f.amount.as_str())).collect();
+    assert_eq!(summary, [(2, 0x0a, "-100"), (7, 0x0a, "30")]);
+    assert_eq!(flows.allowances.len(), 1);
+    assert_eq!((flows.allowances[0].spender.as_slice(), flows.allowances[0].allowance.as_str()), (EXCHANGE.as_slice(), "1000"));
+}
+
+#[test]
+#[cfg(all(feature = "calls", feature = "trading"))]
+fn fills_reverted_by_the_collateral_transfer_are_flagged() {
+    let block_time = prost_types::Timestamp { seconds: 1_750_000_000, nanos: 0 };
+    let blk = eth::Block {
+        number: 75_000_000,
+        header: Some(eth::BlockHeader { timestamp: Some(block_time), ..Default::default() }),
+        transaction_traces: vec![
+            failed_fill_tx("ERC20: transfer amount exceeds allowance"),
+            failed_fill_tx("ERC20: transfer amount exceeds balance"),
+            failed_fill_tx("Pausable: paused"),
+            // Told apart by the message alone.
+            failed_fill_tx("ERC1155: need operator approval for 3rd party transfers."),
+        ],
+        ..Default::default()
+    };
+
+    let failed = collateral::failed_fills(&blk, &EXCHANGE_COLLATERAL);
+    assert_eq!(failed.len(), 3);
+    assert_eq!(
+        failed[0],
+        contract::FailedFill {
+            tx_hash: "f1".repeat(32),
+            block_time: Some(block_time),
+            block_number: 75_000_000,
+            ordinal: 3,
+            call: "fillOrder".to_string(),
+            reason: Reason::InsufficientAllowance as i32,
+            token: EXCHANGE_COLLATERAL.to_vec(),
+            token_id: String::new(),
+            owner: address(0x0a),
+            amount: "40".to_string(),
+            revert_reason: "ERC20: transfer amount exceeds allowance".to_string(),
+        }
+    );
+    assert_eq!(failed[1].reason, Reason::InsufficientBalance as i32);
+    assert_eq!(failed[2].reason, Reason::MissingOperatorApproval as i32);
+}
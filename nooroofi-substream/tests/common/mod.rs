@@ -0,0 +1,57 @@
+//! Decoded records shared by the tests of the analysis modules.
+
+#![allow(dead_code)]
+
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{Side, COLLATERAL_ASSET_ID};
+use prost_types::Timestamp;
+
+/// The outcome token of `fill` and `order`.
+pub const TOKEN: &str = "1234";
+
+/// A deterministic 20-byte address derived from `seed`.
+pub fn address(seed: u8) -> Vec<u8> {
+    vec![seed; 20]
+}
+
+/// A block time `seconds` after epoch.
+pub fn time(seconds: i64) -> Option<Timestamp> {
+    Some(Timestamp { seconds, nanos: 0 })
+}
+
+/// A fill of `maker` buying, or selling with `Side::Sell`, `size` of `TOKEN` for `notional` of
+/// collateral against `taker`.
+pub fn fill(ordinal: u64, maker: u8, taker: u8, side: Side, size: &str, notional: &str) -> contract::PolymarketctfOrderFilled {
+    let (maker_asset, taker_asset, maker_amount, taker_amount) = match side {
+        Side::Buy => (COLLATERAL_ASSET_ID, TOKEN, notional, size),
+        Side::Sell => (TOKEN, COLLATERAL_ASSET_ID, size, notional),
+    };
+    contract::PolymarketctfOrderFilled {
+        evt_ordinal: ordinal,
+        maker: address(maker),
+        taker: address(taker),
+        maker_asset_id: maker_asset.to_string(),
+        taker_asset_id: taker_asset.to_string(),
+        maker_amount_filled: maker_amount.to_string(),
+        taker_amount_filled: taker_amount.to_string(),
+        ..Default::default()
+    }
+}
+
+/// An order of `maker`, signed by itself, giving `maker_amount` for `taker_amount` of `TOKEN`:
+/// collateral for tokens when buying, tokens for collateral when selling.
+pub fn order(maker: u8, side: Side, maker_amount: &str, taker_amount: &str) -> contract::PolymarketctfOrder {
+    contract::PolymarketctfOrder {
+        salt: maker.to_string(),
+        maker: address(maker),
+        signer: address(maker),
+        token_id: TOKEN.to_string(),
+        maker_amount: maker_amount.to_string(),
+        taker_amount: taker_amount.to_string(),
+        expiration: "0".to_string(),
+        nonce: "0".to_string(),
+        fee_rate_bps: "0".to_string(),
+        side: side as u32,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use common::{fill, order};
+use nooroo::execution;
+use nooroo_core::order::exchange_order_hash;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{self, Side};
+
+use contract::fill_execution::Role;
+
+/// The `OrderFilled` of `order` in transaction `tx_hash`, trading `size` tokens for `notional`
+/// against `taker`.
+fn filled(tx_hash: &str, ordinal: u64, order: &contract::PolymarketctfOrder, taker: u8, size: &str, notional: &str) -> contract::PolymarketctfOrderFilled {
+    let side = if order.side == Side::Buy as u32 { Side::Buy } else { Side::Sell };
+    contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        order_hash: exchange_order_hash(order).unwrap().to_vec(),
+        ..fill(ordinal, order.maker[0], taker, side, size, notional)
+    }
+}
+
+#[test]
+fn fills_are_compared_with_the_orders_of_their_calls() {
+    // 0x0a buys at up to 0.40 from 0x0b selling at 0.35 or more, both filled at 0.38.
+    let taker_order = order(0x0a, Side::Buy, "40000000", "100000000");
+    let maker_order = order(0x0b, Side::Sell, "100000000", "35000000");
+    // A fillOrder that reverted.
+    let failed_order = order(0x0c, Side::Buy, "50000000", "100000000");
+
+    let calls = contract::Calls {
+        polymarketctf_call_match_orders: vec![contract::PolymarketctfMatchOrdersCall {
+            call_tx_hash: "aa".to_string(),
+            call_success: true,
+            taker_order: Some(taker_order.clone()),
+            maker_orders: vec![maker_order.clone()],
+            ..Default::default()
+        }],
+        polymarketctf_call_fill_orders: vec![contract::PolymarketctfFillOrderCall {
+            call_tx_hash: "bb".to_string(),
+            call_success: false,
+            order: Some(failed_order.clone()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            filled("aa", 3, &maker_order, 0x0a, "100000000", "38000000"),
+            filled("aa", 4, &taker_order, 0xee, "100000000", "38000000"),
+            // The same order in a transaction without its call.
+            filled("cc", 9, &maker_order, 0x0a, "100000000", "38000000"),
+            filled("bb", 7, &failed_order, 0x0d, "100000000", "50000000"),
+        ],
+        ..Default::default()
+    };
+
+    let fills = execution::executions(&events, &calls).fills;
+
+    // (ordinal, role, maker, limit, execution, improvement), addresses by seed.
+    let summary: Vec<_> = fills
+        .iter()
+        .map(|f| {
+            (
+                f.ordinal,
+                f.role,
+                f.maker[0],
+                f.limit_price.as_str(),
+                f.execution_price.as_str(),
+                f.improvement_bps.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (3, Role::Maker as i32, 0x0b, "0.35", "0.38", "857.14"),
+            (4, Role::Taker as i32, 0x0a, "0.4", "0.38", "500.00"),
+        ]
+    );
+    assert_eq!((fills[0].call.as_str(), fills[0].side), ("matchOrders", Side::Sell as u32));
+}
+
+#[test]
+fn slippage_is_negative() {
+    let order = order(0x0a, Side::Buy, "40000000", "100000000");
+    let fill = price::fill(&filled("aa", 1, &order, 0x0b, "100000000", "41000000")).unwrap();
+    assert_eq!(execution::improvement_bps(&order, &fill).unwrap().to_string(), "-250.00");
+
+    let fill = price::fill(&filled("aa", 1, &order, 0x0b, "100000000", "40000000")).unwrap();
+    assert_eq!(execution::improvement_bps(&order, &fill).unwrap().to_string(), "0.00");
+}
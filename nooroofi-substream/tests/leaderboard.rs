@@ -0,0 +1,270 @@
+mod common;
+
+use common::{address, fill, time, TOKEN};
+use nooroo::leaderboard::{self, Params, Position, Window};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+use std::cell::Cell;
+use substreams::scalar::BigInt;
+
+/// `fill` paying `fee`, in block 100 on Monday 2025-06-16.
+fn on_monday(fill: contract::PolymarketctfOrderFilled, fee: &str) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        evt_block_number: 100,
+        evt_block_time: time(1_750_032_000),
+        fee: fee.to_string(),
+        ..fill
+    }
+}
+
+#[test]
+fn params_default_to_ten_entries_over_every_window() {
+    assert_eq!(Params::parse("").unwrap(), Params { top: 10, windows: Window::ALL.to_vec() });
+    assert_eq!(Params::parse("top=5&window=all, day").unwrap(), Params { top: 5, windows: vec![Window::All, Window::Day] });
+    assert!(Params::parse("top=0").is_err());
+    assert!(Params::parse("top=101").is_err());
+    assert!(Params::parse("window=month").is_err());
+}
+
+#[test]
+fn fills_add_up_per_wallet() {
+    let exchange = address(0xee);
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            // 0x0a buys 100 for 40 paying 5 tokens of fee, then sells 50 for 30 paying 1.
+            on_monday(fill(2, 0x0a, 0x0b, Side::Buy, "100", "40"), "5"),
+            on_monday(fill(4, 0x0a, 0x0c, Side::Sell, "50", "30"), "1"),
+            // The taker order of a `matchOrders`, against the exchange.
+            on_monday(fill(6, 0x0d, 0xee, Side::Sell, "50", "30"), "3"),
+        ],
+        ..Default::default()
+    };
+    let position = |wallet: &[u8], _token_id: &str, ordinal: Option<u64>| match (wallet[0], ordinal) {
+        (0x0a, None) => Position {
+            buy_size: BigInt::from(100),
+            buy_cost: BigInt::from(40),
+            sell_size: BigInt::from(50),
+            sell_proceeds: BigInt::from(30),
+        },
+        _ => Position::default(),
+    };
+
+    let stats = leaderboard::trader_stats(&events, &exchange, position);
+    assert_eq!((stats.day, stats.week), (20_255, 2_894));
+
+    // (wallet, volume, trades, maker volume, taker volume, fees, realized PnL), addresses by seed.
+    let summary: Vec<_> = stats
+        .stats
+        .iter()
+        .map(|s| {
+            (
+                s.wallet[0],
+                s.volume.as_str(),
+                s.trades,
+                s.maker_volume.as_str(),
+                s.taker_volume.as_str(),
+                s.fees.as_str(),
+                s.realized_pnl.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (0x0a, "70", 2, "70", "0", "3", "10"),
+            (0x0b, "40", 1, "0", "40", "0", "0"),
+            (0x0c, "30", 1, "0", "30", "0", "0"),
+            (0x0d, "30", 1, "0", "30", "3", "0"),
+        ]
+    );
+}
+
+#[test]
+fn matched_takers_trade_their_own_side_and_token() {
+    let exchange = address(0xee);
+    let complement = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        maker_asset_id: fill.maker_asset_id.replace(TOKEN, "5678"),
+        taker_asset_id: fill.taker_asset_id.replace(TOKEN, "5678"),
+        ..fill
+    };
+    let in_tx = |tx_hash: &str, fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        ..fill
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            // COMPLEMENTARY: the taker order of 0x0a sells 200 to the buy orders of 0x0b and 0x0c.
+            in_tx("aa", fill(1, 0x0b, 0x0a, Side::Buy, "100", "40")),
+            in_tx("aa", fill(2, 0x0c, 0x0a, Side::Buy, "100", "40")),
+            in_tx("aa", fill(3, 0x0a, 0xee, Side::Sell, "200", "80")),
+            // MINT: 0x0b buys the complement with 60, 0x0a the token with 40.
+            in_tx("bb", complement(fill(5, 0x0b, 0x0a, Side::Buy, "100", "60"))),
+            in_tx("bb", fill(6, 0x0a, 0xee, Side::Buy, "100", "40")),
+            // MERGE: 0x0b sells the complement for 60, 0x0a the token for 40.
+            in_tx("cc", complement(fill(8, 0x0b, 0x0a, Side::Sell, "100", "60"))),
+            in_tx("cc", fill(9, 0x0a, 0xee, Side::Sell, "100", "40")),
+            // An operator 0x0f filling the order of 0x0b itself.
+            in_tx("dd", fill(11, 0x0b, 0x0f, Side::Buy, "100", "40")),
+        ],
+        ..Default::default()
+    };
+
+    // (ordinal, wallet, maker, token, side, size, notional), addresses by seed.
+    let trades: Vec<_> = leaderboard::trades(&events, &exchange)
+        .into_iter()
+        .map(|t| (t.ordinal, t.wallet[0], t.maker, t.token_id, t.side, t.size.to_string(), t.notional.to_string()))
+        .collect();
+    let trade = |ordinal, wallet, maker, token: &str, side, size: &str, notional: &str| {
+        (ordinal, wallet, maker, token.to_string(), side, size.to_string(), notional.to_string())
+    };
+    assert_eq!(
+        trades,
+        [
+            trade(1, 0x0b, true, TOKEN, Side::Buy, "100", "40"),
+            trade(2, 0x0c, true, TOKEN, Side::Buy, "100", "40"),
+            trade(3, 0x0a, false, TOKEN, Side::Sell, "200", "80"),
+            trade(5, 0x0b, true, "5678", Side::Buy, "100", "60"),
+            trade(6, 0x0a, false, TOKEN, Side::Buy, "100", "40"),
+            trade(8, 0x0b, true, "5678", Side::Sell, "100", "60"),
+            trade(9, 0x0a, false, TOKEN, Side::Sell, "100", "40"),
+            trade(11, 0x0b, true, TOKEN, Side::Buy, "100", "40"),
+            trade(11, 0x0f, false, TOKEN, Side::Sell, "100", "40"),
+        ]
+    );
+}
+
+#[test]
+fn realized_pnl_only_counts_what_was_both_bought_and_sold() {
+    let position = Position {
+        buy_size: BigInt::from(100),
+        buy_cost: BigInt::from(40),
+        sell_size: BigInt::from(150),
+        sell_proceeds: BigInt::from(30),
+    };
+    // 100 sold at 0.2 against 100 bought at 0.4.
+    assert_eq!(position.realized_pnl(), BigInt::from(-20));
+    assert_eq!(Position { buy_size: BigInt::from(100), ..Default::default() }.realized_pnl(), BigInt::zero());
+}
+
+#[test]
+fn totals_move_between_buckets() {
+    assert_eq!(
+        [0, -5, 1, 255, 256].map(|v| leaderboard::bucket(&BigInt::from(v))),
+        [0, 0, 1, 8, 9]
+    );
+
+    let stats = contract::TraderStats {
+        day: 20_255,
+        week: 2_894,
+        stats: vec![contract::TraderStat {
+            wallet: address(0x0a),
+            volume: "100".to_string(),
+            trades: 1,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    // The day's volume is all from this block, the all-time one was 200 before it.
+    let total = |key: &str| match key.rsplit(':').next() {
+        Some("volume") if key.starts_with("all:") => BigInt::from(300),
+        Some("volume") => BigInt::from(100),
+        _ => BigInt::one(),
+    };
+
+    let moves: Vec<_> = leaderboard::bucket_moves(&stats, total)
+        .into_iter()
+        .map(|m| (m.window, m.metric, m.from, m.to))
+        .collect();
+    assert_eq!(
+        moves,
+        [
+            ("day:20255".to_string(), "volume", 0, 7),
+            ("day:20255".to_string(), "trades", 0, 1),
+            ("week:2894".to_string(), "volume", 0, 7),
+            ("week:2894".to_string(), "trades", 0, 1),
+            ("all".to_string(), "volume", 8, 9),
+            ("all".to_string(), "trades", 0, 1),
+        ]
+    );
+}
+
+#[test]
+fn windows_before_the_last_one_expire() {
+    assert!(leaderboard::expired_windows(0, 20_255).is_empty());
+    assert!(leaderboard::expired_windows(20_255, 20_255).is_empty());
+    assert_eq!(leaderboard::expired_windows(20_255, 20_256), 20_254..=20_254);
+    // Days without fills in between: the previous day kept goes too.
+    assert_eq!(leaderboard::expired_windows(20_255, 20_260), 20_254..=20_258);
+}
+
+#[test]
+fn wallets_moving_into_a_bucket_become_candidates_while_it_has_room() {
+    let stats = contract::TraderStats {
+        day: 20_255,
+        week: 2_894,
+        stats: vec![contract::TraderStat {
+            wallet: address(0x0a),
+            volume: "100".to_string(),
+            realized_pnl: "-200".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    // The wallet's all-time PnL falls from 300 to 100, and its volume is all from this block.
+    let total = |key: &str| match key.rsplit(':').next() {
+        Some("realized_pnl") if key.starts_with("all:") => BigInt::from(100),
+        Some("realized_pnl") => BigInt::from(-200),
+        _ => BigInt::from(100),
+    };
+    // The weekly volume board is full above bucket 7.
+    let above = |window: &str, metric: &str, bucket: u32| match (window, metric, bucket) {
+        ("week:2894", "volume", 7) => leaderboard::MAX_TOP as i64,
+        _ => 0,
+    };
+
+    let admitted: Vec<_> = leaderboard::admissions(&stats, total, above)
+        .into_iter()
+        .map(|m| (m.window, m.metric, m.from, m.to))
+        .collect();
+    assert_eq!(
+        admitted,
+        [
+            ("day:20255".to_string(), "volume", 0, 7),
+            ("all".to_string(), "volume", 0, 7),
+            ("all".to_string(), "realized_pnl", 9, 7),
+        ]
+    );
+}
+
+#[test]
+fn boards_stop_scanning_once_full() {
+    let lowest = Cell::new(u32::MAX);
+    let candidates = |bucket: u32| {
+        lowest.set(bucket);
+        match bucket {
+            10 => vec![address(0x0a)],
+            8 => vec![address(0x0b), address(0x0a), address(0x0c)],
+            3 => vec![address(0x0d)],
+            _ => vec![],
+        }
+    };
+    // 0x0c entered bucket 8 but fell back, as realized PnL can.
+    let total = |wallet: &[u8]| match wallet[0] {
+        0x0a => BigInt::from(600),
+        0x0b => BigInt::from(200),
+        0x0c => BigInt::from(100),
+        0x0d => BigInt::from(5),
+        _ => BigInt::zero(),
+    };
+
+    let board: Vec<_> = leaderboard::top(2, candidates, total)
+        .into_iter()
+        .map(|e| (e.rank, e.wallet[0], e.value))
+        .collect();
+    assert_eq!(board, [(1, 0x0a, "600".to_string()), (2, 0x0b, "200".to_string())]);
+    assert_eq!(lowest.get(), 8);
+
+    let board: Vec<_> = leaderboard::top(5, candidates, total).into_iter().map(|e| e.wallet[0]).collect();
+    assert_eq!(board, [0x0a, 0x0b, 0x0c, 0x0d]);
+    assert_eq!(lowest.get(), 1);
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::{address, fill, time};
+use nooroo::market_stats::{self, TraderMove};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+use std::collections::HashMap;
+use std::str::FromStr;
+use substreams::scalar::{BigDecimal, BigInt};
+
+const HOUR: i64 = 486_120;
+
+/// `maker` buying 100 of `token_id` for 40 of collateral from `taker`, in block 100.
+fn buy(ordinal: u64, token_id: &str, maker: u8, taker: u8) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        evt_block_number: 100,
+        evt_block_time: time(HOUR * 3600 + 60),
+        taker_asset_id: token_id.to_string(),
+        ..fill(ordinal, maker, taker, Side::Buy, "100", "40")
+    }
+}
+
+fn trades() -> Vec<market_stats::MarketTrade> {
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            buy(2, "1", 0x0a, 0x0b),
+            buy(4, "1", 0x0a, 0x0d),
+            buy(5, "1", 0x0d, 0xee),
+            buy(6, "3", 0x0e, 0x0b),
+            buy(8, "9", 0x0a, 0x0b),
+        ],
+        ..Default::default()
+    };
+    let condition_of = |token_id: &str| match token_id {
+        "1" | "2" => Some(address(0xc1)),
+        "3" | "4" => Some(address(0xc2)),
+        _ => None,
+    };
+    market_stats::market_trades(&events, &address(0xee), condition_of)
+}
+
+#[test]
+fn windows_expire_hour_by_hour() {
+    assert_eq!(market_stats::window(HOUR), HOUR - 23..=HOUR);
+    assert!(market_stats::expired_hours(0, HOUR).is_empty());
+    assert!(market_stats::expired_hours(HOUR, HOUR).is_empty());
+    assert_eq!(market_stats::expired_hours(HOUR, HOUR + 1), HOUR - 23..=HOUR - 23);
+    assert_eq!(market_stats::expired_hours(HOUR, HOUR + 100), HOUR - 23..=HOUR);
+}
+
+#[test]
+fn traders_move_to_the_hour_of_their_last_trade() {
+    let trades = trades();
+    // (ordinal, market), the exchange as taker and the unregistered token left out.
+    let summary: Vec<_> = trades.iter().map(|t| (t.ordinal, t.condition_id[0])).collect();
+    assert_eq!(summary, [(2, 0xc1), (4, 0xc1), (6, 0xc2)]);
+
+    // 0x0a traded the first market earlier this hour, 0x0b ten hours ago and 0x0e a week ago.
+    let previous_hour = |condition_id: &[u8], wallet: &[u8], _ordinal: u64| match (condition_id[0], wallet[0]) {
+        (0xc1, 0x0a) => Some(HOUR),
+        (0xc1, 0x0b) => Some(HOUR - 10),
+        (0xc2, 0x0e) => Some(HOUR - 168),
+        _ => None,
+    };
+    let moves = market_stats::trader_moves(&trades, previous_hour);
+
+    let moved = |ordinal, market, new, from| TraderMove { ordinal, condition_id: address(market), new, from, to: HOUR };
+    assert_eq!(
+        moves,
+        [
+            moved(2, 0xc1, false, Some(HOUR - 10)),
+            moved(4, 0xc1, true, None),
+            moved(6, 0xc2, false, None),
+            moved(6, 0xc2, true, None),
+        ]
+    );
+}
+
+#[test]
+fn stats_sum_the_hours_of_the_window() {
+    let mut totals = HashMap::new();
+    totals.insert(market_stats::total_key(&address(0xc1), "trades"), 50);
+    totals.insert(market_stats::total_key(&address(0xc1), "traders"), 12);
+    totals.insert(market_stats::total_key(&address(0xc1), "volume"), 4_000);
+    for (hour, trades) in [(HOUR - 24, 7), (HOUR - 23, 3), (HOUR, 2)] {
+        totals.insert(market_stats::hourly_key(hour, &address(0xc1), "trades"), trades);
+    }
+    let total = |key: &str| BigInt::from(totals.get(key).copied().unwrap_or_default());
+
+    let last_price = |token_id: &str| match token_id {
+        "1" => BigDecimal::from_str("0.6").ok(),
+        "3" => BigDecimal::from_str("0.3").ok(),
+        _ => None,
+    };
+    // Token 1 first traded in the window five hours ago, at 0.5 before it.
+    let open = |hour: i64, token_id: &str| match (hour, token_id) {
+        (h, "1") if h == HOUR - 5 => BigDecimal::from_str("0.5").ok(),
+        (h, "1") if h == HOUR => BigDecimal::from_str("0.55").ok(),
+        _ => None,
+    };
+    let market_tokens = |condition_id: &[u8]| match condition_id[0] {
+        0xc1 => vec!["1".to_string(), "2".to_string()],
+        _ => vec!["3".to_string(), "4".to_string()],
+    };
+
+    let updates = market_stats::market_stats(&trades(), market_tokens, total, last_price, open);
+    assert_eq!(updates.markets.len(), 2);
+
+    let stats = &updates.markets[0];
+    assert_eq!(
+        (stats.trades, stats.traders, stats.volume.as_str(), stats.trades_24h, stats.block_number),
+        (50, 12, "4000", 5, 100)
+    );
+    let prices = (stats.price0.as_str(), stats.price0_24h_ago.as_str(), stats.change0_24h.as_str());
+    assert_eq!(prices, ("0.6", "0.5", "0.1"));
+    assert_eq!((stats.token1.as_str(), stats.price1.as_str(), stats.change1_24h.as_str()), ("2", "", ""));
+
+    // No open in the window: the price is where it was a day ago.
+    let stats = &updates.markets[1];
+    assert_eq!((stats.price0.as_str(), stats.change0_24h.as_str()), ("0.3", "0.0"));
+}
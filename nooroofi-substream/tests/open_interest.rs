@@ -0,0 +1,91 @@
+use nooroo::open_interest;
+use nooroo_core::ctf;
+use nooroo_core::pb::contract::v1 as contract;
+
+const EXCHANGE: [u8; 20] = nooroo_core::EXCHANGE;
+
+fn market() -> Vec<u8> {
+    vec![0xcc; 32]
+}
+
+fn split(stakeholder: &[u8], partition: &[&str], amount: &str) -> contract::CtfPositionSplit {
+    contract::CtfPositionSplit {
+        evt_block_number: 7,
+        stakeholder: stakeholder.to_vec(),
+        collateral_token: ctf::EXCHANGE_COLLATERAL.to_vec(),
+        parent_collection_id: vec![0; 32],
+        condition_id: market(),
+        partition: partition.iter().map(|p| p.to_string()).collect(),
+        amount: amount.to_string(),
+        ..Default::default()
+    }
+}
+
+fn merge(stakeholder: &[u8], amount: &str) -> contract::CtfPositionsMerge {
+    let split = split(stakeholder, &["1", "2"], amount);
+    contract::CtfPositionsMerge {
+        stakeholder: split.stakeholder,
+        collateral_token: split.collateral_token,
+        parent_collection_id: split.parent_collection_id,
+        condition_id: split.condition_id,
+        partition: split.partition,
+        amount: split.amount,
+        ..Default::default()
+    }
+}
+
+fn changes(events: &contract::Events) -> contract::OpenInterestChanges {
+    open_interest::open_interest_changes(events, &EXCHANGE, |condition_id| if condition_id == market() { 2 } else { 0 })
+}
+
+#[test]
+fn complete_sets_move_open_interest() {
+    let mut deep_split = split(&[0x01; 20], &["1", "2"], "1000");
+    deep_split.parent_collection_id = vec![0xab; 32];
+    let mut other_market = split(&[0x01; 20], &["1", "2"], "1000");
+    other_market.condition_id = vec![0xdd; 32];
+
+    let events = contract::Events {
+        ctf_position_splits: vec![
+            split(&[0x01; 20], &["1", "2"], "300"),
+            split(&EXCHANGE, &["1", "2"], "200"),
+            split(&[0x01; 20], &["1"], "1000"),
+            deep_split,
+            other_market,
+        ],
+        ctf_positions_merges: vec![merge(&[0x02; 20], "50"), merge(&EXCHANGE, "20")],
+        ctf_payout_redemptions: vec![contract::CtfPayoutRedemption {
+            collateral_token: ctf::EXCHANGE_COLLATERAL.to_vec(),
+            parent_collection_id: vec![0; 32],
+            condition_id: market(),
+            payout: "30".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let [change] = changes(&events).changes.try_into().unwrap();
+    assert_eq!(
+        change,
+        contract::OpenInterestChange {
+            condition_id: market(),
+            block_number: 7,
+            split: "300".to_string(),
+            merged: "50".to_string(),
+            exchange_minted: "200".to_string(),
+            exchange_merged: "20".to_string(),
+            redeemed: "30".to_string(),
+            delta: "400".to_string(),
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn blocks_without_complete_sets_have_no_changes() {
+    let events = contract::Events {
+        ctf_position_splits: vec![split(&[0x01; 20], &["1"], "1000")],
+        ..Default::default()
+    };
+    assert!(changes(&events).changes.is_empty());
+}
@@ -0,0 +1,150 @@
+#![cfg(all(feature = "calls", feature = "trading"))]
+
+mod common;
+
+use common::{address, fill, TOKEN};
+use nooroo::operator;
+use nooroo_core::abi::polymarketctf_contract::functions;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::{Side, COLLATERAL_ASSET_ID};
+use nooroo_core::EXCHANGE;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+const OPERATOR: u8 = 0x0e;
+
+type AbiOrder = (BigInt, Vec<u8>, Vec<u8>, Vec<u8>, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, BigInt, Vec<u8>);
+
+/// An order of `maker` in the tuple form of the generated ABI bindings.
+fn abi_order(maker: u8) -> AbiOrder {
+    (
+        BigInt::from(maker as u32),
+        address(maker),
+        address(maker),
+        address(0),
+        BigInt::from(1234),
+        BigInt::from(40),
+        BigInt::from(100),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        BigInt::from(0),
+        vec![0xab; 65],
+    )
+}
+
+/// Transaction `seed` of the operator, at index `seed`, calling the exchange with `input`.
+fn tx(seed: u8, status: eth::TransactionTraceStatus, input: Vec<u8>, call_reverted: bool) -> eth::TransactionTrace {
+    eth::TransactionTrace {
+        hash: vec![seed; 32],
+        index: seed as u32,
+        from: address(OPERATOR),
+        gas_used: 100_000 * seed as u64,
+        gas_price: Some(eth::BigInt { bytes: 30_000_000_000u64.to_be_bytes().to_vec() }),
+        status: status as i32,
+        calls: vec![eth::Call {
+            index: 1,
+            caller: address(OPERATOR),
+            address: EXCHANGE.to_vec(),
+            input,
+            state_reverted: call_reverted,
+            status_reverted: call_reverted,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn match_orders() -> Vec<u8> {
+    functions::MatchOrders {
+        taker_order: abi_order(0x0a),
+        maker_orders: vec![abi_order(0x0b), abi_order(0x0c)],
+        taker_fill_amount: BigInt::from(80),
+        maker_fill_amounts: vec![BigInt::from(100), BigInt::from(100)],
+    }
+    .encode()
+}
+
+fn fill_orders() -> Vec<u8> {
+    functions::FillOrders {
+        orders: vec![abi_order(0x0b), abi_order(0x0c)],
+        fill_amounts: vec![BigInt::from(100), BigInt::from(100)],
+    }
+    .encode()
+}
+
+/// The fills and fees of the `matchOrders` in transaction 0x01.
+fn events() -> contract::Events {
+    let in_tx = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: "01".repeat(32),
+        ..fill
+    };
+    contract::Events {
+        polymarketctf_order_filleds: vec![
+            in_tx(fill(1, 0x0b, 0x0a, Side::Sell, "100", "40")),
+            in_tx(fill(2, 0x0c, 0x0a, Side::Sell, "100", "40")),
+            // The taker order against the exchange, already counted by the makers.
+            in_tx(contract::PolymarketctfOrderFilled { taker: EXCHANGE.to_vec(), ..fill(3, 0x0a, 0x00, Side::Buy, "200", "80") }),
+        ],
+        polymarketctf_fee_chargeds: vec![
+            contract::PolymarketctfFeeCharged {
+                evt_tx_hash: "01".repeat(32),
+                token_id: COLLATERAL_ASSET_ID.to_string(),
+                amount: "2".to_string(),
+                ..Default::default()
+            },
+            contract::PolymarketctfFeeCharged {
+                evt_tx_hash: "01".repeat(32),
+                token_id: TOKEN.to_string(),
+                amount: "5".to_string(),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn batches_are_read_from_every_transaction_trace() {
+    let blk = eth::Block {
+        number: 75_000_000,
+        header: Some(eth::BlockHeader { timestamp: Some(prost_types::Timestamp { seconds: 1_750_000_000, nanos: 0 }), ..Default::default() }),
+        transaction_traces: vec![
+            // A reverted fillOrders, seen only in the traces.
+            tx(2, eth::TransactionTraceStatus::Reverted, fill_orders(), true),
+            tx(1, eth::TransactionTraceStatus::Succeeded, match_orders(), false),
+            // A transaction that succeeded around a reverted fillOrders.
+            tx(3, eth::TransactionTraceStatus::Succeeded, fill_orders(), true),
+        ],
+        ..Default::default()
+    };
+
+    let batches = operator::operator_batches(&blk, &events(), &EXCHANGE).batches;
+
+    // (index, match calls, fill calls, makers, notional, reverted)
+    let summary: Vec<_> = batches
+        .iter()
+        .map(|b| (b.tx_index, b.match_orders_calls, b.fill_orders_calls, b.makers_matched, b.notional.as_str(), b.reverted))
+        .collect();
+    assert_eq!(summary, [(1, 1, 0, 2, "80", false), (2, 0, 1, 0, "0", true), (3, 0, 1, 0, "0", true)]);
+
+    let matched = &batches[0];
+    assert_eq!((matched.operator.as_slice(), matched.block_number), (address(OPERATOR).as_slice(), 75_000_000));
+    assert_eq!((matched.collateral_fees.as_str(), matched.outcome_token_fees.as_str()), ("2", "5"));
+
+    // The reverted transaction still paid for its gas.
+    let reverted = &batches[1];
+    assert_eq!((reverted.tx_hash.as_str(), reverted.gas_used, reverted.gas_price.as_str()), ("02".repeat(32).as_str(), 200_000, "30000000000"));
+    assert_eq!(operator::gas_cost_wei(reverted).to_string(), "6000000000000000");
+    assert_eq!(reverted.gas_cost, "0.006000000000000000");
+}
+
+#[test]
+fn other_calls_are_not_batches() {
+    let blk = eth::Block {
+        transaction_traces: vec![tx(1, eth::TransactionTraceStatus::Succeeded, functions::IncrementNonce {}.encode(), false)],
+        ..Default::default()
+    };
+    assert!(operator::operator_batches(&blk, &events(), &EXCHANGE).batches.is_empty());
+}
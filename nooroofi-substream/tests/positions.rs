@@ -0,0 +1,100 @@
+mod common;
+
+use common::address;
+use nooroo::positions;
+use nooroo_core::pb::contract::v1 as contract;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas};
+
+const YES: &str = "1111";
+const NO: &str = "2222";
+
+fn single(ordinal: u64, from: u8, to: u8, id: &str, value: &str) -> contract::CtfTransferSingle {
+    contract::CtfTransferSingle {
+        evt_tx_hash: "aa".to_string(),
+        evt_ordinal: ordinal,
+        operator: address(0xe0),
+        from: address(from),
+        to: address(to),
+        id: id.to_string(),
+        value: value.to_string(),
+        ..Default::default()
+    }
+}
+
+fn delta(ordinal: u64, key: &str, old_value: i64, new_value: i64) -> DeltaBigInt {
+    DeltaBigInt {
+        operation: Operation::Update,
+        ordinal,
+        key: key.to_string(),
+        old_value: BigInt::from(old_value),
+        new_value: BigInt::from(new_value),
+    }
+}
+
+#[test]
+fn transfers_move_both_holders_and_skip_the_zero_address() {
+    let events = contract::Events {
+        ctf_transfer_singles: vec![single(9, 0x01, 0x02, YES, "40"), single(3, 0x00, 0x01, YES, "100")],
+        ctf_transfer_batches: vec![contract::CtfTransferBatch {
+            evt_ordinal: 5,
+            operator: address(0xe0),
+            from: address(0x01),
+            to: address(0x00),
+            ids: vec![YES.to_string(), NO.to_string()],
+            values: vec!["10".to_string(), "0".to_string()],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let changes = positions::position_changes(&events).changes;
+    assert!(changes.iter().all(|c| c.position_id == YES && c.operator == address(0xe0)));
+
+    // (ordinal, holder, delta, counterparty), addresses by seed.
+    let summary: Vec<_> = changes
+        .iter()
+        .map(|c| (c.ordinal, c.holder[0], c.delta.as_str(), c.counterparty[0]))
+        .collect();
+    assert_eq!(
+        summary,
+        [(3, 0x01, "100", 0x00), (5, 0x01, "-10", 0x00), (9, 0x01, "-40", 0x02), (9, 0x02, "40", 0x01)]
+    );
+}
+
+#[test]
+fn balances_span_the_whole_block() {
+    let holder = positions::balance_key(&address(0x01), YES);
+    let other = positions::balance_key(&address(0x02), YES);
+    let deltas = Deltas {
+        deltas: vec![
+            delta(3, &holder, 0, 100),
+            delta(5, &holder, 100, 90),
+            delta(9, &holder, 90, 50),
+            delta(9, &other, 7, 47),
+            delta(9, "unrelated", 0, 1),
+        ],
+    };
+
+    let balances = positions::position_balances(&deltas).balances;
+    assert_eq!(
+        balances,
+        [
+            contract::PositionBalance {
+                holder: address(0x01),
+                position_id: YES.to_string(),
+                old_balance: "0".to_string(),
+                new_balance: "50".to_string(),
+                ordinal: 9,
+            },
+            contract::PositionBalance {
+                holder: address(0x02),
+                position_id: YES.to_string(),
+                old_balance: "7".to_string(),
+                new_balance: "47".to_string(),
+                ordinal: 9,
+            },
+        ]
+    );
+}
@@ -0,0 +1,76 @@
+use nooroo::probability;
+use nooroo_core::pb::contract::v1 as contract;
+use std::collections::HashMap;
+use std::str::FromStr;
+use substreams::scalar::BigDecimal;
+
+const YES: &str = "1111";
+const NO: &str = "2222";
+
+fn decimal(value: &str) -> BigDecimal {
+    BigDecimal::from_str(value).unwrap()
+}
+
+/// A buy of `token_id` at `notional / 100`.
+fn buy(token_id: &str, notional: &str) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        evt_block_number: 7,
+        maker_asset_id: "0".to_string(),
+        taker_asset_id: token_id.to_string(),
+        maker_amount_filled: notional.to_string(),
+        taker_amount_filled: "100".to_string(),
+        ..Default::default()
+    }
+}
+
+fn probabilities(fills: Vec<contract::PolymarketctfOrderFilled>, prices: &[(&str, &str)]) -> contract::ImpliedProbabilities {
+    let events = contract::Events {
+        polymarketctf_order_filleds: fills,
+        ..Default::default()
+    };
+    let prices: HashMap<&str, BigDecimal> = prices.iter().map(|(token, price)| (*token, decimal(price))).collect();
+
+    probability::implied_probabilities(
+        &events,
+        &decimal("0.02"),
+        |token_id| [YES, NO].contains(&token_id).then(|| (vec![0xcc; 32], [YES.to_string(), NO.to_string()])),
+        |token_id| prices.get(token_id).cloned(),
+    )
+}
+
+#[test]
+fn probabilities_normalize_the_last_prices() {
+    let output = probabilities(vec![buy(YES, "60"), buy("9999", "10")], &[(YES, "0.6"), (NO, "0.41")]);
+
+    let [probability] = output.probabilities.try_into().unwrap();
+    assert_eq!((probability.token0.as_str(), probability.token1.as_str()), (YES, NO));
+    assert_eq!(decimal(&probability.probability0), decimal("0.6") / &decimal("1.01"));
+    assert_eq!(decimal(&probability.probability1), decimal("0.41") / &decimal("1.01"));
+    assert!(output.inconsistencies.is_empty());
+}
+
+#[test]
+fn prices_off_by_more_than_the_tolerance_are_inconsistent() {
+    let output = probabilities(vec![buy(NO, "30")], &[(YES, "0.6"), (NO, "0.3")]);
+
+    let [inconsistency] = output.inconsistencies.try_into().unwrap();
+    assert_eq!(decimal(&inconsistency.sum), decimal("0.9"));
+    assert_eq!(inconsistency.block_number, 7);
+}
+
+#[test]
+fn a_single_price_implies_its_complement() {
+    let output = probabilities(vec![buy(YES, "70")], &[(YES, "0.7")]);
+
+    let [probability] = output.probabilities.try_into().unwrap();
+    assert_eq!(probability.price1, "");
+    assert_eq!(decimal(&probability.probability1), decimal("0.3"));
+}
+
+#[test]
+fn tolerance_comes_from_the_params() {
+    assert_eq!(probability::parse_tolerance("tolerance=0.05").unwrap(), decimal("0.05"));
+    assert_eq!(probability::parse_tolerance("").unwrap(), decimal(probability::DEFAULT_TOLERANCE));
+    assert!(probability::parse_tolerance("tolerance=abc").is_err());
+    assert!(probability::parse_tolerance("slippage=1").is_err());
+}
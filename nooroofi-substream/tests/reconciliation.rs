@@ -0,0 +1,152 @@
+mod common;
+
+use common::{address, fill, order, TOKEN};
+use nooroo::reconciliation;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+
+use contract::reconciliation_anomaly::Kind;
+
+/// A block whose exchange calls emitted everything they should: transaction `aa` matches the
+/// buy of 0x0a against the sell of 0x0b, paying a fee of 1, and transaction `bb` cancels an
+/// order of 0x0c.
+fn block() -> (contract::Events, contract::Calls) {
+    let in_tx = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: "aa".to_string(),
+        ..fill
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            in_tx(contract::PolymarketctfOrderFilled {
+                fee: "1".to_string(),
+                ..fill(1, 0x0b, 0x0a, Side::Sell, "100", "40")
+            }),
+            in_tx(fill(2, 0x0a, 0xee, Side::Buy, "100", "40")),
+        ],
+        polymarketctf_orders_matcheds: vec![contract::PolymarketctfOrdersMatched {
+            evt_tx_hash: "aa".to_string(),
+            taker_order_maker: address(0x0a),
+            ..Default::default()
+        }],
+        polymarketctf_fee_chargeds: vec![contract::PolymarketctfFeeCharged {
+            evt_tx_hash: "aa".to_string(),
+            receiver: address(0xfe),
+            token_id: TOKEN.to_string(),
+            amount: "1".to_string(),
+            ..Default::default()
+        }],
+        polymarketctf_order_cancelleds: vec![contract::PolymarketctfOrderCancelled {
+            evt_tx_hash: "bb".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let calls = contract::Calls {
+        polymarketctf_call_match_orders: vec![contract::PolymarketctfMatchOrdersCall {
+            call_tx_hash: "aa".to_string(),
+            call_success: true,
+            taker_order: Some(order(0x0a, Side::Buy, "40", "100")),
+            maker_orders: vec![order(0x0b, Side::Sell, "100", "40")],
+            ..Default::default()
+        }],
+        polymarketctf_call_cancel_orders: vec![contract::PolymarketctfCancelOrderCall {
+            call_tx_hash: "bb".to_string(),
+            call_success: true,
+            order: Some(order(0x0c, Side::Buy, "40", "100")),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    (events, calls)
+}
+
+/// `(tx hash, kind, expected, actual, detail)` of every anomaly.
+fn anomalies(events: &contract::Events, calls: &contract::Calls) -> Vec<(String, i32, u32, u32, String)> {
+    reconciliation::reconcile(events, calls)
+        .anomalies
+        .into_iter()
+        .map(|a| (a.tx_hash, a.kind, a.expected, a.actual, a.detail))
+        .collect()
+}
+
+fn anomaly(tx_hash: &str, kind: Kind, expected: u32, actual: u32, detail: &str) -> (String, i32, u32, u32, String) {
+    (tx_hash.to_string(), kind as i32, expected, actual, detail.to_string())
+}
+
+#[test]
+fn emitted_events_reconcile() {
+    let (events, calls) = block();
+    let reconciliation = reconciliation::reconcile(&events, &calls);
+    assert_eq!(reconciliation.transactions_checked, 2);
+    assert!(reconciliation.anomalies.is_empty());
+}
+
+#[test]
+fn failed_calls_expect_nothing() {
+    let (_, mut calls) = block();
+    calls.polymarketctf_call_match_orders[0].call_success = false;
+    calls.polymarketctf_call_cancel_orders[0].call_success = false;
+    let events = contract::Events::default();
+    assert!(anomalies(&events, &calls).is_empty());
+}
+
+#[test]
+fn a_missing_fill_is_an_unmatched_maker() {
+    let (mut events, calls) = block();
+    events.polymarketctf_order_filleds.remove(0);
+    let maker = format!("maker 0x{}", "0b".repeat(20));
+    assert_eq!(
+        anomalies(&events, &calls),
+        [
+            anomaly("aa", Kind::OrderFilledCountMismatch, 2, 1, ""),
+            anomaly("aa", Kind::OrderFilledMakerMismatch, 1, 0, &maker),
+            // Its fee has no fill to pay it either.
+            anomaly("aa", Kind::UnmatchedFeeCharged, 1, 0, &format!("fee 1 on token {} to 0x{}", TOKEN, "fe".repeat(20))),
+        ]
+    );
+}
+
+#[test]
+fn a_fill_for_another_maker_is_unmatched() {
+    let (mut events, calls) = block();
+    events.polymarketctf_order_filleds[0].maker = address(0x0d);
+    let maker = format!("maker 0x{}", "0b".repeat(20));
+    assert_eq!(anomalies(&events, &calls), [anomaly("aa", Kind::OrderFilledMakerMismatch, 1, 0, &maker)]);
+}
+
+#[test]
+fn a_missing_orders_matched_is_reported() {
+    let (mut events, calls) = block();
+    events.polymarketctf_orders_matcheds.clear();
+    let taker = format!("taker order maker 0x{}", "0a".repeat(20));
+    assert_eq!(
+        anomalies(&events, &calls),
+        [
+            anomaly("aa", Kind::OrdersMatchedCountMismatch, 1, 0, ""),
+            anomaly("aa", Kind::OrdersMatchedTakerMismatch, 1, 0, &taker),
+        ]
+    );
+}
+
+#[test]
+fn an_orders_matched_for_another_taker_is_reported() {
+    let (mut events, calls) = block();
+    events.polymarketctf_orders_matcheds[0].taker_order_maker = address(0x0b);
+    let taker = format!("taker order maker 0x{}", "0a".repeat(20));
+    assert_eq!(anomalies(&events, &calls), [anomaly("aa", Kind::OrdersMatchedTakerMismatch, 1, 0, &taker)]);
+}
+
+#[test]
+fn a_missing_cancellation_is_reported() {
+    let (mut events, calls) = block();
+    events.polymarketctf_order_cancelleds.clear();
+    assert_eq!(anomalies(&events, &calls), [anomaly("bb", Kind::OrderCancelledCountMismatch, 1, 0, "")]);
+}
+
+#[test]
+fn a_fee_no_fill_carries_is_reported() {
+    let (mut events, calls) = block();
+    events.polymarketctf_fee_chargeds[0].amount = "2".to_string();
+    let detail = format!("fee 2 on token {} to 0x{}", TOKEN, "fe".repeat(20));
+    assert_eq!(anomalies(&events, &calls), [anomaly("aa", Kind::UnmatchedFeeCharged, 1, 0, &detail)]);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::{fill, order};
+use nooroo::records;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+
+use contract::exchange_record::Body;
+
+/// A `matchOrders` at ordinal 10 of transaction `aa` emitting its fills and `OrdersMatched`,
+/// then a `cancelOrder` at ordinal 20 of transaction `bb`, with the records of each type given
+/// out of execution order.
+fn block() -> (contract::Events, contract::Calls) {
+    let in_tx = |tx_hash: &str, fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        evt_block_number: 100,
+        evt_block_hash: "ff".to_string(),
+        ..fill
+    };
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            in_tx("aa", fill(13, 0x0a, 0xee, Side::Buy, "100", "40")),
+            in_tx("aa", fill(12, 0x0b, 0x0a, Side::Sell, "100", "40")),
+        ],
+        polymarketctf_orders_matcheds: vec![contract::PolymarketctfOrdersMatched {
+            evt_tx_hash: "aa".to_string(),
+            evt_ordinal: 14,
+            ..Default::default()
+        }],
+        polymarketctf_order_cancelleds: vec![contract::PolymarketctfOrderCancelled {
+            evt_tx_hash: "bb".to_string(),
+            evt_ordinal: 22,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let calls = contract::Calls {
+        polymarketctf_call_cancel_orders: vec![contract::PolymarketctfCancelOrderCall {
+            call_tx_hash: "bb".to_string(),
+            call_ordinal: 20,
+            call_success: true,
+            order: Some(order(0x0c, Side::Buy, "40", "100")),
+            ..Default::default()
+        }],
+        polymarketctf_call_match_orders: vec![contract::PolymarketctfMatchOrdersCall {
+            call_tx_hash: "aa".to_string(),
+            call_block_number: 100,
+            call_block_hash: "ff".to_string(),
+            call_ordinal: 10,
+            call_success: true,
+            taker_order: Some(order(0x0a, Side::Buy, "40", "100")),
+            maker_orders: vec![order(0x0b, Side::Sell, "100", "40")],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    (events, calls)
+}
+
+fn kind(body: &Option<Body>) -> &'static str {
+    match body {
+        Some(Body::OrderFilled(_)) => "OrderFilled",
+        Some(Body::OrdersMatched(_)) => "OrdersMatched",
+        Some(Body::OrderCancelled(_)) => "OrderCancelled",
+        Some(Body::MatchOrdersCall(_)) => "matchOrders",
+        Some(Body::CancelOrderCall(_)) => "cancelOrder",
+        _ => "other",
+    }
+}
+
+#[test]
+fn calls_and_events_are_merged_in_execution_order() {
+    let (events, calls) = block();
+    let records = records::exchange_records(events, calls).records;
+
+    let summary: Vec<_> = records.iter().map(|r| (r.ordinal, r.tx_hash.as_str(), kind(&r.body))).collect();
+    assert_eq!(
+        summary,
+        [
+            (10, "aa", "matchOrders"),
+            (12, "aa", "OrderFilled"),
+            (13, "aa", "OrderFilled"),
+            (14, "aa", "OrdersMatched"),
+            (20, "bb", "cancelOrder"),
+            (22, "bb", "OrderCancelled"),
+        ]
+    );
+}
+
+#[test]
+fn records_carry_their_event_or_call_whole() {
+    let (events, calls) = block();
+    let records = records::exchange_records(events.clone(), calls.clone()).records;
+
+    // The block fields are copied from the event or call, which is kept unchanged.
+    assert_eq!((records[0].block_number, records[0].block_hash.as_str()), (100, "ff"));
+    assert_eq!(records[0].body, Some(Body::MatchOrdersCall(calls.polymarketctf_call_match_orders[0].clone())));
+    assert_eq!((records[1].block_number, records[1].block_hash.as_str()), (100, "ff"));
+    assert_eq!(records[1].body, Some(Body::OrderFilled(events.polymarketctf_order_filleds[1].clone())));
+    assert_eq!(records[4].body, Some(Body::CancelOrderCall(calls.polymarketctf_call_cancel_orders[0].clone())));
+}
+
+#[test]
+fn nothing_decoded_gives_no_records() {
+    let records = records::exchange_records(Default::default(), Default::default());
+    assert!(records.records.is_empty());
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use common::address;
+use nooroo::settlement::{self, Payout, SettlementState};
+use nooroo_core::ctf;
+use nooroo_core::pb::contract::v1 as contract;
+use std::collections::HashMap;
+use substreams::scalar::BigInt;
+
+const HOLDER: u8 = 0x0a;
+
+fn condition_id() -> Vec<u8> {
+    vec![0xcc; 32]
+}
+
+fn token(index_set: u64) -> String {
+    ctf::position_id(&ctf::EXCHANGE_COLLATERAL, &ctf::collection_id(&condition_id(), index_set))
+}
+
+/// The stores, as a map of each key to its value.
+#[derive(Default)]
+struct State {
+    payouts: HashMap<String, Payout>,
+    supply: HashMap<String, i64>,
+    balances: HashMap<(Vec<u8>, String), i64>,
+    holders: HashMap<String, Vec<Vec<u8>>>,
+}
+
+impl SettlementState for State {
+    fn market_tokens(&self, condition_id: &[u8]) -> Vec<String> {
+        if condition_id == self::condition_id() {
+            vec![token(1), token(2), "999".to_string()]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn market_of(&self, token_id: &str) -> Option<Vec<u8>> {
+        [token(1), token(2)].contains(&token_id.to_string()).then(condition_id)
+    }
+
+    fn payout(&self, token_id: &str) -> Option<Payout> {
+        self.payouts.get(token_id).cloned()
+    }
+
+    fn supply(&self, _ordinal: Option<u64>, token_id: &str) -> BigInt {
+        BigInt::from(self.supply.get(token_id).copied().unwrap_or_default())
+    }
+
+    fn balance(&self, holder: &[u8], token_id: &str) -> BigInt {
+        BigInt::from(self.balances.get(&(holder.to_vec(), token_id.to_string())).copied().unwrap_or_default())
+    }
+
+    fn holders(&self, token_id: &str) -> Vec<Vec<u8>> {
+        self.holders.get(token_id).cloned().unwrap_or_default()
+    }
+}
+
+/// A binary market where YES won, with `yes_supply` YES and 100 NO tokens outstanding.
+fn resolved_state(yes_supply: i64, holder_yes: i64) -> State {
+    let payout = |numerator| Payout {
+        numerator: BigInt::from(numerator),
+        denominator: BigInt::from(1),
+    };
+    State {
+        payouts: HashMap::from([(token(1), payout(1)), (token(2), payout(0))]),
+        supply: HashMap::from([(token(1), yes_supply), (token(2), 100)]),
+        balances: HashMap::from([((address(HOLDER), token(1)), holder_yes)]),
+        ..Default::default()
+    }
+}
+
+fn resolution(ordinal: u64) -> contract::CtfConditionResolution {
+    contract::CtfConditionResolution {
+        condition_id: condition_id(),
+        outcome_slot_count: "2".to_string(),
+        payout_numerators: vec!["1".to_string(), "0".to_string()],
+        evt_ordinal: ordinal,
+        ..Default::default()
+    }
+}
+
+fn burn(ordinal: u64, token_id: String, amount: &str) -> contract::PositionChange {
+    contract::PositionChange {
+        tx_hash: "aa".to_string(),
+        ordinal,
+        holder: address(HOLDER),
+        position_id: token_id,
+        delta: format!("-{}", amount),
+        counterparty: address(0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn resolutions_place_registered_tokens_among_the_outcomes() {
+    let events = contract::Events {
+        ctf_condition_resolutions: vec![resolution(4)],
+        ..Default::default()
+    };
+
+    let settlements = settlement::settlements(&events, &Default::default(), &resolved_state(100, 0));
+    let [resolution] = settlements.resolutions.as_slice() else {
+        panic!("expected one resolution");
+    };
+    let outcomes: Vec<_> = resolution
+        .outcomes
+        .iter()
+        .map(|o| (o.token_id.clone(), o.outcome_index, o.winning, o.payout.as_str()))
+        .collect();
+    assert_eq!(outcomes, [(token(1), 0, true, "100"), (token(2), 1, false, "0")]);
+    assert_eq!(resolution.unmatched_token_ids, ["999"]);
+    assert_eq!(resolution.total_payout, "100");
+
+    let [market] = settlements.markets.as_slice() else {
+        panic!("expected one market");
+    };
+    assert_eq!((market.redeemed.as_str(), market.outstanding.as_str()), ("0", "100"));
+}
+
+#[test]
+fn every_holder_of_a_resolved_market_gets_a_payout() {
+    let events = contract::Events {
+        ctf_condition_resolutions: vec![resolution(4)],
+        ..Default::default()
+    };
+    // 0x0b holds losing NO tokens, 0x0c sold all of its YES and 0x0a holds YES on both lists.
+    let mut state = resolved_state(100, 60);
+    state.balances.insert((address(0x0b), token(2)), 100);
+    state.holders = HashMap::from([
+        (token(1), vec![address(HOLDER), address(0x0c)]),
+        (token(2), vec![address(0x0b), address(HOLDER)]),
+    ]);
+    // A transfer to 0x0a later in the block, none of its positions moving before.
+    let changes = contract::PositionChanges {
+        changes: vec![contract::PositionChange {
+            ordinal: 9,
+            holder: address(HOLDER),
+            position_id: token(1),
+            delta: "10".to_string(),
+            counterparty: address(0x0c),
+            ..Default::default()
+        }],
+    };
+
+    let settlements = settlement::settlements(&events, &changes, &state);
+    let holders: Vec<_> = settlements.holder_payouts.iter().map(|h| (h.holder[0], h.redeemable.as_str(), h.ordinal)).collect();
+    assert_eq!(holders, [(HOLDER, "60", 9), (0x0b, "0", 4)]);
+}
+
+#[test]
+fn redemptions_are_checked_against_the_burned_tokens() {
+    let events = contract::Events {
+        ctf_payout_redemptions: vec![contract::CtfPayoutRedemption {
+            evt_tx_hash: "aa".to_string(),
+            evt_ordinal: 9,
+            redeemer: address(HOLDER),
+            condition_id: condition_id(),
+            index_sets: vec!["1".to_string(), "2".to_string()],
+            payout: "60".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let changes = contract::PositionChanges {
+        changes: vec![burn(7, token(1), "60"), burn(8, token(2), "10")],
+    };
+
+    let settlements = settlement::settlements(&events, &changes, &resolved_state(40, 0));
+    let [redemption] = settlements.redemptions.as_slice() else {
+        panic!("expected one redemption");
+    };
+    assert_eq!(redemption.burned, "70");
+    assert_eq!(redemption.expected_payout, "60");
+    assert!(redemption.matches);
+
+    let holders: Vec<_> = settlements.holder_payouts.iter().map(|h| (h.holder[0], h.redeemable.as_str(), h.ordinal)).collect();
+    assert_eq!(holders, [(HOLDER, "0", 8)]);
+
+    let [market] = settlements.markets.as_slice() else {
+        panic!("expected one market");
+    };
+    assert_eq!((market.redeemed.as_str(), market.outstanding.as_str()), ("60", "40"));
+}
+
+#[test]
+fn unresolved_and_foreign_markets_are_left_out() {
+    let events = contract::Events {
+        ctf_payout_redemptions: vec![contract::CtfPayoutRedemption {
+            condition_id: vec![0xdd; 32],
+            payout: "5".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let changes = contract::PositionChanges {
+        changes: vec![burn(7, token(1), "60")],
+    };
+
+    let settlements = settlement::settlements(&events, &changes, &State::default());
+    assert_eq!(settlements, contract::Settlements::default());
+}
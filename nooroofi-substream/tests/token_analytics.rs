@@ -0,0 +1,99 @@
+mod common;
+
+use common::{address, fill, time};
+use nooroo::token_analytics::{self, Window};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+use std::collections::HashMap;
+use substreams::scalar::BigInt;
+
+// Noon of 2025-06-16.
+const HOUR: i64 = 486_132;
+
+/// 0x0a buying `size` for `notional` of collateral from `taker`, in block 100.
+fn buy(ordinal: u64, taker: u8, size: &str, notional: &str) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        evt_block_number: 100,
+        evt_block_time: time(HOUR * 3600 + 60),
+        ..fill(ordinal, 0x0a, taker, Side::Buy, size, notional)
+    }
+}
+
+#[test]
+fn windows_are_hours_or_days() {
+    assert_eq!(
+        token_analytics::parse_windows("").unwrap(),
+        [Window::Hours(1), Window::Hours(24), Window::Days(7)]
+    );
+    assert_eq!(token_analytics::parse_windows("windows=6h, 30d").unwrap(), [Window::Hours(6), Window::Days(30)]);
+    for params in ["windows=25h", "windows=31d", "windows=0h", "windows=1w", "window=1h"] {
+        assert!(token_analytics::parse_windows(params).is_err(), "{}", params);
+    }
+
+    assert!(token_analytics::expired_days(0, 20_000).is_empty());
+    assert_eq!(token_analytics::expired_days(20_000, 20_001), 19_971..=19_971);
+}
+
+#[test]
+fn trades_fall_in_usdc_size_buckets() {
+    let buckets = ["999999", "1000000", "99999999", "100000000000"].map(|n| token_analytics::size_bucket(&BigInt::from(n.parse::<u64>().unwrap())));
+    assert_eq!(buckets, [0, 1, 2, 6]);
+}
+
+#[test]
+fn windows_add_up_the_hours_and_days_in_them() {
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            buy(2, 0x0b, "100000000", "40000000"),
+            buy(4, 0x0c, "10000000", "4000000"),
+            // The taker order of a `matchOrders`.
+            buy(5, 0xee, "110000000", "44000000"),
+        ],
+        ..Default::default()
+    };
+    let trades = token_analytics::token_trades(&events, &address(0xee));
+    assert_eq!(trades.len(), 2);
+
+    // The block's trades, and 56 USDC for 90 tokens three hours ago.
+    let mut store: HashMap<String, BigInt> = HashMap::new();
+    let earlier = token_analytics::TokenTrade {
+        block_time: time((HOUR - 3) * 3600),
+        size: BigInt::from(90_000_000),
+        notional: BigInt::from(56_000_000),
+        ..trades[0].clone()
+    };
+    for trade in trades.iter().chain([&earlier]) {
+        for (key, value) in trade.volume_changes() {
+            let total = store.entry(key).or_insert_with(BigInt::zero);
+            *total = total.clone() + value;
+        }
+    }
+    let total = |key: &str| store.get(key).cloned().unwrap_or_else(BigInt::zero);
+
+    let analytics = token_analytics::token_analytics(&trades, &[Window::Hours(1), Window::Days(1)], total);
+    assert_eq!(analytics.tokens.len(), 1);
+    let windows = &analytics.tokens[0].windows;
+
+    // (window, trades, volume, size, vwap)
+    let summary: Vec<_> = windows
+        .iter()
+        .map(|w| (w.window.as_str(), w.trades, w.volume.as_str(), w.size.as_str(), w.vwap.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("1h", 2, "44000000", "110000000", "0.4"),
+            ("1d", 3, "100000000", "200000000", "0.5"),
+        ]
+    );
+
+    // (min, max, trades) of the non-empty buckets.
+    let buckets: Vec<_> = windows[1]
+        .size_buckets
+        .iter()
+        .filter(|b| b.trades > 0)
+        .map(|b| (b.min_usdc, b.max_usdc, b.trades))
+        .collect();
+    assert_eq!(buckets, [(1, 10, 1), (10, 100, 2)]);
+    assert_eq!((windows[1].size_buckets.len(), windows[1].size_buckets[6].max_usdc), (7, 0));
+}
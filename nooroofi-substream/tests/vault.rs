@@ -0,0 +1,55 @@
+use nooroo::vault::{self, Vault};
+use nooroo_core::pb::contract::v1 as contract;
+use substreams::scalar::BigInt;
+
+fn deposit(ordinal: u64, usdc_amount: &str, fee_amount: &str) -> contract::VaultDeposit {
+    contract::VaultDeposit {
+        evt_ordinal: ordinal,
+        user: vec![0x51; 20],
+        usdc_amount: usdc_amount.to_string(),
+        fee_amount: fee_amount.to_string(),
+        ..Default::default()
+    }
+}
+
+fn withdrawal(ordinal: u64, amount: &str, success: bool) -> contract::VaultWithdrawUsdcCall {
+    contract::VaultWithdrawUsdcCall {
+        call_ordinal: ordinal,
+        call_success: success,
+        amount: amount.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn params_name_the_vault_and_default_to_its_token() {
+    let vault = Vault::parse("vault=0x7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a&steth=").unwrap().unwrap();
+    assert_eq!(vault.vault, vec![0x7a; 20]);
+    assert_eq!(vault.steth, nooroo_core::vault::steth_address(&[0x7a; 20]));
+
+    let vault = Vault::parse("vault=0x7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a&steth=0x5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e")
+        .unwrap()
+        .unwrap();
+    assert_eq!(vault.steth, vec![0x5e; 20]);
+
+    assert_eq!(Vault::parse("vault=&steth=").unwrap(), None);
+    assert!(Vault::parse("vault=0x7a7a").is_err());
+    assert!(Vault::parse("pool=0x7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a7a").is_err());
+}
+
+#[test]
+fn tvl_keeps_net_deposits_less_successful_withdrawals() {
+    let events = contract::Events {
+        vault_deposits: vec![deposit(8, "2000000", "10000"), deposit(2, "1000000", "5000")],
+        ..Default::default()
+    };
+    let calls = contract::Calls {
+        vault_call_withdraw_usdcs: vec![withdrawal(5, "300000", true), withdrawal(9, "100", false)],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        vault::tvl_changes(&events, &calls),
+        [(2, BigInt::from(995_000)), (5, BigInt::from(-300_000)), (8, BigInt::from(1_990_000))]
+    );
+}
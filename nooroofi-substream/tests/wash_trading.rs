@@ -0,0 +1,132 @@
+mod common;
+
+use common::{address, time, TOKEN};
+use nooroo::wash_trading::{self, OwnedTrade, Params};
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+use std::collections::HashMap;
+
+use contract::suspicious_trade::Reason;
+
+const TIME: i64 = 1_750_000_000;
+
+/// `taker` selling 100 to `maker` for 40 USDC, `seconds` after `TIME`.
+fn fill(ordinal: u64, seconds: i64, maker: u8, taker: u8) -> contract::PolymarketctfOrderFilled {
+    contract::PolymarketctfOrderFilled {
+        evt_block_time: time(TIME + seconds),
+        order_hash: vec![ordinal as u8; 32],
+        ..common::fill(ordinal, maker, taker, Side::Buy, "100000000", "40000000")
+    }
+}
+
+/// Trades of `fills`, with wallet 0x1a a proxy of 0x0a.
+fn trades(fills: Vec<contract::PolymarketctfOrderFilled>) -> Vec<OwnedTrade> {
+    let events = contract::Events { polymarketctf_order_filleds: fills, ..Default::default() };
+    wash_trading::owned_trades(&events, &address(0xee), |wallet| match wallet[0] {
+        0x1a => address(0x0a),
+        _ => wallet.to_vec(),
+    })
+}
+
+/// The reasons and cycles `map_suspicious_trades` gives for `trades`, with `store_last_buyers`
+/// fed every trade at its ordinal.
+fn suspicious(trades: &[OwnedTrade], params: &Params) -> Vec<(u64, i32, Vec<u8>)> {
+    let mut store: HashMap<String, Vec<(u64, String)>> = HashMap::new();
+    for trade in trades.iter().filter(|t| t.seller != t.buyer) {
+        let key = wash_trading::buyer_key(&trade.token_id, &trade.seller);
+        let value = wash_trading::buyer_value(&trade.buyer, trade.seconds());
+        store.entry(key).or_default().push((trade.ordinal, value));
+    }
+    let last_buyer = |token_id: &str, seller: &[u8], ordinal: u64| {
+        let values = store.get(&wash_trading::buyer_key(token_id, seller))?;
+        let (_, value) = values.iter().rev().find(|(o, _)| *o <= ordinal)?;
+        wash_trading::parse_buyer(value)
+    };
+
+    wash_trading::suspicious_trades(trades, params, last_buyer)
+        .trades
+        .iter()
+        .map(|t| (t.ordinal, t.reason, t.wallets.iter().map(|w| w[0]).collect()))
+        .collect()
+}
+
+#[test]
+fn params_bound_the_window_and_cycle() {
+    assert_eq!(Params::parse("").unwrap(), Params { window: 3600, wallets: 3 });
+    assert_eq!(Params::parse("window=600 & wallets=4").unwrap(), Params { window: 600, wallets: 4 });
+    for params in ["window=0", "window=1h", "wallets=1", "wallets=5", "depth=2", "window"] {
+        assert!(Params::parse(params).is_err(), "{}", params);
+    }
+}
+
+#[test]
+fn trades_between_wallets_of_one_owner_are_self_trades() {
+    let trades = trades(vec![
+        // 0x0a buys from its own proxy.
+        fill(1, 0, 0x0a, 0x1a),
+        fill(2, 0, 0x0a, 0x0b),
+        // The fill of a `matchOrders` taker order.
+        fill(3, 0, 0x0a, 0xee),
+    ]);
+    assert_eq!(trades.len(), 2);
+    assert_eq!((trades[0].seller[0], trades[0].buyer[0]), (0x0a, 0x0a));
+
+    let params = Params::parse("").unwrap();
+    assert_eq!(suspicious(&trades, &params), [(1, Reason::SelfTrade as i32, vec![])]);
+}
+
+#[test]
+fn tokens_passed_back_to_the_seller_within_the_window_close_a_cycle() {
+    let trades = trades(vec![
+        // 0x0b -> 0x0c -> 0x0d -> 0x0b within a minute.
+        fill(1, 0, 0x0c, 0x0b),
+        fill(2, 30, 0x0d, 0x0c),
+        fill(3, 60, 0x0b, 0x0d),
+        // A day later, 0x0d sells to the proxy of 0x0a and buys back.
+        fill(5, 90_000, 0x1a, 0x0d),
+        fill(6, 90_010, 0x0d, 0x0a),
+    ]);
+
+    let params = Params::parse("").unwrap();
+    assert_eq!(
+        suspicious(&trades, &params),
+        [
+            (3, Reason::CircularTrade as i32, vec![0x0d, 0x0b, 0x0c]),
+            (6, Reason::CircularTrade as i32, vec![0x0a, 0x0d]),
+        ]
+    );
+
+    // Two-owner cycles only, or cycles within 50 seconds.
+    for params in ["wallets=2", "window=50"] {
+        let params = Params::parse(params).unwrap();
+        assert_eq!(suspicious(&trades, &params), [(6, Reason::CircularTrade as i32, vec![0x0a, 0x0d])]);
+    }
+}
+
+#[test]
+fn mint_and_merge_matches_pass_no_tokens_between_their_sides() {
+    let in_tx = |tx_hash: &str, fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        evt_tx_hash: tx_hash.to_string(),
+        ..fill
+    };
+    let complement = |fill: contract::PolymarketctfOrderFilled| contract::PolymarketctfOrderFilled {
+        maker_asset_id: fill.maker_asset_id.replace(TOKEN, "5678"),
+        taker_asset_id: fill.taker_asset_id.replace(TOKEN, "5678"),
+        ..fill
+    };
+    let trades = trades(vec![
+        // COMPLEMENTARY: 0x0a sells to 0x0b through its taker order.
+        in_tx("aa", fill(1, 0, 0x0b, 0x0a)),
+        in_tx("aa", common::fill(2, 0x0a, 0xee, Side::Sell, "100000000", "40000000")),
+        // MINT: the proxy of 0x0a buys the token and 0x0a the complement, from no one.
+        in_tx("bb", complement(fill(4, 0, 0x0a, 0x1a))),
+        in_tx("bb", common::fill(5, 0x1a, 0xee, Side::Buy, "100000000", "60000000")),
+        // MERGE: both sell back.
+        in_tx("cc", complement(common::fill(7, 0x0a, 0x1a, Side::Sell, "100000000", "40000000"))),
+        in_tx("cc", common::fill(8, 0x1a, 0xee, Side::Sell, "100000000", "60000000")),
+    ]);
+
+    let summary: Vec<_> = trades.iter().map(|t| (t.ordinal, t.seller[0], t.buyer[0])).collect();
+    assert_eq!(summary, [(1, 0x0a, 0x0b)]);
+    assert!(suspicious(&trades, &Params::parse("").unwrap()).is_empty());
+}
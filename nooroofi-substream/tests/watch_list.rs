@@ -0,0 +1,125 @@
+mod common;
+
+use common::{address, fill, order, TOKEN};
+use nooroo::watch_list::WatchList;
+use nooroo_core::pb::contract::v1 as contract;
+use nooroo_core::price::Side;
+
+/// Token 1234 is registered under condition 0xcc...
+fn condition_of(token_id: &str) -> Option<Vec<u8>> {
+    (token_id == TOKEN).then(|| vec![0xcc; 32])
+}
+
+fn hex(seed: u8, len: usize) -> String {
+    format!("0x{}", format!("{:02x}", seed).repeat(len))
+}
+
+#[test]
+fn params_are_checked() {
+    assert!(WatchList::parse("").unwrap().is_empty());
+    assert!(WatchList::parse(" & wallets=").unwrap().is_empty());
+    let params = format!("wallets={},{}&token_ids=01234&condition_ids={}", hex(0x0a, 20), hex(0x0b, 20), hex(0xcc, 32));
+    assert!(!WatchList::parse(&params).unwrap().is_empty());
+
+    for params in [
+        "wallets=0x0a0a".to_string(),
+        format!("wallets={}", hex(0x0a, 32)),
+        format!("condition_ids={}", hex(0xcc, 20)),
+        "token_ids=0x12".to_string(),
+        "tokens=1234".to_string(),
+        "wallets".to_string(),
+    ] {
+        assert!(WatchList::parse(&params).is_err(), "{}", params);
+    }
+}
+
+#[test]
+fn events_are_kept_by_wallet_token_or_condition() {
+    let events = contract::Events {
+        polymarketctf_order_filleds: vec![
+            fill(1, 0x0a, 0x0b, Side::Buy, "100", "40"),
+            fill(2, 0x0c, 0x0d, Side::Buy, "100", "40"),
+            contract::PolymarketctfOrderFilled {
+                taker_asset_id: "99".to_string(),
+                ..fill(3, 0x0c, 0x0d, Side::Buy, "100", "40")
+            },
+        ],
+        polymarketctf_order_cancelleds: vec![Default::default()],
+        ..Default::default()
+    };
+    let ordinals = |events: &contract::Events| events.polymarketctf_order_filleds.iter().map(|e| e.evt_ordinal).collect::<Vec<_>>();
+
+    let everything = WatchList::parse("").unwrap().filter_events(events.clone(), condition_of);
+    assert_eq!(everything, events);
+
+    let wallet = WatchList::parse(&format!("wallets={}", hex(0x0b, 20))).unwrap();
+    let filtered = wallet.filter_events(events.clone(), condition_of);
+    assert_eq!(ordinals(&filtered), [1]);
+    assert!(filtered.polymarketctf_order_cancelleds.is_empty());
+
+    // Normalized token ids, and conditions through the tokens registered under them.
+    for params in ["token_ids=001234".to_string(), format!("condition_ids={}", hex(0xcc, 32))] {
+        let filtered = WatchList::parse(&params).unwrap().filter_events(events.clone(), condition_of);
+        assert_eq!(ordinals(&filtered), [1, 2], "{}", params);
+    }
+}
+
+#[test]
+fn order_calls_are_kept_by_their_orders() {
+    let other_token = |maker: u8| contract::PolymarketctfOrder { token_id: "99".to_string(), ..order(maker, Side::Buy, "40", "100") };
+    // 0x0b's order signed by 0x5b.
+    let signed = contract::PolymarketctfOrder { signer: address(0x5b), ..other_token(0x0b) };
+    let calls = contract::Calls {
+        polymarketctf_call_fill_orders: vec![
+            contract::PolymarketctfFillOrderCall { call_ordinal: 1, order: Some(order(0x0a, Side::Buy, "40", "100")), ..Default::default() },
+            contract::PolymarketctfFillOrderCall { call_ordinal: 2, order: Some(other_token(0x0c)), ..Default::default() },
+        ],
+        polymarketctf_call_fill_orders_batch: vec![contract::PolymarketctfFillOrdersCall {
+            call_ordinal: 3,
+            orders: vec![other_token(0x0c), signed.clone()],
+            ..Default::default()
+        }],
+        polymarketctf_call_match_orders: vec![
+            contract::PolymarketctfMatchOrdersCall {
+                call_ordinal: 4,
+                taker_order: Some(other_token(0x0c)),
+                maker_orders: vec![order(0x0d, Side::Sell, "100", "40")],
+                ..Default::default()
+            },
+            contract::PolymarketctfMatchOrdersCall {
+                call_ordinal: 5,
+                taker_order: Some(other_token(0x0c)),
+                maker_orders: vec![other_token(0x0d)],
+                ..Default::default()
+            },
+        ],
+        polymarketctf_call_cancel_orders: vec![contract::PolymarketctfCancelOrderCall {
+            call_ordinal: 6,
+            order: Some(signed),
+            ..Default::default()
+        }],
+        polymarketctf_call_increment_nonces: vec![Default::default()],
+        ..Default::default()
+    };
+    let ordinals = |calls: &contract::Calls| {
+        let fills = calls.polymarketctf_call_fill_orders.iter().map(|c| c.call_ordinal);
+        let batches = calls.polymarketctf_call_fill_orders_batch.iter().map(|c| c.call_ordinal);
+        let matches = calls.polymarketctf_call_match_orders.iter().map(|c| c.call_ordinal);
+        let cancels = calls.polymarketctf_call_cancel_orders.iter().map(|c| c.call_ordinal);
+        fills.chain(batches).chain(matches).chain(cancels).collect::<Vec<_>>()
+    };
+
+    let by_token = WatchList::parse(&format!("condition_ids={}", hex(0xcc, 32))).unwrap();
+    let filtered = by_token.filter_calls(calls.clone(), condition_of);
+    assert_eq!(ordinals(&filtered), [1, 4]);
+    assert!(filtered.polymarketctf_call_increment_nonces.is_empty());
+
+    // The signer of an order its proxy made, and batches kept whole.
+    let by_signer = WatchList::parse(&format!("wallets={}", hex(0x5b, 20))).unwrap();
+    let filtered = by_signer.filter_calls(calls.clone(), condition_of);
+    assert_eq!(ordinals(&filtered), [3, 6]);
+    assert_eq!(filtered.polymarketctf_call_fill_orders_batch[0].orders.len(), 2);
+
+    let by_maker = WatchList::parse(&format!("wallets={}", hex(0x0d, 20))).unwrap();
+    assert_eq!(ordinals(&by_maker.filter_calls(calls, condition_of)), [4, 5]);
+}